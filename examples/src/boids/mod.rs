@@ -244,11 +244,6 @@ impl crate::framework::Example for Example {
         }
     }
 
-    /// update is called for any WindowEvent not handled by the framework
-    fn update(&mut self, _event: winit::event::WindowEvent) {
-        //empty
-    }
-
     /// resize is called on WindowEvent::Resized events
     fn resize(
         &mut self,
@@ -320,6 +315,7 @@ impl crate::framework::Example for Example {
 }
 
 /// run example
+#[cfg(feature = "winit")]
 pub fn main() {
     crate::framework::run::<Example>("boids");
 }
@@ -2,6 +2,7 @@ use bytemuck::{Pod, Zeroable};
 use nanorand::{Rng, WyRand};
 use std::{borrow::Cow, mem};
 use wgpu::util::DeviceExt;
+#[cfg(feature = "winit")]
 use winit::{
     event::{ElementState, KeyEvent},
     keyboard::{Key, NamedKey},
@@ -346,6 +347,7 @@ impl crate::framework::Example for Example {
         ex
     }
 
+    #[cfg(feature = "winit")]
     fn update(&mut self, event: winit::event::WindowEvent) {
         if let winit::event::WindowEvent::KeyboardInput {
             event:
@@ -375,6 +377,7 @@ impl crate::framework::Example for Example {
     }
 }
 
+#[cfg(feature = "winit")]
 pub fn main() {
     crate::framework::run::<Example>("bunnymark");
 }
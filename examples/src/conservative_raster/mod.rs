@@ -248,8 +248,6 @@ impl crate::framework::Example for Example {
         self.bind_group_upscale = bind_group_upscale;
     }
 
-    fn update(&mut self, _event: winit::event::WindowEvent) {}
-
     fn render(&mut self, view: &wgpu::TextureView, device: &wgpu::Device, queue: &wgpu::Queue) {
         let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
             label: Some("primary"),
@@ -306,6 +304,7 @@ impl crate::framework::Example for Example {
     }
 }
 
+#[cfg(feature = "winit")]
 pub fn main() {
     crate::framework::run::<Example>("conservative-raster");
 }
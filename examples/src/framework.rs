@@ -1,6 +1,9 @@
+#[cfg(feature = "winit")]
 use std::sync::Arc;
 
+#[cfg(feature = "winit")]
 use wgpu::{Instance, Surface};
+#[cfg(feature = "winit")]
 use winit::{
     dpi::PhysicalSize,
     event::{Event, KeyEvent, StartCause, WindowEvent},
@@ -46,12 +49,15 @@ pub trait Example: 'static + Sized {
         queue: &wgpu::Queue,
     );
 
-    fn update(&mut self, event: WindowEvent);
+    /// Handle a window event that the framework doesn't handle itself.
+    #[cfg(feature = "winit")]
+    fn update(&mut self, _event: WindowEvent) {}
 
     fn render(&mut self, view: &wgpu::TextureView, device: &wgpu::Device, queue: &wgpu::Queue);
 }
 
 // Initialize logging in platform dependant ways.
+#[cfg(feature = "winit")]
 fn init_logger() {
     cfg_if::cfg_if! {
         if #[cfg(target_arch = "wasm32")] {
@@ -89,32 +95,47 @@ fn init_logger() {
     }
 }
 
+#[cfg(feature = "winit")]
 struct EventLoopWrapper {
     event_loop: EventLoop<()>,
     window: Arc<Window>,
 }
 
+#[cfg(feature = "winit")]
 impl EventLoopWrapper {
     pub fn new(title: &str) -> Self {
         let event_loop = EventLoop::new().unwrap();
         let mut builder = winit::window::WindowBuilder::new();
         #[cfg(target_arch = "wasm32")]
-        {
+        let append_canvas = {
             use wasm_bindgen::JsCast;
             use winit::platform::web::WindowBuilderExtWebSys;
+            // Use the page's canvas if it has one, otherwise have winit create one
+            // and append it to the body.
             let canvas = web_sys::window()
-                .unwrap()
-                .document()
-                .unwrap()
-                .get_element_by_id("canvas")
-                .unwrap()
-                .dyn_into::<web_sys::HtmlCanvasElement>()
-                .unwrap();
-            builder = builder.with_canvas(Some(canvas));
-        }
+                .and_then(|window| window.document())
+                .and_then(|document| document.get_element_by_id("canvas"))
+                .and_then(|element| element.dyn_into::<web_sys::HtmlCanvasElement>().ok());
+            let append_canvas = canvas.is_none();
+            builder = builder.with_canvas(canvas).with_append(append_canvas);
+            append_canvas
+        };
         builder = builder.with_title(title);
         let window = Arc::new(builder.build(&event_loop).unwrap());
 
+        // winit observes the canvas' CSS size and device pixel ratio itself, and sends
+        // `WindowEvent::Resized` with the physical size, so we only need to make a
+        // canvas we created follow the size of the page.
+        #[cfg(target_arch = "wasm32")]
+        if append_canvas {
+            use winit::platform::web::WindowExtWebSys;
+            if let Some(canvas) = window.canvas() {
+                canvas
+                    .set_attribute("style", "width: 100%; height: 100%; display: block;")
+                    .unwrap();
+            }
+        }
+
         Self { event_loop, window }
     }
 }
@@ -122,11 +143,13 @@ impl EventLoopWrapper {
 /// Wrapper type which manages the surface and surface configuration.
 ///
 /// As surface usage varies per platform, wrapping this up cleans up the event loop code.
+#[cfg(feature = "winit")]
 struct SurfaceWrapper {
     surface: Option<wgpu::Surface<'static>>,
     config: Option<wgpu::SurfaceConfiguration>,
 }
 
+#[cfg(feature = "winit")]
 impl SurfaceWrapper {
     /// Create a new surface wrapper with no surface or configuration.
     fn new() -> Self {
@@ -202,14 +225,19 @@ impl SurfaceWrapper {
     }
 
     /// Resize the surface, making sure to not resize to zero.
-    fn resize(&mut self, context: &ExampleContext, size: PhysicalSize<u32>) {
+    ///
+    /// Returns `false` without reconfiguring if the size didn't change.
+    fn resize(&mut self, context: &ExampleContext, size: PhysicalSize<u32>) -> bool {
+        let config = self.config.as_mut().unwrap();
+        if !update_surface_size(config, size) {
+            return false;
+        }
+
         log::info!("Surface resize {size:?}");
 
-        let config = self.config.as_mut().unwrap();
-        config.width = size.width.max(1);
-        config.height = size.height.max(1);
         let surface = self.surface.as_ref().unwrap();
         surface.configure(&context.device, config);
+        true
     }
 
     /// Acquire the next surface texture.
@@ -255,13 +283,32 @@ impl SurfaceWrapper {
     }
 }
 
+/// Store `size` in `config`, making sure to not resize to zero.
+///
+/// Returns `false` if the integer size didn't change, in which case the surface
+/// doesn't need to be reconfigured.
+#[cfg(feature = "winit")]
+fn update_surface_size(config: &mut wgpu::SurfaceConfiguration, size: PhysicalSize<u32>) -> bool {
+    let width = size.width.max(1);
+    let height = size.height.max(1);
+    if config.width == width && config.height == height {
+        return false;
+    }
+
+    config.width = width;
+    config.height = height;
+    true
+}
+
 /// Context containing global wgpu resources.
+#[cfg(feature = "winit")]
 struct ExampleContext {
     instance: wgpu::Instance,
     adapter: wgpu::Adapter,
     device: wgpu::Device,
     queue: wgpu::Queue,
 }
+#[cfg(feature = "winit")]
 impl ExampleContext {
     /// Initializes the example context.
     async fn init_async<E: Example>(surface: &mut SurfaceWrapper, window: Arc<Window>) -> Self {
@@ -334,6 +381,7 @@ impl ExampleContext {
     }
 }
 
+#[cfg(feature = "winit")]
 struct FrameCounter {
     // Instant of the last time we printed the frame time.
     last_printed_instant: web_time::Instant,
@@ -341,6 +389,7 @@ struct FrameCounter {
     frame_count: u32,
 }
 
+#[cfg(feature = "winit")]
 impl FrameCounter {
     fn new() -> Self {
         Self {
@@ -365,6 +414,7 @@ impl FrameCounter {
     }
 }
 
+#[cfg(feature = "winit")]
 async fn start<E: Example>(title: &str) {
     init_logger();
     let window_loop = EventLoopWrapper::new(title);
@@ -409,7 +459,9 @@ async fn start<E: Example>(title: &str) {
                 }
                 Event::WindowEvent { event, .. } => match event {
                     WindowEvent::Resized(size) => {
-                        surface.resize(&context, size);
+                        if !surface.resize(&context, size) {
+                            return;
+                        }
                         example.as_mut().unwrap().resize(
                             surface.config(),
                             &context.device,
@@ -474,6 +526,7 @@ async fn start<E: Example>(title: &str) {
     );
 }
 
+#[cfg(feature = "winit")]
 pub fn run<E: Example>(title: &'static str) {
     cfg_if::cfg_if! {
         if #[cfg(target_arch = "wasm32")] {
@@ -627,3 +680,49 @@ impl<E: Example + wgpu::WasmNotSendSync> From<ExampleTestParams<E>>
             })
     }
 }
+
+#[cfg(all(test, target_arch = "wasm32", feature = "winit"))]
+mod web_tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+    use winit::dpi::{LogicalSize, PhysicalSize};
+
+    use super::update_surface_size;
+
+    /// Physical size of a canvas with the given CSS size, computed the same way
+    /// winit does when the canvas or the device pixel ratio changes.
+    fn physical_size(css_size: LogicalSize<f64>, device_pixel_ratio: f64) -> PhysicalSize<u32> {
+        css_size.to_physical(device_pixel_ratio)
+    }
+
+    #[wasm_bindgen_test]
+    fn device_pixel_ratio_change_reconfigures_once() {
+        let css_size = LogicalSize::new(301.0, 151.0);
+        let mut config = wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            width: 301,
+            height: 151,
+            present_mode: wgpu::PresentMode::Fifo,
+            alpha_mode: wgpu::CompositeAlphaMode::Auto,
+            view_formats: vec![],
+        };
+
+        // A DPR change is reported both as a scale factor change and by the resize
+        // observer, with the same physical size. Only the first one reconfigures.
+        let mut reconfigures = 0;
+        for _ in 0..2 {
+            if update_surface_size(&mut config, physical_size(css_size, 1.25)) {
+                reconfigures += 1;
+            }
+        }
+        assert_eq!(reconfigures, 1);
+        assert_eq!((config.width, config.height), (376, 189));
+
+        // Sizes are always derived from the CSS size, so going through several
+        // fractional ratios doesn't accumulate rounding errors.
+        for device_pixel_ratio in [1.5, 1.1, 2.25, 1.0] {
+            update_surface_size(&mut config, physical_size(css_size, device_pixel_ratio));
+        }
+        assert_eq!((config.width, config.height), (301, 151));
+    }
+}
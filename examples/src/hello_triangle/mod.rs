@@ -64,7 +64,7 @@ async fn run(event_loop: EventLoop<()>, window: Window) {
         fragment: Some(wgpu::FragmentState {
             module: &shader,
             entry_point: "fs_main",
-            targets: &[Some(swapchain_format.into())],
+            targets: &[swapchain_format.into()],
         }),
         primitive: wgpu::PrimitiveState::default(),
         depth_stencil: None,
@@ -8,7 +8,9 @@ pub mod cube;
 pub mod hello;
 pub mod hello_compute;
 pub mod hello_synchronization;
+#[cfg(feature = "winit")]
 pub mod hello_triangle;
+#[cfg(feature = "winit")]
 pub mod hello_windows;
 pub mod hello_workgroups;
 pub mod mipmap;
@@ -22,6 +24,7 @@ pub mod stencil_triangles;
 pub mod storage_texture;
 pub mod texture_arrays;
 pub mod timestamp_queries;
+#[cfg(feature = "winit")]
 pub mod uniform_values;
 pub mod water;
 
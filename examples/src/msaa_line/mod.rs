@@ -12,6 +12,7 @@ use std::{borrow::Cow, iter};
 use bytemuck::{Pod, Zeroable};
 use wgpu::util::DeviceExt;
 
+#[cfg(feature = "winit")]
 use winit::{
     event::{ElementState, KeyEvent, WindowEvent},
     keyboard::{Key, NamedKey},
@@ -214,6 +215,7 @@ impl crate::framework::Example for Example {
         }
     }
 
+    #[cfg(feature = "winit")]
     #[allow(clippy::single_match)]
     fn update(&mut self, event: winit::event::WindowEvent) {
         match event {
@@ -313,6 +315,7 @@ impl crate::framework::Example for Example {
     }
 }
 
+#[cfg(feature = "winit")]
 pub fn main() {
     crate::framework::run::<Example>("msaa-line");
 }
@@ -681,10 +681,6 @@ impl crate::framework::Example for Example {
         }
     }
 
-    fn update(&mut self, _event: winit::event::WindowEvent) {
-        //empty
-    }
-
     fn resize(
         &mut self,
         config: &wgpu::SurfaceConfiguration,
@@ -834,6 +830,7 @@ impl crate::framework::Example for Example {
     }
 }
 
+#[cfg(feature = "winit")]
 pub fn main() {
     crate::framework::run::<Example>("shadow");
 }
@@ -376,6 +376,7 @@ impl crate::framework::Example for Example {
         }
     }
 
+    #[cfg(feature = "winit")]
     #[allow(clippy::single_match)]
     fn update(&mut self, event: winit::event::WindowEvent) {
         match event {
@@ -463,6 +464,7 @@ impl crate::framework::Example for Example {
     }
 }
 
+#[cfg(feature = "winit")]
 pub fn main() {
     crate::framework::run::<Example>("skybox");
 }
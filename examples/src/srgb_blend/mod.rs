@@ -161,10 +161,6 @@ impl<const SRGB: bool> crate::framework::Example for Example<SRGB> {
         }
     }
 
-    fn update(&mut self, _event: winit::event::WindowEvent) {
-        //empty
-    }
-
     fn resize(
         &mut self,
         _config: &wgpu::SurfaceConfiguration,
@@ -211,6 +207,7 @@ impl<const SRGB: bool> crate::framework::Example for Example<SRGB> {
     }
 }
 
+#[cfg(feature = "winit")]
 pub fn main() {
     let mut args = std::env::args();
     args.next();
@@ -164,10 +164,6 @@ impl crate::framework::Example for Example {
         }
     }
 
-    fn update(&mut self, _event: winit::event::WindowEvent) {
-        // empty
-    }
-
     fn resize(
         &mut self,
         config: &wgpu::SurfaceConfiguration,
@@ -237,6 +233,7 @@ impl crate::framework::Example for Example {
     }
 }
 
+#[cfg(feature = "winit")]
 pub fn main() {
     crate::framework::run::<Example>("stencil-triangles");
 }
@@ -61,6 +61,12 @@ fn create_texture_data(color: Color) -> [u8; 4] {
     }
 }
 
+/// The bind group layout holds arrays of two textures and two samplers.
+const LIMITS: wgpu::Limits = wgpu::Limits {
+    max_binding_array_elements: 2,
+    ..wgpu::Limits::downlevel_webgl2_defaults()
+};
+
 struct Example {
     pipeline: wgpu::RenderPipeline,
     bind_group: wgpu::BindGroup,
@@ -77,6 +83,9 @@ impl crate::framework::Example for Example {
     fn required_features() -> wgpu::Features {
         wgpu::Features::TEXTURE_BINDING_ARRAY
     }
+    fn required_limits() -> wgpu::Limits {
+        LIMITS
+    }
     fn init(
         config: &wgpu::SurfaceConfiguration,
         _adapter: &wgpu::Adapter,
@@ -358,9 +367,6 @@ impl crate::framework::Example for Example {
     ) {
         // noop
     }
-    fn update(&mut self, _event: winit::event::WindowEvent) {
-        // noop
-    }
     fn render(&mut self, view: &wgpu::TextureView, device: &wgpu::Device, queue: &wgpu::Queue) {
         let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
             label: Some("primary"),
@@ -400,6 +406,7 @@ impl crate::framework::Example for Example {
     }
 }
 
+#[cfg(feature = "winit")]
 pub fn main() {
     crate::framework::run::<Example>("texture-arrays");
 }
@@ -412,7 +419,7 @@ static TEST: crate::framework::ExampleTestParams = crate::framework::ExampleTest
     width: 1024,
     height: 768,
     optional_features: wgpu::Features::empty(),
-    base_test_parameters: wgpu_test::TestParameters::default(),
+    base_test_parameters: wgpu_test::TestParameters::default().limits(LIMITS),
     comparisons: &[wgpu_test::ComparisonType::Mean(0.0)],
     _phantom: std::marker::PhantomData::<Example>,
 };
@@ -425,7 +432,7 @@ static TEST_UNIFORM: crate::framework::ExampleTestParams = crate::framework::Exa
     width: 1024,
     height: 768,
     optional_features: wgpu::Features::empty(),
-    base_test_parameters: wgpu_test::TestParameters::default(),
+    base_test_parameters: wgpu_test::TestParameters::default().limits(LIMITS),
     comparisons: &[wgpu_test::ComparisonType::Mean(0.0)],
     _phantom: std::marker::PhantomData::<Example>,
 };
@@ -440,7 +447,7 @@ static TEST_NON_UNIFORM: crate::framework::ExampleTestParams =
         height: 768,
         optional_features:
             wgpu::Features::SAMPLED_TEXTURE_AND_STORAGE_BUFFER_ARRAY_NON_UNIFORM_INDEXING,
-        base_test_parameters: wgpu_test::TestParameters::default(),
+        base_test_parameters: wgpu_test::TestParameters::default().limits(LIMITS),
         comparisons: &[wgpu_test::ComparisonType::Mean(0.0)],
         _phantom: std::marker::PhantomData::<Example>,
     };
@@ -184,7 +184,7 @@ impl WgpuContext {
             fragment: Some(wgpu::FragmentState {
                 module: &shader,
                 entry_point: "fs_main",
-                targets: &[Some(swapchain_format.into())],
+                targets: &[swapchain_format.into()],
             }),
             primitive: wgpu::PrimitiveState::default(),
             depth_stencil: None,
@@ -656,10 +656,6 @@ impl crate::framework::Example for Example {
         }
     }
 
-    fn update(&mut self, _event: winit::event::WindowEvent) {
-        //empty
-    }
-
     fn resize(
         &mut self,
         config: &wgpu::SurfaceConfiguration,
@@ -813,6 +809,7 @@ impl crate::framework::Example for Example {
     }
 }
 
+#[cfg(feature = "winit")]
 pub fn main() {
     crate::framework::run::<Example>("water");
 }
@@ -172,6 +172,11 @@ pub enum Error<'a> {
     UnknownAddressSpace(Span),
     RepeatedAttribute(Span),
     UnknownAttribute(Span),
+    InvalidBlendSrc(Span),
+    EnableExtensionNotEnabled {
+        span: Span,
+        extension: &'static str,
+    },
     UnknownBuiltin(Span),
     UnknownAccess(Span),
     UnknownIdent(Span, &'a str),
@@ -179,6 +184,7 @@ pub enum Error<'a> {
     UnknownType(Span),
     UnknownStorageFormat(Span),
     UnknownConservativeDepth(Span),
+    UnknownEnableExtension(Span),
     SizeAttributeTooLow(Span, u32),
     AlignAttributeTooLow(Span, Alignment),
     NonPowerOfTwoAlignAttribute(Span),
@@ -450,6 +456,24 @@ impl<'a> Error<'a> {
                 labels: vec![(bad_span, "unknown attribute".into())],
                 notes: vec![],
             },
+            Error::InvalidBlendSrc(bad_span) => ParseError {
+                message: format!("invalid blend source: '{}'", &source[bad_span]),
+                labels: vec![(bad_span, "invalid blend source".into())],
+                notes: vec!["`blend_src` must be `0` or `1`".into()],
+            },
+            Error::EnableExtensionNotEnabled { span, extension } => ParseError {
+                message: format!(
+                    "the `{}` attribute requires the `{extension}` enable-extension",
+                    &source[span]
+                ),
+                labels: vec![(span, "requires an enable-extension".into())],
+                notes: vec![format!("add `enable {extension};` at the start of the shader")],
+            },
+            Error::UnknownEnableExtension(bad_span) => ParseError {
+                message: format!("unknown enable-extension: '{}'", &source[bad_span]),
+                labels: vec![(bad_span, "unknown enable-extension".into())],
+                notes: vec![],
+            },
             Error::UnknownBuiltin(bad_span) => ParseError {
                 message: format!("unknown builtin: '{}'", &source[bad_span]),
                 labels: vec![(bad_span, "unknown builtin".into())],
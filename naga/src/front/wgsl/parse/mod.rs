@@ -179,6 +179,28 @@ impl<'a> BindingParser<'a> {
             "second_blend_source" => {
                 self.second_blend_source.set(true, name_span)?;
             }
+            "blend_src" => {
+                if !parser.dual_source_blending {
+                    return Err(Error::EnableExtensionNotEnabled {
+                        span: name_span,
+                        extension: "dual_source_blending",
+                    });
+                }
+                lexer.expect(Token::Paren('('))?;
+                let (token, span) = lexer.next();
+                let second_blend_source = match token {
+                    Token::Number(Ok(Number::AbstractInt(0) | Number::I32(0) | Number::U32(0))) => {
+                        false
+                    }
+                    Token::Number(Ok(Number::AbstractInt(1) | Number::I32(1) | Number::U32(1))) => {
+                        true
+                    }
+                    _ => return Err(Error::InvalidBlendSrc(span)),
+                };
+                self.second_blend_source
+                    .set(second_blend_source, name_span)?;
+                lexer.expect(Token::Paren(')'))?;
+            }
             "invariant" => {
                 self.invariant.set(true, name_span)?;
             }
@@ -221,15 +243,21 @@ impl<'a> BindingParser<'a> {
 
 pub struct Parser {
     rules: Vec<(Rule, usize)>,
+    /// Whether the module has an `enable dual_source_blending;` directive.
+    dual_source_blending: bool,
 }
 
 impl Parser {
     pub const fn new() -> Self {
-        Parser { rules: Vec::new() }
+        Parser {
+            rules: Vec::new(),
+            dual_source_blending: false,
+        }
     }
 
     fn reset(&mut self) {
         self.rules.clear();
+        self.dual_source_blending = false;
     }
 
     fn push_rule_span(&mut self, rule: Rule, lexer: &mut Lexer<'_>) {
@@ -2334,6 +2362,23 @@ impl Parser {
 
         let mut lexer = Lexer::new(source);
         let mut tu = ast::TranslationUnit::default();
+
+        while lexer.skip(Token::Word("enable")) {
+            loop {
+                let (name, span) = lexer.next_ident_with_span()?;
+                match name {
+                    "dual_source_blending" => self.dual_source_blending = true,
+                    // `f16` types are still rejected where they are used.
+                    "f16" => {}
+                    _ => return Err(Error::UnknownEnableExtension(span)),
+                }
+                if !lexer.skip(Token::Separator(',')) || lexer.peek().0 == Token::Separator(';') {
+                    break;
+                }
+            }
+            lexer.expect(Token::Separator(';'))?;
+        }
+
         loop {
             match self.global_decl(&mut lexer, &mut tu) {
                 Err(error) => return Err(error),
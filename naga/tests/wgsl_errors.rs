@@ -309,6 +309,68 @@ fn unknown_attribute() {
     );
 }
 
+#[test]
+fn enable_extension() {
+    naga::front::wgsl::parse_str(
+        r#"
+            enable f16;
+            enable dual_source_blending, f16,;
+        "#,
+    )
+    .unwrap();
+
+    check(
+        r#"
+            enable something;
+        "#,
+        r#"error: unknown enable-extension: 'something'
+  ┌─ wgsl:2:20
+  │
+2 │             enable something;
+  │                    ^^^^^^^^^ unknown enable-extension
+
+"#,
+    );
+}
+
+#[test]
+fn invalid_blend_src() {
+    check(
+        r#"
+            enable dual_source_blending;
+            struct FragmentOutput {
+                @location(0) @blend_src(2) color: vec4<f32>,
+            }
+        "#,
+        r#"error: invalid blend source: '2'
+  ┌─ wgsl:4:41
+  │
+4 │                 @location(0) @blend_src(2) color: vec4<f32>,
+  │                                         ^ invalid blend source
+  │
+  = note: `blend_src` must be `0` or `1`
+
+"#,
+    );
+
+    check(
+        r#"
+            struct FragmentOutput {
+                @location(0) @blend_src(0) color: vec4<f32>,
+            }
+        "#,
+        r#"error: the `blend_src` attribute requires the `dual_source_blending` enable-extension
+  ┌─ wgsl:3:31
+  │
+3 │                 @location(0) @blend_src(0) color: vec4<f32>,
+  │                               ^^^^^^^^^ requires an enable-extension
+  │
+  = note: add `enable dual_source_blending;` at the start of the shader
+
+"#,
+    );
+}
+
 #[test]
 fn unknown_built_in() {
     check(
@@ -261,6 +261,18 @@ impl GlobalPlay for wgc::global::Global<IdentityPassThroughFactory> {
                 } else if data.ends_with(".ron") {
                     let module = ron::de::from_str(&code).unwrap();
                     wgc::pipeline::ShaderModuleSource::Naga(module)
+                } else if data.ends_with(".glsl") {
+                    // Only GLSL that failed to parse is recorded as source, without its defines.
+                    let stage = if data.ends_with(".vert.glsl") {
+                        naga::ShaderStage::Vertex
+                    } else if data.ends_with(".frag.glsl") {
+                        naga::ShaderStage::Fragment
+                    } else if data.ends_with(".comp.glsl") {
+                        naga::ShaderStage::Compute
+                    } else {
+                        panic!("Unknown GLSL shader stage {}", data);
+                    };
+                    wgc::pipeline::ShaderModuleSource::Glsl(Cow::Owned(code.clone()), stage.into())
                 } else {
                     panic!("Unknown shader {}", data);
                 };
@@ -600,6 +600,22 @@ impl ReadbackBuffers {
         }
     }
 
+    /// Read back the color or depth aspect, with the row padding removed.
+    pub fn contents(&self, device: &Device) -> Vec<u8> {
+        let contents = self.retrieve_buffer(device, &self.buffer, self.buffer_aspect());
+        self.buffer.unmap();
+        contents
+    }
+
+    /// Read back the stencil aspect of a combined depth-stencil texture, with the row padding
+    /// removed.
+    pub fn stencil_contents(&self, device: &Device) -> Vec<u8> {
+        let buffer = self.buffer_stencil.as_ref().unwrap();
+        let contents = self.retrieve_buffer(device, buffer, Some(TextureAspect::StencilOnly));
+        buffer.unmap();
+        contents
+    }
+
     pub fn are_zero(&self, device: &Device) -> bool {
         let is_zero = |device: &Device, buffer: &Buffer, aspect: Option<TextureAspect>| -> bool {
             let is_zero = self
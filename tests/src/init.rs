@@ -1,4 +1,4 @@
-use wgpu::{Adapter, Device, Instance, Queue};
+use wgpu::{Adapter, Device, Instance, InstanceFlags, Queue};
 use wgt::{Backends, Features, Limits};
 
 /// Initialize the logger for the test runner.
@@ -10,8 +10,8 @@ pub fn init_logger() {
     let _ = console_log::init_with_level(log::Level::Info);
 }
 
-/// Initialize a wgpu instance with the options from the environment.
-pub fn initialize_instance() -> Instance {
+/// Initialize a wgpu instance with the options from the environment, plus `instance_flags`.
+pub fn initialize_instance(instance_flags: InstanceFlags) -> Instance {
     // We ignore `WGPU_BACKEND` for now, merely using test filtering to only run a single backend's tests.
     //
     // We can potentially work support back into the test runner in the future, but as the adapters are matched up
@@ -21,15 +21,18 @@ pub fn initialize_instance() -> Instance {
     let gles_minor_version = wgpu::util::gles_minor_version_from_env().unwrap_or_default();
     Instance::new(wgpu::InstanceDescriptor {
         backends,
-        flags: wgpu::InstanceFlags::debugging().with_env(),
+        flags: (InstanceFlags::debugging() | instance_flags).with_env(),
         dx12_shader_compiler,
         gles_minor_version,
     })
 }
 
 /// Initialize a wgpu adapter, taking the `n`th adapter from the instance.
-pub async fn initialize_adapter(adapter_index: usize) -> (Instance, Adapter, Option<SurfaceGuard>) {
-    let instance = initialize_instance();
+pub async fn initialize_adapter(
+    adapter_index: usize,
+    instance_flags: InstanceFlags,
+) -> (Instance, Adapter, Option<SurfaceGuard>) {
+    let instance = initialize_instance(instance_flags);
     #[allow(unused_variables)]
     let _surface: wgpu::Surface;
     let surface_guard: Option<SurfaceGuard>;
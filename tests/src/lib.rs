@@ -41,6 +41,66 @@ pub fn valid<T>(device: &wgpu::Device, callback: impl FnOnce() -> T) -> T {
     result
 }
 
+/// Record commands into `encoder` and finish it, returning the command buffer, or the message of
+/// the first validation error raised along the way.
+///
+/// Errors inside a pass are only reported when the pass ends, and an encoder that hit an error
+/// still has to be finished, so both happen inside the error scope.
+pub fn record_and_finish(
+    device: &wgpu::Device,
+    mut encoder: wgpu::CommandEncoder,
+    record: impl FnOnce(&mut wgpu::CommandEncoder),
+) -> Result<wgpu::CommandBuffer, String> {
+    device.push_error_scope(wgpu::ErrorFilter::Validation);
+    record(&mut encoder);
+    let command_buffer = encoder.finish();
+    match pollster::block_on(device.pop_error_scope()) {
+        None => Ok(command_buffer),
+        Some(error) => Err(error.to_string()),
+    }
+}
+
+/// WGSL vertex entry point `vs_main` that covers the whole render target with one triangle when
+/// drawn with `draw(0..3, 0..1)`.
+pub const FULLSCREEN_TRIANGLE_WGSL: &str = r#"
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4<f32> {
+    let uv = vec2<f32>(f32((vertex_index << 1u) & 2u), f32(vertex_index & 2u));
+    return vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+}
+"#;
+
+/// Create a shader module from `fragment_wgsl`, with [`FULLSCREEN_TRIANGLE_WGSL`] supplying the
+/// vertex stage as `vs_main`.
+pub fn fullscreen_shader(device: &wgpu::Device, fragment_wgsl: &str) -> wgpu::ShaderModule {
+    device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("fullscreen"),
+        source: wgpu::ShaderSource::Wgsl(
+            format!("{FULLSCREEN_TRIANGLE_WGSL}{fragment_wgsl}").into(),
+        ),
+    })
+}
+
+/// Copy the contents of `buffer`, which needs `BufferUsages::COPY_SRC`, back to the CPU.
+pub fn read_buffer(device: &wgpu::Device, queue: &wgpu::Queue, buffer: &wgpu::Buffer) -> Vec<u8> {
+    let readback = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("readback"),
+        size: buffer.size(),
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    encoder.copy_buffer_to_buffer(buffer, 0, &readback, 0, buffer.size());
+    queue.submit(Some(encoder.finish()));
+
+    let slice = readback.slice(..);
+    slice.map_async(wgpu::MapMode::Read, |_| ());
+    device.poll(wgpu::Maintain::Wait);
+    let contents = slice.get_mapped_range().to_vec();
+    readback.unmap();
+    contents
+}
+
 /// Run some code in an error scope and assert that validation succeeds or fails depending on the
 /// provided `should_fail` boolean.
 pub fn fail_if<T>(device: &wgpu::Device, should_fail: bool, callback: impl FnOnce() -> T) -> T {
@@ -1,5 +1,5 @@
 use arrayvec::ArrayVec;
-use wgt::{DownlevelCapabilities, DownlevelFlags, Features, Limits};
+use wgt::{DownlevelCapabilities, DownlevelFlags, Features, InstanceFlags, Limits};
 
 use crate::{
     report::AdapterReport, FailureApplicationReasons, FailureBehavior, FailureCase,
@@ -18,6 +18,8 @@ pub struct TestParameters {
     pub required_features: Features,
     pub required_downlevel_caps: DownlevelCapabilities,
     pub required_limits: Limits,
+    /// Flags added to the instance the test runs on.
+    pub instance_flags: InstanceFlags,
 
     /// Conditions under which this test should be skipped.
     pub skips: Vec<FailureCase>,
@@ -32,6 +34,7 @@ impl Default for TestParameters {
             required_features: Features::empty(),
             required_downlevel_caps: LOWEST_DOWNLEVEL_PROPERTIES,
             required_limits: Limits::downlevel_webgl2_defaults(),
+            instance_flags: InstanceFlags::empty(),
             skips: Vec::new(),
             failures: Vec::new(),
         }
@@ -63,6 +66,12 @@ impl TestParameters {
         self
     }
 
+    /// Set the instance flags this test needs, on top of the debugging ones.
+    pub fn instance_flags(mut self, instance_flags: InstanceFlags) -> Self {
+        self.instance_flags |= instance_flags;
+        self
+    }
+
     /// Mark the test as always failing, but not to be skipped.
     pub fn expect_fail(mut self, when: FailureCase) -> Self {
         self.failures.push(when);
@@ -0,0 +1,272 @@
+//! Tests for the draw and dispatch time validation of the bind groups set in a pass
+//! against the layout of the current pipeline.
+
+use wgpu_test::{
+    gpu_test, record_and_finish, GpuTestConfiguration, TestParameters, TestingContext,
+};
+
+const SHADER: &str = r#"
+@group(0) @binding(0)
+var<uniform> value: vec4<f32>;
+
+@compute @workgroup_size(1)
+fn cs_main() {
+    _ = value;
+}
+
+@vertex
+fn vs_main() -> @builtin(position) vec4<f32> {
+    return vec4<f32>(0.0, 0.0, 0.0, 1.0);
+}
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    return value;
+}
+"#;
+
+fn create_layout(
+    ctx: &TestingContext,
+    ty: wgpu::BufferBindingType,
+    has_dynamic_offset: bool,
+) -> wgpu::BindGroupLayout {
+    ctx.device
+        .create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: None,
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::COMPUTE | wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty,
+                    has_dynamic_offset,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        })
+}
+
+fn create_bind_group(ctx: &TestingContext, layout: &wgpu::BindGroupLayout) -> wgpu::BindGroup {
+    let buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+        label: None,
+        size: 16,
+        usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::STORAGE,
+        mapped_at_creation: false,
+    });
+    ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: None,
+        layout,
+        entries: &[wgpu::BindGroupEntry {
+            binding: 0,
+            resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                buffer: &buffer,
+                offset: 0,
+                size: wgpu::BufferSize::new(16),
+            }),
+        }],
+    })
+}
+
+/// A compute pipeline using a uniform buffer at group 0, binding 0.
+fn create_compute_pipeline(
+    ctx: &TestingContext,
+    layout: &wgpu::BindGroupLayout,
+) -> wgpu::ComputePipeline {
+    let shader = ctx
+        .device
+        .create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: None,
+            source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+        });
+    let pipeline_layout = ctx
+        .device
+        .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: None,
+            bind_group_layouts: &[layout],
+            push_constant_ranges: &[],
+        });
+    ctx.device
+        .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("compat pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader,
+            entry_point: "cs_main",
+        })
+}
+
+/// Run a single dispatch with `bind_group` set at group 0, if any, and check that
+/// the pass is rejected with an error mentioning everything in `expected`.
+fn dispatch_test_impl(
+    ctx: &TestingContext,
+    pipeline: &wgpu::ComputePipeline,
+    bind_group: Option<(&wgpu::BindGroup, &[wgpu::DynamicOffset])>,
+    expected: &[&str],
+) {
+    let encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    let error = record_and_finish(&ctx.device, encoder, |encoder| {
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+        pass.set_pipeline(pipeline);
+        if let Some((bind_group, offsets)) = bind_group {
+            pass.set_bind_group(0, bind_group, offsets);
+        }
+        pass.dispatch_workgroups(1, 1, 1);
+    })
+    .expect_err("invalid dispatch was not rejected");
+    for expected in expected {
+        assert!(error.contains(expected), "{expected:?} not in {error:?}");
+    }
+}
+
+fn compute_params() -> TestParameters {
+    TestParameters::default().downlevel_flags(wgpu::DownlevelFlags::COMPUTE_SHADERS)
+}
+
+#[gpu_test]
+static DISPATCH_MISSING_BIND_GROUP: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(compute_params())
+    .run_sync(|ctx| {
+        let layout = create_layout(&ctx, wgpu::BufferBindingType::Uniform, false);
+        let pipeline = create_compute_pipeline(&ctx, &layout);
+
+        dispatch_test_impl(
+            &ctx,
+            &pipeline,
+            None,
+            &[
+                "Bind group at index 0 is required by the current compute pipeline, but is not set",
+                "compute pipeline = `compat pipeline`",
+            ],
+        );
+    });
+
+#[gpu_test]
+static DISPATCH_INCOMPATIBLE_BIND_GROUP: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(compute_params())
+    .run_sync(|ctx| {
+        let layout = create_layout(&ctx, wgpu::BufferBindingType::Uniform, false);
+        let pipeline = create_compute_pipeline(&ctx, &layout);
+        let other_layout = create_layout(
+            &ctx,
+            wgpu::BufferBindingType::Storage { read_only: true },
+            false,
+        );
+        let bind_group = create_bind_group(&ctx, &other_layout);
+
+        dispatch_test_impl(
+            &ctx,
+            &pipeline,
+            Some((&bind_group, &[])),
+            &[
+                "Incompatible bind group at index 0",
+                "compute pipeline = `compat pipeline`",
+                "Entry 0 type expected",
+            ],
+        );
+    });
+
+#[gpu_test]
+static SET_BIND_GROUP_DYNAMIC_OFFSET_COUNT: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(compute_params())
+    .run_sync(|ctx| {
+        let layout = create_layout(&ctx, wgpu::BufferBindingType::Uniform, true);
+        let pipeline = create_compute_pipeline(&ctx, &layout);
+        let bind_group = create_bind_group(&ctx, &layout);
+
+        dispatch_test_impl(
+            &ctx,
+            &pipeline,
+            Some((&bind_group, &[])),
+            &["Bind group 0 expects 1 dynamic offset. However 0 dynamic offset were provided."],
+        );
+        dispatch_test_impl(
+            &ctx,
+            &pipeline,
+            Some((&bind_group, &[0, 0])),
+            &["Bind group 0 expects 1 dynamic offset. However 2 dynamic offsets were provided."],
+        );
+    });
+
+#[gpu_test]
+static DRAW_MISSING_BIND_GROUP: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        let layout = create_layout(&ctx, wgpu::BufferBindingType::Uniform, false);
+        let shader = ctx
+            .device
+            .create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: None,
+                source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+            });
+        let pipeline_layout = ctx
+            .device
+            .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: None,
+                bind_group_layouts: &[&layout],
+                push_constant_ranges: &[],
+            });
+        let pipeline = ctx
+            .device
+            .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("compat pipeline"),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                },
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::PointList,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_main",
+                    targets: &[Some(wgpu::TextureFormat::Rgba8Unorm.into())],
+                }),
+                multiview: None,
+            });
+        let target = ctx.device.create_texture(&wgpu::TextureDescriptor {
+            label: None,
+            size: wgpu::Extent3d {
+                width: 1,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+        let view = target.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        let error = record_and_finish(&ctx.device, encoder, |encoder| {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: None,
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: wgpu::Operations::default(),
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_pipeline(&pipeline);
+            pass.draw(0..1, 0..1);
+        })
+        .expect_err("draw without its bind group was not rejected");
+        for expected in [
+            "Bind group at index 0 is required by the current render pipeline, but is not set",
+            "render pipeline = `compat pipeline`",
+        ] {
+            assert!(error.contains(expected), "{expected:?} not in {error:?}");
+        }
+    });
@@ -1,7 +1,7 @@
 use std::num::NonZeroU64;
 
 use wgpu_test::{
-    fail, gpu_test, FailureCase, GpuTestConfiguration, TestParameters, TestingContext,
+    fail, gpu_test, valid, FailureCase, GpuTestConfiguration, TestParameters, TestingContext,
 };
 use wgt::Backends;
 
@@ -442,3 +442,90 @@ fn derived_bgls_incompatible_with_regular_bgls(ctx: TestingContext) {
         drop(pass);
     })
 }
+
+#[gpu_test]
+static BIND_GROUP_LAYOUT_DEDUPLICATION_INTERCHANGEABLE: GpuTestConfiguration =
+    GpuTestConfiguration::new()
+        .parameters(TestParameters::default().test_features_limits())
+        .run_sync(bgl_dedupe_interchangeable);
+
+fn bgl_dedupe_interchangeable(ctx: TestingContext) {
+    // Identical entries with different labels.
+    let bgl_a = ctx
+        .device
+        .create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("material a"),
+            entries: &[ENTRY],
+        });
+    let bgl_b = ctx
+        .device
+        .create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("material b"),
+            entries: &[ENTRY],
+        });
+
+    if ctx.adapter_info.backend != wgt::Backend::BrowserWebGpu {
+        let global_report = ctx.instance.generate_report();
+        let report = global_report.hub_report(ctx.adapter_info.backend);
+        assert_eq!(report.bind_group_layouts.num_kept_from_user, 2);
+        assert_eq!(report.bind_group_layouts.num_shared, 1);
+    }
+
+    let buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+        label: None,
+        size: 4,
+        usage: wgpu::BufferUsages::UNIFORM,
+        mapped_at_creation: false,
+    });
+
+    let module = ctx
+        .device
+        .create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: None,
+            source: wgpu::ShaderSource::Wgsl(SHADER_SRC.into()),
+        });
+
+    // Bind groups made from either layout work with pipelines made from either layout.
+    for (pipeline_bgl, bind_group_bgl) in [(&bgl_a, &bgl_b), (&bgl_b, &bgl_a)] {
+        let pipeline_layout = ctx
+            .device
+            .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: None,
+                bind_group_layouts: &[pipeline_bgl],
+                push_constant_ranges: &[],
+            });
+
+        let pipeline = ctx
+            .device
+            .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: None,
+                layout: Some(&pipeline_layout),
+                module: &module,
+                entry_point: "resources",
+            });
+
+        let bg = ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: bind_group_bgl,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: buffer.as_entire_binding(),
+            }],
+        });
+
+        let mut encoder = ctx.device.create_command_encoder(&Default::default());
+
+        valid(&ctx.device, || {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: None,
+                timestamp_writes: None,
+            });
+
+            pass.set_pipeline(&pipeline);
+            pass.set_bind_group(0, &bg, &[]);
+            pass.dispatch_workgroups(1, 1, 1);
+        });
+
+        ctx.queue.submit(Some(encoder.finish()));
+    }
+}
@@ -0,0 +1,270 @@
+//! Tests for arrays of sampled textures, bound with [`wgpu::BindingResource::TextureViewArray`].
+
+use std::num::NonZeroU32;
+
+use wgpu::util::DeviceExt;
+use wgpu_test::{fail, gpu_test, valid, GpuTestConfiguration, TestParameters, TestingContext};
+
+const COUNT: u32 = 4;
+/// The largest array the tests bind.
+const MAX_COUNT: u32 = 16;
+const COLORS: [[u8; 4]; COUNT as usize] = [
+    [255, 0, 0, 255],
+    [0, 255, 0, 255],
+    [0, 0, 255, 255],
+    [255, 255, 255, 255],
+];
+
+const SHADER: &str = r#"
+@group(0) @binding(0)
+var textures: binding_array<texture_2d<f32>>;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) @interpolate(flat) index: u32,
+}
+
+// Each instance covers one column of a 4x1 target with a quad.
+@vertex
+fn vs_main(
+    @builtin(vertex_index) vertex_index: u32,
+    @builtin(instance_index) instance_index: u32,
+) -> VertexOutput {
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(0.0, 0.0),
+        vec2<f32>(1.0, 0.0),
+        vec2<f32>(0.0, 1.0),
+        vec2<f32>(0.0, 1.0),
+        vec2<f32>(1.0, 0.0),
+        vec2<f32>(1.0, 1.0),
+    );
+    let corner = corners[vertex_index];
+    let x = -1.0 + (f32(instance_index) + corner.x) * 0.5;
+    let y = -1.0 + corner.y * 2.0;
+    return VertexOutput(vec4<f32>(x, y, 0.0, 1.0), instance_index);
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return textureLoad(textures[in.index], vec2<i32>(0, 0), 0);
+}
+"#;
+
+fn create_layout(ctx: &TestingContext, count: u32) -> wgpu::BindGroupLayout {
+    ctx.device
+        .create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: None,
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: NonZeroU32::new(count),
+            }],
+        })
+}
+
+fn binding_array_params(features: wgpu::Features) -> TestParameters {
+    TestParameters::default()
+        .features(wgpu::Features::TEXTURE_BINDING_ARRAY | features)
+        .limits(wgpu::Limits {
+            max_binding_array_elements: MAX_COUNT,
+            ..wgpu::Limits::downlevel_webgl2_defaults()
+        })
+}
+
+/// A 1x1 texture for each of [`COLORS`].
+fn create_views(ctx: &TestingContext) -> Vec<wgpu::TextureView> {
+    COLORS
+        .iter()
+        .map(|color| {
+            ctx.device
+                .create_texture_with_data(
+                    &ctx.queue,
+                    &wgpu::TextureDescriptor {
+                        label: None,
+                        size: wgpu::Extent3d {
+                            width: 1,
+                            height: 1,
+                            depth_or_array_layers: 1,
+                        },
+                        mip_level_count: 1,
+                        sample_count: 1,
+                        dimension: wgpu::TextureDimension::D2,
+                        format: wgpu::TextureFormat::Rgba8Unorm,
+                        usage: wgpu::TextureUsages::TEXTURE_BINDING,
+                        view_formats: &[],
+                    },
+                    wgpu::util::TextureDataOrder::LayerMajor,
+                    color,
+                )
+                .create_view(&wgpu::TextureViewDescriptor::default())
+        })
+        .collect()
+}
+
+/// Draw `instances` columns of a [`COUNT`]x1 target, each sampling the array element
+/// with its instance index, and check the target against `expected`.
+fn draw_columns(
+    ctx: &TestingContext,
+    layout: &wgpu::BindGroupLayout,
+    bind_group: &wgpu::BindGroup,
+    instances: u32,
+    expected: &[u8],
+) {
+    let shader = ctx
+        .device
+        .create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: None,
+            source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+        });
+    let pipeline_layout = ctx
+        .device
+        .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: None,
+            bind_group_layouts: &[layout],
+            push_constant_ranges: &[],
+        });
+    let pipeline = ctx
+        .device
+        .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: None,
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::TextureFormat::Rgba8Unorm.into())],
+            }),
+            multiview: None,
+        });
+
+    let target = ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("target"),
+        size: wgpu::Extent3d {
+            width: COUNT,
+            height: 1,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let target_view = target.create_view(&wgpu::TextureViewDescriptor::default());
+    let readback_buffers = wgpu_test::image::ReadbackBuffers::new(&ctx.device, &target);
+
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    {
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: None,
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &target_view,
+                resolve_target: None,
+                ops: wgpu::Operations::default(),
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        pass.set_pipeline(&pipeline);
+        pass.set_bind_group(0, bind_group, &[]);
+        pass.draw(0..6, 0..instances);
+    }
+    readback_buffers.copy_from(&ctx.device, &mut encoder, &target);
+    ctx.queue.submit(Some(encoder.finish()));
+
+    readback_buffers.assert_buffer_contents(&ctx.device, expected);
+}
+
+#[gpu_test]
+static BINDING_ARRAY_INDEXED_PER_INSTANCE: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(binding_array_params(
+        wgpu::Features::SAMPLED_TEXTURE_AND_STORAGE_BUFFER_ARRAY_NON_UNIFORM_INDEXING,
+    ))
+    .run_sync(|ctx| {
+        let layout = create_layout(&ctx, COUNT);
+        let views = create_views(&ctx);
+        let view_refs: Vec<_> = views.iter().collect();
+        let bind_group = ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureViewArray(&view_refs),
+            }],
+        });
+
+        draw_columns(&ctx, &layout, &bind_group, COUNT, &COLORS.concat());
+    });
+
+#[gpu_test]
+static BINDING_ARRAY_TOO_LARGE: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(binding_array_params(wgpu::Features::empty()))
+    .run_sync(|ctx| {
+        valid(&ctx.device, || create_layout(&ctx, MAX_COUNT));
+        fail(&ctx.device, || create_layout(&ctx, MAX_COUNT + 1));
+    });
+
+#[gpu_test]
+static BINDING_ARRAY_LENGTH_MISMATCH: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(binding_array_params(wgpu::Features::empty()))
+    .run_sync(|ctx| {
+        let layout = create_layout(&ctx, COUNT);
+        let views = create_views(&ctx);
+        let view_refs: Vec<_> = views.iter().collect();
+
+        // Without `PARTIALLY_BOUND_BINDING_ARRAY`, every element must be bound.
+        fail(&ctx.device, || {
+            ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: None,
+                layout: &layout,
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureViewArray(&view_refs[..3]),
+                }],
+            })
+        });
+    });
+
+#[gpu_test]
+static BINDING_ARRAY_PARTIALLY_BOUND: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(binding_array_params(
+        wgpu::Features::SAMPLED_TEXTURE_AND_STORAGE_BUFFER_ARRAY_NON_UNIFORM_INDEXING
+            | wgpu::Features::PARTIALLY_BOUND_BINDING_ARRAY,
+    ))
+    .run_sync(|ctx| {
+        // Only the first three of sixteen slots are bound, and only those are sampled.
+        let layout = create_layout(&ctx, MAX_COUNT);
+        let views = create_views(&ctx);
+        let view_refs: Vec<_> = views.iter().take(3).collect();
+        let bind_group = valid(&ctx.device, || {
+            ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: None,
+                layout: &layout,
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureViewArray(&view_refs),
+                }],
+            })
+        });
+
+        let mut expected = COLORS[..3].concat();
+        // The last column isn't drawn, and keeps the transparent black it was cleared to.
+        expected.extend([0; 4]);
+        draw_columns(&ctx, &layout, &bind_group, 3, &expected);
+    });
@@ -0,0 +1,115 @@
+//! Tests for the runtime bounds checks naga inserts into shaders, and for opting out of them with
+//! [`wgpu::Device::create_shader_module_unchecked`].
+
+use wgpu::util::DeviceExt;
+use wgpu_test::{
+    gpu_test, read_buffer, FailureCase, GpuTestConfiguration, TestParameters, TestingContext,
+};
+
+const INPUT: [u32; 4] = [1, 2, 3, 4];
+
+// `output[0]` holds the index to read on the way in, and the value read on the way out.
+const SHADER: &str = r#"
+@group(0) @binding(0)
+var<storage, read> input: array<u32>;
+@group(0) @binding(1)
+var<storage, read_write> output: array<u32>;
+
+@compute @workgroup_size(1)
+fn main() {
+    output[0] = input[output[0]];
+}
+"#;
+
+fn shader_descriptor() -> wgpu::ShaderModuleDescriptor<'static> {
+    wgpu::ShaderModuleDescriptor {
+        label: None,
+        source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+    }
+}
+
+/// Run [`SHADER`] from `module` and return what it read from `input[index]`.
+fn read_input(ctx: &TestingContext, module: &wgpu::ShaderModule, index: u32) -> u32 {
+    let pipeline = ctx
+        .device
+        .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: None,
+            layout: None,
+            module,
+            entry_point: "main",
+        });
+
+    let input = ctx
+        .device
+        .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("input"),
+            contents: bytemuck::cast_slice(&INPUT),
+            usage: wgpu::BufferUsages::STORAGE,
+        });
+    let output = ctx
+        .device
+        .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("output"),
+            contents: bytemuck::bytes_of(&index),
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+        });
+    let bind_group = ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: None,
+        layout: &pipeline.get_bind_group_layout(0),
+        entries: &[
+            wgpu::BindGroupEntry {
+                binding: 0,
+                resource: input.as_entire_binding(),
+            },
+            wgpu::BindGroupEntry {
+                binding: 1,
+                resource: output.as_entire_binding(),
+            },
+        ],
+    });
+
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    {
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+        pass.set_pipeline(&pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.dispatch_workgroups(1, 1, 1);
+    }
+    ctx.queue.submit(Some(encoder.finish()));
+
+    bytemuck::pod_read_unaligned(&read_buffer(&ctx.device, &ctx.queue, &output))
+}
+
+#[gpu_test]
+static BOUNDS_CHECKED_READ: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(
+        TestParameters::default()
+            .downlevel_flags(wgpu::DownlevelFlags::COMPUTE_SHADERS)
+            // Naga's GLSL output doesn't check buffer accesses.
+            .skip(FailureCase::backend(wgpu::Backends::GL)),
+    )
+    .run_sync(|ctx| {
+        let module = ctx.device.create_shader_module(shader_descriptor());
+        assert_eq!(read_input(&ctx, &module, 2), 3);
+
+        // Depending on the backend, the read is clamped into the array or returns zero.
+        let value = read_input(&ctx, &module, 1000);
+        assert!(
+            value == 0 || INPUT.contains(&value),
+            "the out of bounds read returned {value}"
+        );
+    });
+
+#[gpu_test]
+static BOUNDS_UNCHECKED_READ: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().downlevel_flags(wgpu::DownlevelFlags::COMPUTE_SHADERS))
+    .run_sync(|ctx| {
+        // SAFETY: The shader is only run with an index that is in bounds.
+        let module = unsafe {
+            ctx.device
+                .create_shader_module_unchecked(shader_descriptor())
+        };
+        assert_eq!(read_input(&ctx, &module, 2), 3);
+    });
@@ -1,4 +1,9 @@
-use wgpu_test::{gpu_test, FailureCase, GpuTestConfiguration, TestParameters, TestingContext};
+use std::sync::{Arc, Mutex};
+
+use wgpu::util::DeviceExt;
+use wgpu_test::{
+    fail, gpu_test, valid, FailureCase, GpuTestConfiguration, TestParameters, TestingContext,
+};
 
 fn test_empty_buffer_range(ctx: &TestingContext, buffer_size: u64, label: &str) {
     let r = wgpu::BufferUsages::MAP_READ;
@@ -154,3 +159,339 @@ static MAP_OFFSET: GpuTestConfiguration = GpuTestConfiguration::new().run_sync(|
         assert_eq!(*byte, 0);
     }
 });
+
+#[gpu_test]
+static CREATE_BUFFER_INIT_MAPPED: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        // A staging buffer stays mapped, with the contents already written and
+        // the padding zeroed, so it can be filled further before unmapping.
+        let staging = ctx
+            .device
+            .create_buffer_init_mapped(&wgpu::util::BufferInitDescriptor {
+                label: Some("staging"),
+                contents: &[1, 2, 3, 4, 5, 6],
+                usage: wgpu::BufferUsages::MAP_WRITE | wgpu::BufferUsages::COPY_SRC,
+            });
+        assert_eq!(staging.size(), 8);
+        {
+            let mut view = staging.slice(..).get_mapped_range_mut();
+            assert_eq!(&view[..], &[1, 2, 3, 4, 5, 6, 0, 0]);
+            view[6] = 7;
+            view[7] = 8;
+        }
+        staging.unmap();
+
+        let read_buf = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: 8,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        encoder.copy_buffer_to_buffer(&staging, 0, &read_buf, 0, 8);
+        ctx.queue.submit(Some(encoder.finish()));
+
+        read_buf
+            .slice(..)
+            .map_async(wgpu::MapMode::Read, Result::unwrap);
+        ctx.device.poll(wgpu::MaintainBase::Wait);
+
+        assert_eq!(
+            &read_buf.slice(..).get_mapped_range()[..],
+            &[1, 2, 3, 4, 5, 6, 7, 8]
+        );
+    });
+
+#[gpu_test]
+static CREATE_BUFFER_INIT_EMPTY: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        let buffer = ctx
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("empty"),
+                contents: &[],
+                usage: wgpu::BufferUsages::MAP_READ,
+            });
+        assert_eq!(buffer.size(), 0);
+
+        // The mapped variant takes the same unmapped path for empty contents, so
+        // the buffer can be mapped later like any other.
+        let unmapped = ctx
+            .device
+            .create_buffer_init_mapped(&wgpu::util::BufferInitDescriptor {
+                label: Some("empty mapped"),
+                contents: &[],
+                usage: wgpu::BufferUsages::MAP_WRITE | wgpu::BufferUsages::COPY_SRC,
+            });
+        assert_eq!(unmapped.size(), 0);
+
+        ctx.device.push_error_scope(wgpu::ErrorFilter::Validation);
+        unmapped
+            .slice(..)
+            .map_async(wgpu::MapMode::Write, Result::unwrap);
+        ctx.device.poll(wgpu::Maintain::Wait);
+        assert!(pollster::block_on(ctx.device.pop_error_scope()).is_none());
+    });
+
+#[gpu_test]
+static CREATE_BUFFER_INIT_MAP_READ: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        // A MAP_READ buffer created with initial contents can still be written by
+        // the GPU and read back afterwards.
+        let read_buf = ctx
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("readback"),
+                contents: &[0xFF; 16],
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            });
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        encoder.clear_buffer(&read_buf, 4, Some(8));
+        ctx.queue.submit(Some(encoder.finish()));
+
+        read_buf
+            .slice(..)
+            .map_async(wgpu::MapMode::Read, Result::unwrap);
+        ctx.device.poll(wgpu::MaintainBase::Wait);
+
+        let view = read_buf.slice(..).get_mapped_range();
+        assert_eq!(&view[0..4], &[0xFF; 4]);
+        assert_eq!(&view[4..12], &[0; 8]);
+        assert_eq!(&view[12..16], &[0xFF; 4]);
+    });
+
+#[gpu_test]
+static MAP_DISJOINT_RANGES: GpuTestConfiguration = GpuTestConfiguration::new().run_sync(|ctx| {
+    let write_buf = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+        label: None,
+        size: 32,
+        usage: wgpu::BufferUsages::MAP_WRITE | wgpu::BufferUsages::COPY_SRC,
+        mapped_at_creation: true,
+    });
+    let read_buf = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+        label: None,
+        size: 32,
+        usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+
+    {
+        let mut header = write_buf.slice(0..8).get_mapped_range_mut();
+        let mut payload = write_buf.slice(16..32).get_mapped_range_mut();
+        header.fill(1);
+        payload.fill(2);
+
+        // Panics abort on wasm, so they can't be caught there.
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let overlap = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                write_buf.slice(4..20).get_mapped_range_mut();
+            }));
+            let panic = overlap.expect_err("overlapping views must panic");
+            let message = panic
+                .downcast_ref::<String>()
+                .expect("panic payload should be a formatted message");
+            assert!(message.contains("Range 4..20 overlaps"), "{message}");
+        }
+    }
+    write_buf.unmap();
+
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    encoder.copy_buffer_to_buffer(&write_buf, 0, &read_buf, 0, 32);
+    ctx.queue.submit(Some(encoder.finish()));
+
+    read_buf
+        .slice(..)
+        .map_async(wgpu::MapMode::Read, Result::unwrap);
+    ctx.device.poll(wgpu::MaintainBase::Wait);
+    let view = read_buf.slice(..).get_mapped_range();
+    assert_eq!(&view[0..8], &[1; 8]);
+    assert_eq!(&view[8..16], &[0; 8]);
+    assert_eq!(&view[16..32], &[2; 16]);
+});
+
+#[gpu_test]
+static CLEAR_BUFFER_VALIDATION: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        let buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: 16,
+            usage: wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let no_copy_dst = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: 16,
+            usage: wgpu::BufferUsages::VERTEX,
+            mapped_at_creation: false,
+        });
+
+        let invalid_clears = [
+            ("missing COPY_DST", &no_copy_dst, 0, None),
+            ("unaligned offset", &buffer, 2, None),
+            ("unaligned size", &buffer, 0, Some(6)),
+            ("overrun", &buffer, 8, Some(12)),
+            ("offset past the end", &buffer, 20, None),
+        ];
+        for (case, buffer, offset, size) in invalid_clears {
+            log::info!("Testing clear_buffer with {case}");
+            fail(&ctx.device, || {
+                let mut encoder = ctx
+                    .device
+                    .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+                encoder.clear_buffer(buffer, offset, size);
+                encoder.finish()
+            });
+        }
+
+        valid(&ctx.device, || {
+            let mut encoder = ctx
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+            encoder.clear_buffer(&buffer, 16, None);
+            encoder.clear_buffer(&buffer, 4, Some(12));
+            ctx.queue.submit(Some(encoder.finish()));
+        });
+    });
+
+#[gpu_test]
+static CREATE_BUFFER_INIT_UNALIGNED: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        // Six bytes of contents are padded with zeros to an aligned size.
+        let buffer = ctx
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: None,
+                contents: &[1, 2, 3, 4, 5, 6],
+                usage: wgpu::BufferUsages::MAP_READ,
+            });
+        assert_eq!(buffer.size(), 8);
+
+        buffer
+            .slice(..)
+            .map_async(wgpu::MapMode::Read, Result::unwrap);
+        ctx.device.poll(wgpu::MaintainBase::Wait);
+        let view = buffer.slice(..).get_mapped_range();
+        assert_eq!(&view[..], &[1, 2, 3, 4, 5, 6, 0, 0]);
+    });
+
+#[gpu_test]
+static MAPPED_AT_CREATION_UNALIGNED: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        ctx.device.push_error_scope(wgpu::ErrorFilter::Validation);
+        let _buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: 6,
+            usage: wgpu::BufferUsages::MAP_WRITE,
+            mapped_at_creation: true,
+        });
+        let error = pollster::block_on(ctx.device.pop_error_scope())
+            .expect("unaligned mapped_at_creation was not rejected")
+            .to_string();
+        assert!(
+            error.contains("multiple of `COPY_BUFFER_ALIGNMENT` (4), but the size is 6"),
+            "{error:?}"
+        );
+    });
+
+/// Map `slice` and return the result that was passed to the callback.
+fn map_result(
+    ctx: &TestingContext,
+    slice: wgpu::BufferSlice<'_>,
+    mode: wgpu::MapMode,
+) -> Result<(), wgpu::BufferAsyncError> {
+    let result = Arc::new(Mutex::new(None));
+    let callback_result = Arc::clone(&result);
+    slice.map_async(mode, move |res| {
+        *callback_result.lock().unwrap() = Some(res);
+    });
+    ctx.device.poll(wgpu::MaintainBase::Wait);
+    let res = result.lock().unwrap().take();
+    res.expect("map callback was not called")
+}
+
+/// The browser's WebGPU reports every failed mapping as the same opaque error, and
+/// `Maintain::Wait` doesn't wait for the callback there.
+fn map_async_params() -> TestParameters {
+    TestParameters::default().skip(FailureCase::backend(wgpu::Backends::BROWSER_WEBGPU))
+}
+
+#[gpu_test]
+static MAP_ASYNC_ERRORS: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(map_async_params())
+    .run_sync(|ctx| {
+        let create_buffer = |usage| {
+            ctx.device.create_buffer(&wgpu::BufferDescriptor {
+                label: None,
+                size: 16,
+                usage,
+                mapped_at_creation: false,
+            })
+        };
+        let read_usage = wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST;
+
+        let buffer = create_buffer(read_usage);
+        let res = fail(&ctx.device, || {
+            map_result(&ctx, buffer.slice(..), wgpu::MapMode::Write)
+        });
+        assert_eq!(res, Err(wgpu::BufferAsyncError::InvalidUsage));
+
+        let buffer = create_buffer(read_usage);
+        let res = fail(&ctx.device, || {
+            map_result(&ctx, buffer.slice(0..32), wgpu::MapMode::Read)
+        });
+        assert_eq!(
+            res,
+            Err(wgpu::BufferAsyncError::RangeOutOfBounds {
+                requested: 0..32,
+                size: 16,
+            })
+        );
+
+        let buffer = create_buffer(read_usage);
+        buffer.destroy();
+        let res = fail(&ctx.device, || {
+            map_result(&ctx, buffer.slice(..), wgpu::MapMode::Read)
+        });
+        assert_eq!(res, Err(wgpu::BufferAsyncError::Destroyed));
+
+        // Unmapping before the device is polled aborts the pending mapping.
+        let buffer = create_buffer(read_usage);
+        let result = Arc::new(Mutex::new(None));
+        let callback_result = Arc::clone(&result);
+        buffer.slice(..).map_async(wgpu::MapMode::Read, move |res| {
+            *callback_result.lock().unwrap() = Some(res);
+        });
+        buffer.unmap();
+        ctx.device.poll(wgpu::MaintainBase::Wait);
+        assert_eq!(
+            result.lock().unwrap().take(),
+            Some(Err(wgpu::BufferAsyncError::Aborted))
+        );
+    });
+
+#[gpu_test]
+static MAP_ASYNC_DEVICE_LOST: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(map_async_params())
+    .run_sync(|ctx| {
+        let buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: 16,
+            usage: wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+        ctx.device.destroy();
+
+        let res = fail(&ctx.device, || {
+            map_result(&ctx, buffer.slice(..), wgpu::MapMode::Read)
+        });
+        assert_eq!(res, Err(wgpu::BufferAsyncError::DeviceLost));
+    });
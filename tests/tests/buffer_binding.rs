@@ -0,0 +1,154 @@
+//! Tests for the validation of buffer binding sizes and dynamic offsets.
+
+use wgpu_test::{
+    gpu_test, record_and_finish, valid, GpuTestConfiguration, TestParameters, TestingContext,
+};
+
+/// Size of the uniform binding, which is also the layout's `min_binding_size`.
+const BINDING_SIZE: u64 = 16;
+
+fn create_layout(ctx: &TestingContext, has_dynamic_offset: bool) -> wgpu::BindGroupLayout {
+    ctx.device
+        .create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: None,
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset,
+                    min_binding_size: wgpu::BufferSize::new(BINDING_SIZE),
+                },
+                count: None,
+            }],
+        })
+}
+
+fn create_bind_group(
+    ctx: &TestingContext,
+    layout: &wgpu::BindGroupLayout,
+    buffer: &wgpu::Buffer,
+    size: u64,
+) -> wgpu::BindGroup {
+    ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: None,
+        layout,
+        entries: &[wgpu::BindGroupEntry {
+            binding: 0,
+            resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                buffer,
+                offset: 0,
+                size: wgpu::BufferSize::new(size),
+            }),
+        }],
+    })
+}
+
+/// Set `bind_group` with a single dynamic `offset` in a compute pass, and return the
+/// validation error, if any.
+fn set_bind_group_error(
+    ctx: &TestingContext,
+    bind_group: &wgpu::BindGroup,
+    offset: wgpu::DynamicOffset,
+) -> Option<String> {
+    let encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    record_and_finish(&ctx.device, encoder, |encoder| {
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+        pass.set_bind_group(0, bind_group, &[offset]);
+    })
+    .err()
+}
+
+fn assert_contains(error: Option<String>, expected: &str) {
+    let error = error.expect("invalid binding was not rejected");
+    assert!(error.contains(expected), "{expected:?} not in {error:?}");
+}
+
+fn compute_params() -> TestParameters {
+    TestParameters::default().downlevel_flags(wgpu::DownlevelFlags::COMPUTE_SHADERS)
+}
+
+#[gpu_test]
+static DYNAMIC_OFFSET_UNALIGNED: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(compute_params())
+    .run_sync(|ctx| {
+        let alignment = ctx.device.limits().min_uniform_buffer_offset_alignment;
+        let layout = create_layout(&ctx, true);
+        let buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: alignment as u64 * 2,
+            usage: wgpu::BufferUsages::UNIFORM,
+            mapped_at_creation: false,
+        });
+        let bind_group = create_bind_group(&ctx, &layout, &buffer, BINDING_SIZE);
+
+        assert_eq!(set_bind_group_error(&ctx, &bind_group, alignment), None);
+        assert_contains(
+            set_bind_group_error(&ctx, &bind_group, alignment / 2),
+            &format!(
+                "binding 0) with value {}, does not respect device's requested \
+                 `min_uniform_buffer_offset_alignment` limit: {alignment}",
+                alignment / 2
+            ),
+        );
+    });
+
+#[gpu_test]
+static DYNAMIC_OFFSET_OUT_OF_BOUNDS: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(compute_params())
+    .run_sync(|ctx| {
+        let alignment = ctx.device.limits().min_uniform_buffer_offset_alignment;
+        let layout = create_layout(&ctx, true);
+        let buffer_size = alignment as u64 * 2;
+        let buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: buffer_size,
+            usage: wgpu::BufferUsages::UNIFORM,
+            mapped_at_creation: false,
+        });
+        let bind_group = create_bind_group(&ctx, &layout, &buffer, BINDING_SIZE);
+
+        // The last binding that fits ends exactly at the end of the buffer.
+        assert_contains(
+            set_bind_group_error(&ctx, &bind_group, alignment * 2),
+            &format!(
+                "binding 0. Buffer size is {buffer_size} bytes, the binding binds bytes \
+                 0..{BINDING_SIZE}, meaning the maximum the binding can be offset is {} bytes",
+                buffer_size - BINDING_SIZE
+            ),
+        );
+    });
+
+#[gpu_test]
+static BINDING_SMALLER_THAN_MIN_BINDING_SIZE: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| {
+        let layout = create_layout(&ctx, false);
+        let buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: BINDING_SIZE,
+            usage: wgpu::BufferUsages::UNIFORM,
+            mapped_at_creation: false,
+        });
+
+        valid(&ctx.device, || {
+            create_bind_group(&ctx, &layout, &buffer, BINDING_SIZE);
+        });
+
+        let encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        let error = record_and_finish(&ctx.device, encoder, |_| {
+            create_bind_group(&ctx, &layout, &buffer, BINDING_SIZE / 2);
+        })
+        .err();
+        assert_contains(
+            error,
+            &format!(
+                "Buffer binding 0 has size {}, which is less than the minimum binding size \
+                 {BINDING_SIZE} of its layout",
+                BINDING_SIZE / 2
+            ),
+        );
+    });
@@ -40,3 +40,86 @@ const BUFFER_DESCRIPTOR: wgpu::BufferDescriptor = wgpu::BufferDescriptor {
     usage: wgpu::BufferUsages::COPY_SRC.union(wgpu::BufferUsages::COPY_DST),
     mapped_at_creation: false,
 };
+
+/// Copy between `source` and `destination` and return the validation error, if any.
+fn copy_error(
+    ctx: &wgpu_test::TestingContext,
+    source: &wgpu::Buffer,
+    source_offset: BufferAddress,
+    destination: &wgpu::Buffer,
+    destination_offset: BufferAddress,
+    size: BufferAddress,
+) -> Option<String> {
+    ctx.device.push_error_scope(wgpu::ErrorFilter::Validation);
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    encoder.copy_buffer_to_buffer(source, source_offset, destination, destination_offset, size);
+    drop(encoder.finish());
+    pollster::block_on(ctx.device.pop_error_scope()).map(|error| error.to_string())
+}
+
+#[gpu_test]
+static COPY_BUFFER_TO_BUFFER_VALIDATION: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| {
+        let create_buffer = |label| {
+            ctx.device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some(label),
+                ..BUFFER_DESCRIPTOR
+            })
+        };
+        let a = create_buffer("buffer a");
+        let b = create_buffer("buffer b");
+
+        assert_eq!(copy_error(&ctx, &a, 0, &b, 64, 128), None);
+
+        // Copies within one buffer are rejected, whether or not the ranges overlap.
+        for (source_offset, destination_offset) in [(0, 64), (0, 512)] {
+            let error = copy_error(&ctx, &a, source_offset, &a, destination_offset, 128)
+                .expect("copy within the same buffer was not rejected");
+            assert!(error.contains("cannot be the same buffer"), "{error:?}");
+            assert!(error.contains("buffer a"), "{error:?}");
+        }
+
+        let error = copy_error(&ctx, &a, 2, &b, 0, 128).expect("unaligned copy was not rejected");
+        assert!(error.contains("Source buffer offset 2"), "{error:?}");
+        assert!(error.contains("buffer a"), "{error:?}");
+
+        let error = copy_error(&ctx, &a, 0, &b, 6, 128).expect("unaligned copy was not rejected");
+        assert!(error.contains("Destination buffer offset 6"), "{error:?}");
+        assert!(error.contains("buffer b"), "{error:?}");
+
+        let error = copy_error(&ctx, &a, 0, &b, 0, 130).expect("unaligned copy was not rejected");
+        assert!(error.contains("Copy size 130"), "{error:?}");
+    });
+
+#[gpu_test]
+static WRITE_BUFFER_ALIGNMENT_ERRORS: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        let buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("target buffer"),
+            ..BUFFER_DESCRIPTOR
+        });
+        let write_error = |offset, data: &[u8]| {
+            ctx.device.push_error_scope(wgpu::ErrorFilter::Validation);
+            ctx.queue.write_buffer(&buffer, offset, data);
+            pollster::block_on(ctx.device.pop_error_scope())
+                .expect("unaligned write was not rejected")
+                .to_string()
+        };
+
+        let error = write_error(2, &[0; 4]);
+        assert!(
+            error.contains(
+                "Destination buffer offset 2 is not a multiple of `COPY_BUFFER_ALIGNMENT` (4)"
+            ),
+            "{error:?}"
+        );
+        assert!(error.contains("target buffer"), "{error:?}");
+
+        let error = write_error(0, &[0; 6]);
+        assert!(
+            error.contains("Copy size 6 is not a multiple of `COPY_BUFFER_ALIGNMENT` (4)"),
+            "{error:?}"
+        );
+    });
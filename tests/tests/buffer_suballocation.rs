@@ -0,0 +1,126 @@
+//! Tests that small buffers share device memory blocks without corrupting each other.
+
+use wgpu::util::DeviceExt;
+use wgpu_test::{gpu_test, GpuTestConfiguration, TestingContext};
+
+const SMALL_BUFFER_COUNT: usize = 10_000;
+
+/// Number of live device memory allocations, on backends that report it.
+#[cfg(any(
+    windows,
+    all(
+        unix,
+        not(target_os = "emscripten"),
+        not(target_os = "ios"),
+        not(target_os = "macos")
+    )
+))]
+fn memory_allocation_count(ctx: &TestingContext) -> Option<usize> {
+    unsafe {
+        ctx.device.as_hal::<wgpu::hal::api::Vulkan, _, _>(|device| {
+            device.map(|device| device.memory_allocation_count())
+        })
+    }
+}
+
+#[cfg(not(any(
+    windows,
+    all(
+        unix,
+        not(target_os = "emscripten"),
+        not(target_os = "ios"),
+        not(target_os = "macos")
+    )
+)))]
+fn memory_allocation_count(_ctx: &TestingContext) -> Option<usize> {
+    None
+}
+
+#[gpu_test]
+static SMALL_BUFFERS_SHARE_ALLOCATIONS: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| {
+        let before = memory_allocation_count(&ctx);
+
+        let buffers: Vec<_> = (0..SMALL_BUFFER_COUNT)
+            .map(|_| {
+                ctx.device.create_buffer(&wgpu::BufferDescriptor {
+                    label: None,
+                    size: 256,
+                    usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+                    mapped_at_creation: false,
+                })
+            })
+            .collect();
+
+        // Only the Vulkan backend reports its allocation count. Elsewhere this still
+        // checks that creating this many buffers works.
+        if let (Some(before), Some(after)) = (before, memory_allocation_count(&ctx)) {
+            let allocations = after - before;
+            assert!(
+                allocations < SMALL_BUFFER_COUNT / 100,
+                "{SMALL_BUFFER_COUNT} small buffers took {allocations} device memory allocations"
+            );
+        }
+
+        drop(buffers);
+        ctx.device.poll(wgpu::Maintain::Wait);
+    });
+
+#[gpu_test]
+static SUBALLOCATED_NEIGHBORS_STAY_INTACT: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| {
+        const COUNT: usize = 1024;
+        const SIZE: wgpu::BufferAddress = 64;
+
+        let buffers: Vec<_> = (0..COUNT)
+            .map(|i| {
+                ctx.device
+                    .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                        label: None,
+                        contents: &[i as u8; SIZE as usize],
+                        usage: wgpu::BufferUsages::COPY_SRC | wgpu::BufferUsages::COPY_DST,
+                    })
+            })
+            .collect();
+        let readback = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("readback"),
+            size: SIZE * COUNT as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        // Clear every other buffer while its neighbors, which likely live in the same
+        // memory block, are being copied out in the same submission.
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        for (i, buffer) in buffers.iter().enumerate() {
+            if i % 2 == 0 {
+                encoder.clear_buffer(buffer, 0, None);
+            }
+        }
+        for (i, buffer) in buffers.iter().enumerate() {
+            encoder.copy_buffer_to_buffer(
+                buffer,
+                0,
+                &readback,
+                SIZE * i as wgpu::BufferAddress,
+                SIZE,
+            );
+        }
+        ctx.queue.submit(Some(encoder.finish()));
+
+        readback
+            .slice(..)
+            .map_async(wgpu::MapMode::Read, Result::unwrap);
+        ctx.device.poll(wgpu::Maintain::Wait);
+        let data = readback.slice(..).get_mapped_range();
+
+        for (i, chunk) in data.chunks_exact(SIZE as usize).enumerate() {
+            let expected = if i % 2 == 0 { 0 } else { i as u8 };
+            assert!(
+                chunk.iter().all(|&byte| byte == expected),
+                "buffer {i} holds {chunk:?}, expected {expected}"
+            );
+        }
+    });
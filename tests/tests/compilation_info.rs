@@ -0,0 +1,134 @@
+//! Tests for the diagnostics reported when a shader module fails to compile, through
+//! [`wgpu::ShaderModule::get_compilation_info`] and through the device's errors.
+
+use wgpu_test::{fail, gpu_test, valid, FailureCase, GpuTestConfiguration, TestParameters};
+
+const VALID_SHADER: &str = r#"
+@compute @workgroup_size(1)
+fn main() {}
+"#;
+
+// The comments hold characters that take more bytes in UTF-8 than code units in UTF-16, so the
+// two sets of positions in the reported location differ.
+const PARSE_ERROR_SHADER: &str = "// 😀
+@compute @workgroup_size(1)
+fn main() {
+    let x = /* é */ unknown_value;
+}
+";
+
+#[gpu_test]
+static COMPILATION_INFO_VALID_SHADER: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_async(|ctx| async move {
+        let module = valid(&ctx.device, || {
+            ctx.device
+                .create_shader_module(wgpu::ShaderModuleDescriptor {
+                    label: None,
+                    source: wgpu::ShaderSource::Wgsl(VALID_SHADER.into()),
+                })
+        });
+
+        let info = module.get_compilation_info().await;
+        assert!(
+            info.messages
+                .iter()
+                .all(|message| message.message_type != wgpu::CompilationMessageType::Error),
+            "a valid shader reported errors: {info:?}"
+        );
+    });
+
+#[gpu_test]
+static COMPILATION_INFO_PARSE_ERROR: GpuTestConfiguration = GpuTestConfiguration::new()
+    // Browsers word their messages and choose their spans differently.
+    .parameters(
+        TestParameters::default().skip(FailureCase::backend(wgpu::Backends::BROWSER_WEBGPU)),
+    )
+    .run_async(|ctx| async move {
+        let module = fail(&ctx.device, || {
+            ctx.device
+                .create_shader_module(wgpu::ShaderModuleDescriptor {
+                    label: None,
+                    source: wgpu::ShaderSource::Wgsl(PARSE_ERROR_SHADER.into()),
+                })
+        });
+
+        let info = module.get_compilation_info().await;
+        let [message] = &info.messages[..] else {
+            panic!("expected a single message: {info:?}");
+        };
+        assert_eq!(message.message_type, wgpu::CompilationMessageType::Error);
+        assert!(
+            message.message.contains("unknown_value"),
+            "the message does not name the identifier: {}",
+            message.message
+        );
+
+        let offset = PARSE_ERROR_SHADER.find("unknown_value").unwrap() as u32;
+        assert_eq!(
+            message.location,
+            Some(wgpu::SourceLocation {
+                line_number: 4,
+                line_position: 22,
+                offset,
+                length: 13,
+                line_position_utf16: 21,
+                offset_utf16: offset - 3,
+                length_utf16: 13,
+            })
+        );
+    });
+
+#[gpu_test]
+static COMPILATION_INFO_VALIDATION_ERROR: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_async(|ctx| async move {
+        // Parses fine, but returns a value of the wrong type.
+        let source = "fn f() -> f32 {\n    return 1u;\n}\n";
+        let module = fail(&ctx.device, || {
+            ctx.device
+                .create_shader_module(wgpu::ShaderModuleDescriptor {
+                    label: None,
+                    source: wgpu::ShaderSource::Wgsl(source.into()),
+                })
+        });
+
+        let info = module.get_compilation_info().await;
+        let error = info
+            .messages
+            .iter()
+            .find(|message| message.message_type == wgpu::CompilationMessageType::Error)
+            .expect("the invalid shader reported no errors");
+        let location = error
+            .location
+            .expect("the validation error has no location");
+        assert!(
+            location.line_number <= 2,
+            "the error points past the function: {location:?}"
+        );
+    });
+
+#[gpu_test]
+static SHADER_ERROR_NAMES_LABEL_AND_LINE: GpuTestConfiguration = GpuTestConfiguration::new()
+    // Browsers format their own error messages.
+    .parameters(
+        TestParameters::default().skip(FailureCase::backend(wgpu::Backends::BROWSER_WEBGPU)),
+    )
+    .run_sync(|ctx| {
+        let source = "fn f() -> f32 {\n    return 1u;\n}\n";
+        ctx.device.push_error_scope(wgpu::ErrorFilter::Validation);
+        ctx.device
+            .create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("returns_wrong_type.wgsl"),
+                source: wgpu::ShaderSource::Wgsl(source.into()),
+            });
+        let error = pollster::block_on(ctx.device.pop_error_scope())
+            .expect("the invalid shader was accepted");
+
+        // The snippet quotes the offending line, prefixed with its line number.
+        let message = error.to_string();
+        assert!(
+            message.contains("Shader 'returns_wrong_type.wgsl' validation error")
+                && message.contains("returns_wrong_type.wgsl:")
+                && message.contains("2 │     return 1u;"),
+            "the error does not point at the source: {message}"
+        );
+    });
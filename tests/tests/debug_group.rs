@@ -0,0 +1,160 @@
+//! Tests for debug groups and markers on command encoders and passes.
+
+use wgpu_test::{fail, gpu_test, valid, GpuTestConfiguration, TestParameters, TestingContext};
+
+fn create_target(ctx: &TestingContext) -> wgpu::TextureView {
+    ctx.device
+        .create_texture(&wgpu::TextureDescriptor {
+            label: None,
+            size: wgpu::Extent3d {
+                width: 4,
+                height: 4,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        })
+        .create_view(&wgpu::TextureViewDescriptor::default())
+}
+
+fn begin_render_pass<'a>(
+    encoder: &'a mut wgpu::CommandEncoder,
+    view: &'a wgpu::TextureView,
+) -> wgpu::RenderPass<'a> {
+    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: None,
+        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+            view,
+            resolve_target: None,
+            ops: wgpu::Operations::default(),
+        })],
+        depth_stencil_attachment: None,
+        timestamp_writes: None,
+        occlusion_query_set: None,
+    })
+}
+
+fn compute_params() -> TestParameters {
+    TestParameters::default().downlevel_flags(wgpu::DownlevelFlags::COMPUTE_SHADERS)
+}
+
+#[gpu_test]
+static DEBUG_GROUPS_BALANCED: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(compute_params())
+    .run_sync(|ctx| {
+        let view = create_target(&ctx);
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+
+        let command_buffer = valid(&ctx.device, || {
+            encoder.push_debug_group("encoder");
+            encoder.insert_debug_marker("encoder marker");
+            {
+                let mut pass = begin_render_pass(&mut encoder, &view);
+                pass.push_debug_group("render pass");
+                pass.insert_debug_marker("render pass marker");
+                pass.pop_debug_group();
+            }
+            {
+                let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+                pass.push_debug_group("compute pass");
+                pass.push_debug_group("nested");
+                pass.insert_debug_marker("compute pass marker");
+                pass.pop_debug_group();
+                pass.pop_debug_group();
+            }
+            encoder.pop_debug_group();
+            encoder.finish()
+        });
+        ctx.queue.submit(Some(command_buffer));
+    });
+
+#[gpu_test]
+static DEBUG_GROUP_ENCODER_NOT_POPPED: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        encoder.push_debug_group("never popped");
+        fail(&ctx.device, || encoder.finish());
+    });
+
+#[gpu_test]
+static DEBUG_GROUP_ENCODER_POP_EMPTY: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        fail(&ctx.device, || encoder.pop_debug_group());
+    });
+
+// Pass errors are reported when the pass ends. Finishing the encoder afterwards
+// makes sure it is discarded rather than dropped while still recording.
+
+#[gpu_test]
+static DEBUG_GROUP_RENDER_PASS_NOT_POPPED: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| {
+        let view = create_target(&ctx);
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        fail(&ctx.device, || {
+            let mut pass = begin_render_pass(&mut encoder, &view);
+            pass.push_debug_group("never popped");
+            drop(pass);
+            encoder.finish()
+        });
+    });
+
+#[gpu_test]
+static DEBUG_GROUP_RENDER_PASS_POP_EMPTY: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| {
+        let view = create_target(&ctx);
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        fail(&ctx.device, || {
+            let mut pass = begin_render_pass(&mut encoder, &view);
+            pass.pop_debug_group();
+            drop(pass);
+            encoder.finish()
+        });
+    });
+
+#[gpu_test]
+static DEBUG_GROUP_COMPUTE_PASS_NOT_POPPED: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(compute_params())
+    .run_sync(|ctx| {
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        fail(&ctx.device, || {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.push_debug_group("never popped");
+            drop(pass);
+            encoder.finish()
+        });
+    });
+
+// A group pushed on the encoder can't be popped inside a pass.
+#[gpu_test]
+static DEBUG_GROUP_COMPUTE_PASS_POP_ENCODER_GROUP: GpuTestConfiguration =
+    GpuTestConfiguration::new()
+        .parameters(compute_params())
+        .run_sync(|ctx| {
+            let mut encoder = ctx
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+            encoder.push_debug_group("encoder");
+            fail(&ctx.device, || {
+                let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+                pass.pop_debug_group();
+                drop(pass);
+                encoder.finish()
+            });
+        });
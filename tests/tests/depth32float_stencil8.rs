@@ -0,0 +1,192 @@
+//! Tests for [`wgpu::TextureFormat::Depth32FloatStencil8`], which needs
+//! [`wgpu::Features::DEPTH32FLOAT_STENCIL8`].
+
+use wgpu_test::{
+    fail, fullscreen_shader, gpu_test, image::ReadbackBuffers, valid, GpuTestConfiguration,
+    TestParameters, TestingContext,
+};
+
+const SIZE: u32 = 4;
+const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32FloatStencil8;
+const DEPTH: f32 = 0.25;
+const STENCIL_REFERENCE: u32 = 7;
+
+fn create_texture(ctx: &TestingContext) -> wgpu::Texture {
+    ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("depth stencil"),
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: FORMAT,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+            | wgpu::TextureUsages::TEXTURE_BINDING
+            | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    })
+}
+
+#[gpu_test]
+static DEPTH32FLOAT_STENCIL8_RENDER_AND_COPY_ASPECTS: GpuTestConfiguration =
+    GpuTestConfiguration::new()
+        .parameters(TestParameters::default().features(wgpu::Features::DEPTH32FLOAT_STENCIL8))
+        .run_sync(|ctx| {
+            // A fullscreen triangle that writes `DEPTH` and the stencil reference everywhere.
+            let shader = fullscreen_shader(
+                &ctx.device,
+                &format!(
+                    r#"
+                    @fragment
+                    fn fs_main() -> @builtin(frag_depth) f32 {{
+                        return {DEPTH};
+                    }}
+                    "#
+                ),
+            );
+            let replace = wgpu::StencilFaceState {
+                compare: wgpu::CompareFunction::Always,
+                fail_op: wgpu::StencilOperation::Keep,
+                depth_fail_op: wgpu::StencilOperation::Keep,
+                pass_op: wgpu::StencilOperation::Replace,
+            };
+            let pipeline = ctx
+                .device
+                .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                    label: None,
+                    layout: None,
+                    vertex: wgpu::VertexState {
+                        module: &shader,
+                        entry_point: "vs_main",
+                        buffers: &[],
+                    },
+                    primitive: wgpu::PrimitiveState::default(),
+                    depth_stencil: Some(wgpu::DepthStencilState {
+                        format: FORMAT,
+                        depth_write_enabled: true,
+                        depth_compare: wgpu::CompareFunction::Always,
+                        stencil: wgpu::StencilState {
+                            front: replace,
+                            back: replace,
+                            read_mask: !0,
+                            write_mask: !0,
+                        },
+                        bias: wgpu::DepthBiasState::default(),
+                    }),
+                    multisample: wgpu::MultisampleState::default(),
+                    fragment: Some(wgpu::FragmentState {
+                        module: &shader,
+                        entry_point: "fs_main",
+                        targets: &[],
+                    }),
+                    multiview: None,
+                });
+
+            let texture = create_texture(&ctx);
+            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+            let mut encoder = ctx
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+            {
+                let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: None,
+                    color_attachments: &[],
+                    depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                        view: &view,
+                        depth_ops: Some(wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(1.0),
+                            store: wgpu::StoreOp::Store,
+                        }),
+                        stencil_ops: Some(wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(0),
+                            store: wgpu::StoreOp::Store,
+                        }),
+                    }),
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+                pass.set_pipeline(&pipeline);
+                pass.set_stencil_reference(STENCIL_REFERENCE);
+                pass.draw(0..3, 0..1);
+            }
+            let readback = ReadbackBuffers::new(&ctx.device, &texture);
+            readback.copy_from(&ctx.device, &mut encoder, &texture);
+            ctx.queue.submit(Some(encoder.finish()));
+
+            // The depth aspect copies out as 4 byte floats, the stencil aspect as single bytes.
+            let depth: Vec<f32> = readback
+                .contents(&ctx.device)
+                .chunks_exact(4)
+                .map(|texel| f32::from_ne_bytes(texel.try_into().unwrap()))
+                .collect();
+            assert_eq!(depth, [DEPTH; (SIZE * SIZE) as usize]);
+
+            let stencil = readback.stencil_contents(&ctx.device);
+            assert_eq!(stencil, [STENCIL_REFERENCE as u8; (SIZE * SIZE) as usize]);
+        });
+
+#[gpu_test]
+static DEPTH32FLOAT_STENCIL8_ASPECT_VALIDATION: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().features(wgpu::Features::DEPTH32FLOAT_STENCIL8))
+    .run_sync(|ctx| {
+        let texture = create_texture(&ctx);
+
+        // Both aspects at once have no single texel size to copy with.
+        let readback = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("readback"),
+            size: (wgpu::COPY_BYTES_PER_ROW_ALIGNMENT * SIZE) as u64,
+            usage: wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        fail(&ctx.device, || {
+            encoder.copy_texture_to_buffer(
+                texture.as_image_copy(),
+                wgpu::ImageCopyBuffer {
+                    buffer: &readback,
+                    layout: wgpu::ImageDataLayout {
+                        offset: 0,
+                        bytes_per_row: Some(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT),
+                        rows_per_image: None,
+                    },
+                },
+                texture.size(),
+            );
+        });
+
+        // The depth aspect can be bound for sampling on its own.
+        let layout = ctx
+            .device
+            .create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: None,
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Depth,
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                }],
+            });
+        let depth_view = texture.create_view(&wgpu::TextureViewDescriptor {
+            aspect: wgpu::TextureAspect::DepthOnly,
+            ..Default::default()
+        });
+        valid(&ctx.device, || {
+            ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: None,
+                layout: &layout,
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&depth_view),
+                }],
+            })
+        });
+    });
@@ -0,0 +1,210 @@
+//! Tests for render passes with a depth attachment and no color attachments, as used
+//! when rendering shadow maps.
+
+use wgpu_test::{fail, gpu_test, valid, GpuTestConfiguration, TestingContext};
+
+const SIZE: u32 = 4;
+const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+const DEPTH: f32 = 0.75;
+
+const SHADER: &str = r#"
+@group(0) @binding(0)
+var depth: texture_depth_2d;
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4<f32> {
+    let x = f32(i32(vertex_index) / 2) * 4.0 - 1.0;
+    let y = 1.0 - f32(i32(vertex_index) & 1) * 4.0;
+    return vec4<f32>(x, y, 0.75, 1.0);
+}
+
+@fragment
+fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let value = textureLoad(depth, vec2<i32>(position.xy), 0);
+    return vec4<f32>(value, 0.0, 0.0, 1.0);
+}
+"#;
+
+fn create_shader(ctx: &TestingContext) -> wgpu::ShaderModule {
+    ctx.device
+        .create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: None,
+            source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+        })
+}
+
+/// A pipeline that writes depth, with an optional fragment stage.
+fn create_depth_pipeline(
+    ctx: &TestingContext,
+    shader: &wgpu::ShaderModule,
+    fragment: Option<wgpu::FragmentState<'_>>,
+) -> wgpu::RenderPipeline {
+    ctx.device
+        .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("depth pipeline"),
+            layout: None,
+            vertex: wgpu::VertexState {
+                module: shader,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: DEPTH_FORMAT,
+                depth_write_enabled: true,
+                depth_compare: wgpu::CompareFunction::Less,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState::default(),
+            fragment,
+            multiview: None,
+        })
+}
+
+fn create_depth_texture(ctx: &TestingContext) -> wgpu::Texture {
+    ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("depth"),
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: DEPTH_FORMAT,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+        view_formats: &[],
+    })
+}
+
+fn begin_depth_only_pass<'a>(
+    encoder: &'a mut wgpu::CommandEncoder,
+    depth_view: &'a wgpu::TextureView,
+) -> wgpu::RenderPass<'a> {
+    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some("depth only pass"),
+        color_attachments: &[],
+        depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+            view: depth_view,
+            depth_ops: Some(wgpu::Operations {
+                load: wgpu::LoadOp::Clear(1.0),
+                store: wgpu::StoreOp::Store,
+            }),
+            stencil_ops: None,
+        }),
+        timestamp_writes: None,
+        occlusion_query_set: None,
+    })
+}
+
+#[gpu_test]
+static DEPTH_ONLY_PASS: GpuTestConfiguration = GpuTestConfiguration::new().run_sync(|ctx| {
+    let shader = create_shader(&ctx);
+    let depth_pipeline = create_depth_pipeline(&ctx, &shader, None);
+    let depth = create_depth_texture(&ctx);
+    let depth_view = depth.create_view(&wgpu::TextureViewDescriptor::default());
+
+    // A second pass copies the depth into a color target, so it can be read back.
+    let sample_pipeline = ctx
+        .device
+        .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("sample pipeline"),
+            layout: None,
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::TextureFormat::Rgba8Unorm.into())],
+            }),
+            multiview: None,
+        });
+    let bind_group = ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: None,
+        layout: &sample_pipeline.get_bind_group_layout(0),
+        entries: &[wgpu::BindGroupEntry {
+            binding: 0,
+            resource: wgpu::BindingResource::TextureView(&depth_view),
+        }],
+    });
+    let color = ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("color"),
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let color_view = color.create_view(&wgpu::TextureViewDescriptor::default());
+    let readback_buffers = wgpu_test::image::ReadbackBuffers::new(&ctx.device, &color);
+
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    valid(&ctx.device, || {
+        let mut pass = begin_depth_only_pass(&mut encoder, &depth_view);
+        pass.set_pipeline(&depth_pipeline);
+        pass.draw(0..3, 0..1);
+    });
+    {
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("sample pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &color_view,
+                resolve_target: None,
+                ops: wgpu::Operations::default(),
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        pass.set_pipeline(&sample_pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.draw(0..3, 0..1);
+    }
+    readback_buffers.copy_from(&ctx.device, &mut encoder, &color);
+    ctx.queue.submit(Some(encoder.finish()));
+
+    let red = (DEPTH * 255.0).round() as u8;
+    let expected = [red, 0, 0, 255].repeat((SIZE * SIZE) as usize);
+    readback_buffers.assert_buffer_contents(&ctx.device, &expected);
+});
+
+#[gpu_test]
+static DEPTH_ONLY_PASS_REJECTS_COLOR_TARGETS: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| {
+        let shader = create_shader(&ctx);
+        let pipeline = create_depth_pipeline(
+            &ctx,
+            &shader,
+            Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::TextureFormat::Rgba8Unorm.into())],
+            }),
+        );
+        let depth = create_depth_texture(&ctx);
+        let depth_view = depth.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        fail(&ctx.device, || {
+            let mut pass = begin_depth_only_pass(&mut encoder, &depth_view);
+            pass.set_pipeline(&pipeline);
+        });
+    });
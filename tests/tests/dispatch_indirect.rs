@@ -0,0 +1,190 @@
+//! Tests for `dispatch_workgroups_indirect`.
+
+use wgpu::util::{BufferInitDescriptor, DeviceExt, DispatchIndirectArgs};
+
+use wgpu_test::{
+    gpu_test, read_buffer, record_and_finish, valid, FailureCase, GpuTestConfiguration,
+    TestParameters, TestingContext,
+};
+
+const RECORD_SIZE: u64 = std::mem::size_of::<DispatchIndirectArgs>() as u64;
+
+/// Number of dispatch records stored in the indirect buffer.
+const RECORD_COUNT: u64 = 2;
+
+fn create_indirect_buffer(
+    ctx: &TestingContext,
+    record: DispatchIndirectArgs,
+    usage: wgpu::BufferUsages,
+) -> wgpu::Buffer {
+    ctx.device.create_buffer_init(&BufferInitDescriptor {
+        label: Some("indirect buffer"),
+        contents: &record.as_bytes().repeat(RECORD_COUNT as usize),
+        usage,
+    })
+}
+
+/// Create a pipeline that counts the workgroups it runs in the storage buffer at binding 0.
+fn create_counting_pipeline(ctx: &TestingContext) -> wgpu::ComputePipeline {
+    let shader = ctx
+        .device
+        .create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: None,
+            source: wgpu::ShaderSource::Wgsl(
+                r#"
+                @group(0) @binding(0) var<storage, read_write> count: atomic<u32>;
+
+                @compute @workgroup_size(1)
+                fn main() {
+                    atomicAdd(&count, 1u);
+                }
+                "#
+                .into(),
+            ),
+        });
+    ctx.device
+        .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Pipeline"),
+            layout: None,
+            module: &shader,
+            entry_point: "main",
+        })
+}
+
+/// Dispatch the counting pipeline with the record at `offset` in `indirect_buffer`, returning the
+/// number of workgroups that ran, or the validation error raised while recording.
+fn dispatch_indirect(
+    ctx: &TestingContext,
+    indirect_buffer: &wgpu::Buffer,
+    offset: wgpu::BufferAddress,
+) -> Result<u32, String> {
+    let pipeline = create_counting_pipeline(ctx);
+    let count_buffer = ctx.device.create_buffer_init(&BufferInitDescriptor {
+        label: Some("count"),
+        contents: &[0; 4],
+        usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+    });
+    let bind_group = ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: None,
+        layout: &pipeline.get_bind_group_layout(0),
+        entries: &[wgpu::BindGroupEntry {
+            binding: 0,
+            resource: count_buffer.as_entire_binding(),
+        }],
+    });
+
+    let encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    let command_buffer = record_and_finish(&ctx.device, encoder, |encoder| {
+        let mut cpass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+        cpass.set_pipeline(&pipeline);
+        cpass.set_bind_group(0, &bind_group, &[]);
+        cpass.dispatch_workgroups_indirect(indirect_buffer, offset);
+    })?;
+    valid(&ctx.device, || ctx.queue.submit(Some(command_buffer)));
+
+    let count = read_buffer(&ctx.device, &ctx.queue, &count_buffer);
+    Ok(u32::from_ne_bytes(count.try_into().unwrap()))
+}
+
+fn dispatch_indirect_params() -> TestParameters {
+    TestParameters::default().downlevel_flags(
+        wgpu::DownlevelFlags::COMPUTE_SHADERS | wgpu::DownlevelFlags::INDIRECT_EXECUTION,
+    )
+}
+
+/// Parameters for tests of the dispatch size clamping done with
+/// `InstanceFlags::VALIDATION_INDIRECT_CALL`.
+fn validated_dispatch_indirect_params() -> TestParameters {
+    dispatch_indirect_params()
+        .instance_flags(wgpu::InstanceFlags::VALIDATION_INDIRECT_CALL)
+        // The clamping is done by wgpu-core, which isn't used on top of a browser's WebGPU.
+        .skip(FailureCase::backend(wgpu::Backends::BROWSER_WEBGPU))
+}
+
+fn check_dispatch_in_limit(ctx: &TestingContext) {
+    let record = DispatchIndirectArgs { x: 2, y: 3, z: 1 };
+    let indirect_buffer = create_indirect_buffer(ctx, record, wgpu::BufferUsages::INDIRECT);
+
+    // Dispatch the last record in the buffer.
+    let count = dispatch_indirect(ctx, &indirect_buffer, RECORD_SIZE).unwrap();
+    assert_eq!(count, 6);
+}
+
+#[gpu_test]
+static DISPATCH_INDIRECT: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(dispatch_indirect_params())
+    .run_sync(|ctx| check_dispatch_in_limit(&ctx));
+
+#[gpu_test]
+static DISPATCH_INDIRECT_VALIDATED: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(validated_dispatch_indirect_params())
+    // The counting pipeline's bind group has to be restored after the clamping dispatch.
+    .run_sync(|ctx| check_dispatch_in_limit(&ctx));
+
+#[gpu_test]
+static DISPATCH_INDIRECT_ABOVE_LIMIT: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(validated_dispatch_indirect_params())
+    .run_sync(|ctx| {
+        let limit = ctx.device.limits().max_compute_workgroups_per_dimension;
+        let record = DispatchIndirectArgs {
+            x: limit + 1,
+            y: 1,
+            z: 1,
+        };
+        let indirect_buffer = create_indirect_buffer(&ctx, record, wgpu::BufferUsages::INDIRECT);
+
+        // With `InstanceFlags::VALIDATION_INDIRECT_CALL`, the workgroup count is clamped to the
+        // limit.
+        let count = dispatch_indirect(&ctx, &indirect_buffer, 0).unwrap();
+        assert_eq!(count, limit);
+    });
+
+#[gpu_test]
+static DISPATCH_INDIRECT_UNALIGNED: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(dispatch_indirect_params())
+    .run_sync(|ctx| {
+        let record = DispatchIndirectArgs { x: 1, y: 1, z: 1 };
+        let indirect_buffer = create_indirect_buffer(&ctx, record, wgpu::BufferUsages::INDIRECT);
+
+        let error = dispatch_indirect(&ctx, &indirect_buffer, 2).unwrap_err();
+        assert!(
+            error.contains("offset 2 into indirect buffer"),
+            "unexpected error: {error}"
+        );
+        assert!(
+            error.contains(&format!("of size {}", RECORD_SIZE * RECORD_COUNT)),
+            "unexpected error: {error}"
+        );
+    });
+
+#[gpu_test]
+static DISPATCH_INDIRECT_OUT_OF_BOUNDS: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(dispatch_indirect_params())
+    .run_sync(|ctx| {
+        let record = DispatchIndirectArgs { x: 1, y: 1, z: 1 };
+        let indirect_buffer = create_indirect_buffer(&ctx, record, wgpu::BufferUsages::INDIRECT);
+
+        // An aligned offset whose arguments run past the end of the buffer.
+        let offset = RECORD_SIZE * RECORD_COUNT - 4;
+        let error = dispatch_indirect(&ctx, &indirect_buffer, offset).unwrap_err();
+        assert!(
+            error.contains(&format!("of size {}", RECORD_SIZE * RECORD_COUNT)),
+            "unexpected error: {error}"
+        );
+    });
+
+#[gpu_test]
+static DISPATCH_INDIRECT_MISSING_USAGE: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(dispatch_indirect_params())
+    .run_sync(|ctx| {
+        let record = DispatchIndirectArgs { x: 1, y: 1, z: 1 };
+        let indirect_buffer = create_indirect_buffer(&ctx, record, wgpu::BufferUsages::STORAGE);
+
+        let error = dispatch_indirect(&ctx, &indirect_buffer, 0).unwrap_err();
+        assert!(
+            error.contains("required usage INDIRECT"),
+            "unexpected error: {error}"
+        );
+    });
@@ -0,0 +1,86 @@
+//! Tests for [`wgpu::util::DownloadBuffer`].
+
+use std::sync::{Arc, Mutex};
+
+use wgpu::util::DownloadBuffer;
+use wgpu_test::{gpu_test, GpuTestConfiguration, TestParameters, TestingContext};
+
+const WORDS: u32 = 16;
+
+/// Download `slice` and return its contents.
+fn download(ctx: &TestingContext, slice: wgpu::BufferSlice<'_>) -> Vec<u8> {
+    let result = Arc::new(Mutex::new(None));
+    let callback_result = Arc::clone(&result);
+    DownloadBuffer::read_buffer(&ctx.device, &ctx.queue, &slice, move |download| {
+        *callback_result.lock().unwrap() = Some(download.unwrap().to_vec());
+    });
+    ctx.device.poll(wgpu::Maintain::Wait);
+    let data = result.lock().unwrap().take();
+    data.expect("download callback was not called")
+}
+
+#[gpu_test]
+static DOWNLOAD_BUFFER: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(
+        TestParameters::default()
+            .test_features_limits()
+            .downlevel_flags(wgpu::DownlevelFlags::COMPUTE_SHADERS),
+    )
+    .run_sync(|ctx| {
+        let shader = ctx
+            .device
+            .create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: None,
+                source: wgpu::ShaderSource::Wgsl(
+                    r#"
+                    @group(0) @binding(0)
+                    var<storage, read_write> output: array<u32>;
+
+                    @compute @workgroup_size(1)
+                    fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+                        output[id.x] = id.x * 0x01010101u;
+                    }
+                    "#
+                    .into(),
+                ),
+            });
+        let pipeline = ctx
+            .device
+            .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: None,
+                layout: None,
+                module: &shader,
+                entry_point: "main",
+            });
+        let buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("output"),
+            size: (WORDS * 4) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let bind_group = ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &pipeline.get_bind_group_layout(0),
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: buffer.as_entire_binding(),
+            }],
+        });
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(&pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(WORDS, 1, 1);
+        }
+        ctx.queue.submit(Some(encoder.finish()));
+
+        // Word `i` holds four bytes of value `i`.
+        let expected: Vec<u8> = (0..WORDS as u8).flat_map(|word| [word; 4]).collect();
+        assert_eq!(download(&ctx, buffer.slice(..)), expected);
+        // Unaligned bounds are widened for the copy, but only the requested bytes come back.
+        assert_eq!(download(&ctx, buffer.slice(5..11)), &expected[5..11]);
+    });
@@ -0,0 +1,321 @@
+//! Tests for the encode time validation of `draw_indirect` and `draw_indexed_indirect`,
+//! and for the first instance of indirect draws.
+
+use wgpu::util::{BufferInitDescriptor, DeviceExt};
+
+use wgpu_test::{
+    gpu_test, image::ReadbackBuffers, record_and_finish, valid, GpuTestConfiguration,
+    TestParameters, TestingContext,
+};
+
+/// Number of draw records stored in the indirect buffer.
+const RECORD_COUNT: u64 = 2;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Case {
+    /// Draw the last record in the buffer.
+    Valid,
+    /// Draw from an offset that isn't a multiple of 4.
+    Unaligned,
+    /// Draw from an aligned offset whose arguments run past the end of the buffer.
+    OutOfBounds,
+    /// Draw from a buffer created without `BufferUsages::INDIRECT`.
+    MissingUsage,
+}
+
+fn draw_indirect_test_impl(ctx: &TestingContext, indexed: bool, case: Case) {
+    let record_size = if indexed {
+        std::mem::size_of::<wgpu::util::DrawIndexedIndirectArgs>()
+    } else {
+        std::mem::size_of::<wgpu::util::DrawIndirectArgs>()
+    } as u64;
+
+    let (offset, expected_error) = match case {
+        Case::Valid => (record_size, None),
+        Case::Unaligned => (
+            2,
+            Some(vec![
+                "offset 2 into indirect buffer".to_string(),
+                format!(
+                    "of size {} is not a multiple of 4",
+                    record_size * RECORD_COUNT
+                ),
+                "indirect buffer = `indirect buffer`".to_string(),
+            ]),
+        ),
+        Case::OutOfBounds => {
+            let offset = record_size * RECORD_COUNT - 4;
+            (
+                offset,
+                Some(vec![format!("of size {}", record_size * RECORD_COUNT)]),
+            )
+        }
+        Case::MissingUsage => (0, Some(vec!["required usage INDIRECT".to_string()])),
+    };
+
+    let indirect_buffer = ctx.device.create_buffer_init(&BufferInitDescriptor {
+        label: Some("indirect buffer"),
+        contents: &vec![0; (record_size * RECORD_COUNT) as usize],
+        usage: if case == Case::MissingUsage {
+            wgpu::BufferUsages::VERTEX
+        } else {
+            wgpu::BufferUsages::INDIRECT
+        },
+    });
+
+    let index_buffer = ctx.device.create_buffer_init(&BufferInitDescriptor {
+        label: Some("index buffer"),
+        contents: bytemuck::cast_slice(&[0u32, 1, 2, 0]),
+        usage: wgpu::BufferUsages::INDEX,
+    });
+
+    let texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("Offscreen texture"),
+        size: wgpu::Extent3d {
+            width: 4,
+            height: 4,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        view_formats: &[],
+    });
+    let texture_view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    let shader = ctx
+        .device
+        .create_shader_module(wgpu::include_wgsl!("multi_draw_indirect/green.wgsl"));
+
+    let pipeline = ctx
+        .device
+        .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Pipeline"),
+            layout: None,
+            vertex: wgpu::VertexState {
+                entry_point: "vs_main",
+                module: &shader,
+                buffers: &[],
+            },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                entry_point: "fs_main",
+                module: &shader,
+                targets: &[Some(wgpu::TextureFormat::Rgba8Unorm.into())],
+            }),
+            multiview: None,
+        });
+
+    let encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+    let result = record_and_finish(&ctx.device, encoder, |encoder| {
+        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Renderpass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &texture_view,
+                resolve_target: None,
+                ops: wgpu::Operations::default(),
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        rpass.set_pipeline(&pipeline);
+        if indexed {
+            rpass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+            rpass.draw_indexed_indirect(&indirect_buffer, offset);
+        } else {
+            rpass.draw_indirect(&indirect_buffer, offset);
+        }
+    });
+
+    match expected_error {
+        None => {
+            let command_buffer = result.expect("valid indirect draw was rejected");
+            valid(&ctx.device, || ctx.queue.submit(Some(command_buffer)));
+        }
+        Some(expected_error) => {
+            let error = result.expect_err("invalid indirect draw was not rejected");
+            for expected in expected_error {
+                assert!(error.contains(&expected), "{expected:?} not in {error:?}");
+            }
+        }
+    }
+}
+
+fn draw_indirect_params() -> TestParameters {
+    TestParameters::default().downlevel_flags(wgpu::DownlevelFlags::INDIRECT_EXECUTION)
+}
+
+#[gpu_test]
+static DRAW_INDIRECT: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(draw_indirect_params())
+    .run_sync(|ctx| draw_indirect_test_impl(&ctx, false, Case::Valid));
+
+#[gpu_test]
+static DRAW_INDEXED_INDIRECT: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(draw_indirect_params())
+    .run_sync(|ctx| draw_indirect_test_impl(&ctx, true, Case::Valid));
+
+#[gpu_test]
+static DRAW_INDIRECT_UNALIGNED: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(draw_indirect_params())
+    .run_sync(|ctx| draw_indirect_test_impl(&ctx, false, Case::Unaligned));
+
+#[gpu_test]
+static DRAW_INDEXED_INDIRECT_UNALIGNED: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(draw_indirect_params())
+    .run_sync(|ctx| draw_indirect_test_impl(&ctx, true, Case::Unaligned));
+
+#[gpu_test]
+static DRAW_INDIRECT_OUT_OF_BOUNDS: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(draw_indirect_params())
+    .run_sync(|ctx| draw_indirect_test_impl(&ctx, false, Case::OutOfBounds));
+
+#[gpu_test]
+static DRAW_INDEXED_INDIRECT_OUT_OF_BOUNDS: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(draw_indirect_params())
+    .run_sync(|ctx| draw_indirect_test_impl(&ctx, true, Case::OutOfBounds));
+
+#[gpu_test]
+static DRAW_INDIRECT_MISSING_USAGE: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(draw_indirect_params())
+    .run_sync(|ctx| draw_indirect_test_impl(&ctx, false, Case::MissingUsage));
+
+#[gpu_test]
+static DRAW_INDEXED_INDIRECT_MISSING_USAGE: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(draw_indirect_params())
+    .run_sync(|ctx| draw_indirect_test_impl(&ctx, true, Case::MissingUsage));
+
+/// Draws two triangles covering column `instance_index` of an eight pixel wide target.
+const INSTANCE_COLUMNS_SHADER: &str = r#"
+@vertex
+fn vs_main(
+    @builtin(vertex_index) vertex_index: u32,
+    @builtin(instance_index) instance_index: u32,
+) -> @builtin(position) vec4<f32> {
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(0.0, 0.0),
+        vec2<f32>(1.0, 0.0),
+        vec2<f32>(0.0, 1.0),
+        vec2<f32>(0.0, 1.0),
+        vec2<f32>(1.0, 0.0),
+        vec2<f32>(1.0, 1.0),
+    );
+    let corner = corners[vertex_index];
+    return vec4<f32>((f32(instance_index) + corner.x) / 4.0 - 1.0, corner.y * 2.0 - 1.0, 0.0, 1.0);
+}
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    return vec4<f32>(1.0);
+}
+"#;
+
+#[gpu_test]
+static DRAW_INDIRECT_FIRST_INSTANCE: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(draw_indirect_params().features(wgpu::Features::INDIRECT_FIRST_INSTANCE))
+    .run_sync(|ctx| {
+        const COLUMNS: u32 = 8;
+        const FIRST_INSTANCE: u32 = 5;
+        const INSTANCE_COUNT: u32 = 3;
+
+        let indirect_buffer = ctx.device.create_buffer_init(&BufferInitDescriptor {
+            label: Some("indirect buffer"),
+            contents: wgpu::util::DrawIndirectArgs {
+                vertex_count: 6,
+                instance_count: INSTANCE_COUNT,
+                first_vertex: 0,
+                first_instance: FIRST_INSTANCE,
+            }
+            .as_bytes(),
+            usage: wgpu::BufferUsages::INDIRECT,
+        });
+
+        let texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Offscreen texture"),
+            size: wgpu::Extent3d {
+                width: COLUMNS,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let texture_view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let shader = ctx
+            .device
+            .create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("instance columns"),
+                source: wgpu::ShaderSource::Wgsl(INSTANCE_COLUMNS_SHADER.into()),
+            });
+        let pipeline = ctx
+            .device
+            .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("Pipeline"),
+                layout: None,
+                vertex: wgpu::VertexState {
+                    entry_point: "vs_main",
+                    module: &shader,
+                    buffers: &[],
+                },
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                fragment: Some(wgpu::FragmentState {
+                    entry_point: "fs_main",
+                    module: &shader,
+                    targets: &[Some(wgpu::TextureFormat::Rgba8Unorm.into())],
+                }),
+                multiview: None,
+            });
+
+        let readback_buffer = ReadbackBuffers::new(&ctx.device, &texture);
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        {
+            let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Renderpass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &texture_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            rpass.set_pipeline(&pipeline);
+            rpass.draw_indirect(&indirect_buffer, 0);
+        }
+        readback_buffer.copy_from(&ctx.device, &mut encoder, &texture);
+        ctx.queue.submit(Some(encoder.finish()));
+
+        // Only the columns of instances 5, 6 and 7 are drawn. If the first instance
+        // were ignored, columns 0 to 2 would be drawn instead.
+        let expected: Vec<u8> = (0..COLUMNS)
+            .flat_map(|column| {
+                if (FIRST_INSTANCE..FIRST_INSTANCE + INSTANCE_COUNT).contains(&column) {
+                    [255; 4]
+                } else {
+                    [0; 4]
+                }
+            })
+            .collect();
+        readback_buffer.assert_buffer_contents(&ctx.device, &expected);
+    });
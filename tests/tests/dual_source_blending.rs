@@ -0,0 +1,185 @@
+//! Tests for blending with a second fragment output, using [`wgpu::Features::DUAL_SOURCE_BLENDING`].
+
+use wgpu_test::{
+    fail, gpu_test, valid, FailureCase, GpuTestConfiguration, TestParameters, TestingContext,
+};
+
+const SIZE: u32 = 4;
+
+const VERTEX: &str = r#"
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4<f32> {
+    let x = f32(i32(vertex_index) / 2) * 4.0 - 1.0;
+    let y = 1.0 - f32(i32(vertex_index) & 1) * 4.0;
+    return vec4<f32>(x, y, 0.0, 1.0);
+}
+"#;
+
+const SINGLE_SOURCE: &str = r#"
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    return vec4<f32>(0.2, 0.4, 0.6, 1.0);
+}
+"#;
+
+const DUAL_SOURCE: &str = r#"
+enable dual_source_blending;
+
+struct FragmentOutput {
+    @location(0) @blend_src(0) color: vec4<f32>,
+    @location(0) @blend_src(1) mask: vec4<f32>,
+}
+
+@fragment
+fn fs_main() -> FragmentOutput {
+    return FragmentOutput(vec4<f32>(0.2, 0.4, 0.6, 1.0), vec4<f32>(1.0, 0.0, 1.0, 1.0));
+}
+"#;
+
+/// Blends the first source into the target through the per-channel mask in the second
+/// source, as subpixel text rendering does.
+const MASKED_BLEND: wgpu::BlendState = wgpu::BlendState {
+    color: wgpu::BlendComponent {
+        src_factor: wgpu::BlendFactor::Src1,
+        dst_factor: wgpu::BlendFactor::OneMinusSrc1,
+        operation: wgpu::BlendOperation::Add,
+    },
+    alpha: wgpu::BlendComponent::REPLACE,
+};
+
+fn create_pipeline(
+    ctx: &TestingContext,
+    fragment: &str,
+    targets: &[Option<wgpu::ColorTargetState>],
+) -> wgpu::RenderPipeline {
+    let shader = ctx
+        .device
+        .create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: None,
+            source: wgpu::ShaderSource::Wgsl(format!("{fragment}{VERTEX}").into()),
+        });
+    ctx.device
+        .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: None,
+            layout: None,
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets,
+            }),
+            multiview: None,
+        })
+}
+
+fn target(blend: wgpu::BlendState) -> Option<wgpu::ColorTargetState> {
+    Some(wgpu::ColorTargetState {
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        blend: Some(blend),
+        write_mask: wgpu::ColorWrites::ALL,
+    })
+}
+
+#[gpu_test]
+static DUAL_SOURCE_BLENDING_REQUIRES_FEATURE: GpuTestConfiguration = GpuTestConfiguration::new()
+    // The WebGPU backend panics when mapping the blend factors without the feature.
+    .parameters(
+        TestParameters::default().skip(FailureCase::backend(wgpu::Backends::BROWSER_WEBGPU)),
+    )
+    .run_sync(|ctx| {
+        fail(&ctx.device, || {
+            create_pipeline(&ctx, SINGLE_SOURCE, &[target(MASKED_BLEND)])
+        });
+        // Declaring a second source needs the feature as well.
+        fail(&ctx.device, || {
+            ctx.device
+                .create_shader_module(wgpu::ShaderModuleDescriptor {
+                    label: None,
+                    source: wgpu::ShaderSource::Wgsl(format!("{DUAL_SOURCE}{VERTEX}").into()),
+                })
+        });
+    });
+
+#[gpu_test]
+static DUAL_SOURCE_BLENDING_VALIDATION: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().features(wgpu::Features::DUAL_SOURCE_BLENDING))
+    .run_sync(|ctx| {
+        valid(&ctx.device, || {
+            create_pipeline(&ctx, DUAL_SOURCE, &[target(MASKED_BLEND)])
+        });
+
+        // The factors reference the second source, which the shader doesn't write.
+        fail(&ctx.device, || {
+            create_pipeline(&ctx, SINGLE_SOURCE, &[target(MASKED_BLEND)])
+        });
+        // The shader writes a second source, which the blend state doesn't use.
+        fail(&ctx.device, || {
+            create_pipeline(&ctx, DUAL_SOURCE, &[target(wgpu::BlendState::REPLACE)])
+        });
+        // Only the first color target can blend with the second source.
+        fail(&ctx.device, || {
+            create_pipeline(
+                &ctx,
+                DUAL_SOURCE,
+                &[target(MASKED_BLEND), target(MASKED_BLEND)],
+            )
+        });
+    });
+
+#[gpu_test]
+static DUAL_SOURCE_BLENDING_MASK: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().features(wgpu::Features::DUAL_SOURCE_BLENDING))
+    .run_sync(|ctx| {
+        let pipeline = create_pipeline(&ctx, DUAL_SOURCE, &[target(MASKED_BLEND)]);
+        let texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("target"),
+            size: wgpu::Extent3d {
+                width: SIZE,
+                height: SIZE,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let readback_buffers = wgpu_test::image::ReadbackBuffers::new(&ctx.device, &texture);
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: None,
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::WHITE),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_pipeline(&pipeline);
+            pass.draw(0..3, 0..1);
+        }
+        readback_buffers.copy_from(&ctx.device, &mut encoder, &texture);
+        ctx.queue.submit(Some(encoder.finish()));
+
+        // Red and blue take the color through the mask, green keeps the white it was cleared to.
+        let expected = [51, 255, 153, 255].repeat((SIZE * SIZE) as usize);
+        readback_buffers.assert_buffer_contents(&ctx.device, &expected);
+    });
@@ -8,6 +8,32 @@ static DROP_ENCODER: GpuTestConfiguration = GpuTestConfiguration::new().run_sync
     drop(encoder);
 });
 
+// The device keeps a bounded pool of idle encoders. Submitting more command buffers than
+// it holds destroys the extras once the submission completes, and the pooled ones must
+// still be usable afterwards.
+#[gpu_test]
+static SUBMIT_MORE_ENCODERS_THAN_POOLED: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| {
+        let buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: 16,
+            usage: wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        for _ in 0..2 {
+            let command_buffers = (0..100).map(|_| {
+                let mut encoder = ctx
+                    .device
+                    .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+                encoder.clear_buffer(&buffer, 0, None);
+                encoder.finish()
+            });
+            ctx.queue.submit(command_buffers);
+            ctx.device.poll(wgpu::Maintain::Wait);
+        }
+    });
+
 // This test crashes on DX12 with the exception:
 //
 // ID3D12CommandAllocator::Reset: The command allocator cannot be reset because a
@@ -62,3 +88,135 @@ static DROP_ENCODER_AFTER_ERROR: GpuTestConfiguration = GpuTestConfiguration::ne
         // The encoder is still open!
         drop(encoder);
     });
+
+// Setting a bind group repeatedly within a pass only merges its usage once; conflicts with
+// later usages must still be caught.
+#[gpu_test]
+static REPEATED_BIND_GROUP_USAGE_CONFLICT: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| {
+        let buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("storage and vertex"),
+            size: 16,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::VERTEX,
+            mapped_at_creation: false,
+        });
+        let bind_group_layout =
+            ctx.device
+                .create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                    label: None,
+                    entries: &[wgpu::BindGroupLayoutEntry::buffer(
+                        0,
+                        wgpu::ShaderStages::COMPUTE,
+                        wgpu::BufferBindingType::Storage { read_only: false },
+                    )],
+                });
+        let bind_group = ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: buffer.as_entire_binding(),
+            }],
+        });
+
+        let target_tex = ctx.device.create_texture(&wgpu::TextureDescriptor {
+            label: None,
+            size: wgpu::Extent3d {
+                width: 4,
+                height: 4,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::R8Unorm,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+        let target_view = target_tex.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        let mut renderpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("renderpass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                ops: wgpu::Operations::default(),
+                resolve_target: None,
+                view: &target_view,
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        for _ in 0..3 {
+            renderpass.set_bind_group(0, &bind_group, &[]);
+            renderpass.set_bind_group(1, &bind_group, &[]);
+        }
+
+        // The buffer is already used as a writable storage buffer by the pass.
+        fail(&ctx.device, || {
+            renderpass.set_vertex_buffer(0, buffer.slice(..));
+            drop(renderpass);
+        });
+
+        drop(encoder);
+    });
+
+// Encoders can be recorded on several threads at once and finished on another one.
+#[cfg(not(target_arch = "wasm32"))]
+#[gpu_test]
+static RECORD_ENCODERS_ON_MULTIPLE_THREADS: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| {
+        const THREADS: usize = 4;
+        const SIZE: wgpu::BufferAddress = 256;
+
+        let buffers: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+                    label: None,
+                    size: SIZE,
+                    usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+                    mapped_at_creation: true,
+                });
+                buffer.slice(..).get_mapped_range_mut().fill(0xFF);
+                buffer.unmap();
+                buffer
+            })
+            .collect();
+
+        let encoders: Vec<_> = std::thread::scope(|scope| {
+            let handles: Vec<_> = buffers
+                .iter()
+                .map(|buffer| {
+                    let device = &ctx.device;
+                    scope.spawn(move || {
+                        let mut encoder = device
+                            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+                        encoder.clear_buffer(buffer, 0, None);
+                        encoder
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect()
+        });
+
+        ctx.queue
+            .submit(encoders.into_iter().map(|encoder| encoder.finish()));
+
+        for buffer in &buffers {
+            buffer.slice(..).map_async(wgpu::MapMode::Read, |_| ());
+        }
+        ctx.device.poll(wgpu::Maintain::Wait);
+        for buffer in &buffers {
+            assert!(buffer
+                .slice(..)
+                .get_mapped_range()
+                .iter()
+                .all(|&byte| byte == 0));
+        }
+    });
@@ -0,0 +1,242 @@
+//! Tests for [`wgpu::util::generate_mipmaps`].
+
+use wgpu::util::GenerateMipmapsError;
+use wgpu_test::{gpu_test, read_buffer, GpuTestConfiguration, TestParameters, TestingContext};
+
+const SIZE: u32 = 256;
+const MIP_LEVEL_COUNT: u32 = 9;
+const LAYERS: u32 = 2;
+// Level 4 is 16x16, with every texel averaged from a 16x16 block of level 0.
+const CHECKED_LEVEL: u32 = 4;
+const SOLID: u8 = 200;
+
+/// Create a `SIZE` square Rgba8Unorm texture with a full mip chain. Level 0 of the first
+/// layer holds a black and white checkerboard of single texels, and of the second layer a
+/// solid gray, so that mixing up the layers shows.
+fn create_texture(ctx: &TestingContext, usage: wgpu::TextureUsages) -> wgpu::Texture {
+    let texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("mipmapped"),
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: LAYERS,
+        },
+        mip_level_count: MIP_LEVEL_COUNT,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: usage | wgpu::TextureUsages::COPY_DST | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+
+    let checkerboard: Vec<u8> = (0..SIZE)
+        .flat_map(|y| (0..SIZE).map(move |x| (x, y)))
+        .flat_map(|(x, y)| {
+            let value = if (x + y) % 2 == 0 { 255 } else { 0 };
+            [value, value, value, 255]
+        })
+        .collect();
+    let solid = [SOLID, SOLID, SOLID, 255].repeat((SIZE * SIZE) as usize);
+
+    for (layer, data) in [checkerboard, solid].iter().enumerate() {
+        ctx.queue.write_texture(
+            wgpu::ImageCopyTexture {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d {
+                    x: 0,
+                    y: 0,
+                    z: layer as u32,
+                },
+                aspect: wgpu::TextureAspect::All,
+            },
+            data,
+            wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(SIZE * 4),
+                rows_per_image: None,
+            },
+            wgpu::Extent3d {
+                width: SIZE,
+                height: SIZE,
+                depth_or_array_layers: 1,
+            },
+        );
+    }
+    texture
+}
+
+/// Generate the mip chain of a texture from [`create_texture`] and check level
+/// `CHECKED_LEVEL` of both layers.
+fn generate_and_check(ctx: &TestingContext, usage: wgpu::TextureUsages) {
+    let texture = create_texture(ctx, usage);
+    let level_size = SIZE >> CHECKED_LEVEL;
+    let bytes_per_row = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+    let level = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("level"),
+        size: (bytes_per_row * level_size * LAYERS) as u64,
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::COPY_SRC,
+        mapped_at_creation: false,
+    });
+
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    wgpu::util::generate_mipmaps(&ctx.device, &mut encoder, &texture, 1..MIP_LEVEL_COUNT).unwrap();
+    encoder.copy_texture_to_buffer(
+        wgpu::ImageCopyTexture {
+            texture: &texture,
+            mip_level: CHECKED_LEVEL,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::All,
+        },
+        wgpu::ImageCopyBuffer {
+            buffer: &level,
+            layout: wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(bytes_per_row),
+                rows_per_image: Some(level_size),
+            },
+        },
+        wgpu::Extent3d {
+            width: level_size,
+            height: level_size,
+            depth_or_array_layers: LAYERS,
+        },
+    );
+    ctx.queue.submit(Some(encoder.finish()));
+
+    let data = read_buffer(&ctx.device, &ctx.queue, &level);
+    let layer_texels = |layer: u32| {
+        data.chunks_exact(bytes_per_row as usize)
+            .skip((layer * level_size) as usize)
+            .take(level_size as usize)
+            .flat_map(|row| row[..(level_size * 4) as usize].chunks_exact(4))
+            .collect::<Vec<_>>()
+    };
+
+    // The checkerboard averages out to a mid gray.
+    let checkerboard = layer_texels(0);
+    let average = checkerboard
+        .iter()
+        .map(|texel| texel[0] as f32)
+        .sum::<f32>()
+        / checkerboard.len() as f32;
+    assert!(
+        (average - 127.5).abs() <= 1.0,
+        "level {CHECKED_LEVEL} of the checkerboard averages to {average}"
+    );
+    for texel in checkerboard {
+        assert_eq!(texel[0], texel[1]);
+        assert_eq!(texel[0], texel[2]);
+        assert_eq!(texel[3], 255);
+    }
+
+    for texel in layer_texels(1) {
+        assert_eq!(texel, [SOLID, SOLID, SOLID, 255]);
+    }
+}
+
+#[gpu_test]
+static GENERATE_MIPMAPS_RENDER: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        generate_and_check(
+            &ctx,
+            wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::RENDER_ATTACHMENT,
+        );
+    });
+
+#[gpu_test]
+static GENERATE_MIPMAPS_COMPUTE: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().downlevel_flags(wgpu::DownlevelFlags::COMPUTE_SHADERS))
+    .run_sync(|ctx| {
+        // Without `RENDER_ATTACHMENT`, the levels are written as storage textures.
+        generate_and_check(
+            &ctx,
+            wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::STORAGE_BINDING,
+        );
+    });
+
+#[gpu_test]
+static GENERATE_MIPMAPS_ERRORS: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        let generate = |dimension, format, usage, mip_levels| {
+            let texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+                label: None,
+                size: wgpu::Extent3d {
+                    width: 4,
+                    height: 4,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 3,
+                sample_count: 1,
+                dimension,
+                format,
+                usage,
+                view_formats: &[],
+            });
+            let mut encoder = ctx
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+            wgpu::util::generate_mipmaps(&ctx.device, &mut encoder, &texture, mip_levels)
+        };
+        let d2 = wgpu::TextureDimension::D2;
+        let rgba8 = wgpu::TextureFormat::Rgba8Unorm;
+        let usage = wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::RENDER_ATTACHMENT;
+
+        assert_eq!(
+            generate(d2, rgba8, usage, 0..3),
+            Err(GenerateMipmapsError::InvalidMipRange {
+                range: 0..3,
+                mip_level_count: 3,
+            })
+        );
+        assert_eq!(
+            generate(d2, rgba8, usage, 1..4),
+            Err(GenerateMipmapsError::InvalidMipRange {
+                range: 1..4,
+                mip_level_count: 3,
+            })
+        );
+        assert_eq!(
+            generate(
+                wgpu::TextureDimension::D3,
+                rgba8,
+                wgpu::TextureUsages::TEXTURE_BINDING,
+                1..3
+            ),
+            Err(GenerateMipmapsError::UnsupportedDimension(
+                wgpu::TextureDimension::D3
+            ))
+        );
+        assert_eq!(
+            generate(d2, wgpu::TextureFormat::Depth32Float, usage, 1..3),
+            Err(GenerateMipmapsError::UnsupportedFormat(
+                wgpu::TextureFormat::Depth32Float
+            ))
+        );
+        // Bgra8Unorm is only a storage format with `BGRA8UNORM_STORAGE`.
+        assert_eq!(
+            generate(
+                d2,
+                wgpu::TextureFormat::Bgra8Unorm,
+                wgpu::TextureUsages::TEXTURE_BINDING,
+                1..3
+            ),
+            Err(GenerateMipmapsError::UnsupportedFormat(
+                wgpu::TextureFormat::Bgra8Unorm
+            ))
+        );
+        assert_eq!(
+            generate(d2, rgba8, wgpu::TextureUsages::RENDER_ATTACHMENT, 1..3),
+            Err(GenerateMipmapsError::MissingUsage(
+                wgpu::TextureUsages::TEXTURE_BINDING
+            ))
+        );
+        assert_eq!(
+            generate(d2, rgba8, wgpu::TextureUsages::TEXTURE_BINDING, 1..3),
+            Err(GenerateMipmapsError::MissingUsage(
+                wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::STORAGE_BINDING
+            ))
+        );
+    });
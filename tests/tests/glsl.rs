@@ -0,0 +1,137 @@
+//! Tests for [`wgpu::ShaderSource::Glsl`].
+
+use wgpu_test::{gpu_test, FailureCase, GpuTestConfiguration, TestParameters, TestingContext};
+
+const SIZE: u32 = 4;
+
+const VERTEX: &str = r#"
+#version 450
+
+void main() {
+    float x = float(gl_VertexIndex / 2) * 4.0 - 1.0;
+    float y = 1.0 - float(gl_VertexIndex & 1) * 4.0;
+    gl_Position = vec4(x, y, 0.0, 1.0);
+}
+"#;
+
+const FRAGMENT: &str = r#"
+#version 450
+
+layout(location = 0) out vec4 color;
+
+void main() {
+    color = COLOR;
+}
+"#;
+
+fn create_glsl_module(
+    ctx: &TestingContext,
+    shader: &'static str,
+    stage: naga::ShaderStage,
+    defines: &[(&str, &str)],
+) -> wgpu::ShaderModule {
+    ctx.device
+        .create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("glsl"),
+            source: wgpu::ShaderSource::Glsl {
+                shader: shader.into(),
+                stage,
+                defines: defines
+                    .iter()
+                    .map(|&(name, value)| (name.to_string(), value.to_string()))
+                    .collect(),
+            },
+        })
+}
+
+#[gpu_test]
+static GLSL_TRIANGLE: GpuTestConfiguration = GpuTestConfiguration::new().run_sync(|ctx| {
+    let vertex = create_glsl_module(&ctx, VERTEX, naga::ShaderStage::Vertex, &[]);
+    let fragment = create_glsl_module(
+        &ctx,
+        FRAGMENT,
+        naga::ShaderStage::Fragment,
+        &[("COLOR", "vec4(0.0, 1.0, 0.0, 1.0)")],
+    );
+    let pipeline = ctx
+        .device
+        .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: None,
+            layout: None,
+            vertex: wgpu::VertexState {
+                module: &vertex,
+                entry_point: "main",
+                buffers: &[],
+            },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment,
+                entry_point: "main",
+                targets: &[Some(wgpu::TextureFormat::Rgba8Unorm.into())],
+            }),
+            multiview: None,
+        });
+
+    let target = ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("target"),
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let target_view = target.create_view(&wgpu::TextureViewDescriptor::default());
+    let readback_buffers = wgpu_test::image::ReadbackBuffers::new(&ctx.device, &target);
+
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    {
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: None,
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &target_view,
+                resolve_target: None,
+                ops: wgpu::Operations::default(),
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        pass.set_pipeline(&pipeline);
+        pass.draw(0..3, 0..1);
+    }
+    readback_buffers.copy_from(&ctx.device, &mut encoder, &target);
+    ctx.queue.submit(Some(encoder.finish()));
+
+    let expected = [0, 255, 0, 255].repeat((SIZE * SIZE) as usize);
+    readback_buffers.assert_buffer_contents(&ctx.device, &expected);
+});
+
+#[gpu_test]
+static GLSL_PARSE_ERROR: GpuTestConfiguration = GpuTestConfiguration::new()
+    // The WebGPU backend translates GLSL on the client and panics on parsing errors.
+    .parameters(
+        TestParameters::default().skip(FailureCase::backend(wgpu::Backends::BROWSER_WEBGPU)),
+    )
+    .run_sync(|ctx| {
+        // Without the define, `COLOR` is an unknown identifier.
+        ctx.device.push_error_scope(wgpu::ErrorFilter::Validation);
+        create_glsl_module(&ctx, FRAGMENT, naga::ShaderStage::Fragment, &[]);
+        let error = pollster::block_on(ctx.device.pop_error_scope())
+            .expect("parsing the shader without its define succeeded");
+
+        let message = error.to_string();
+        assert!(
+            message.contains("Shader 'glsl' parsing") && message.contains("COLOR"),
+            "the error does not describe the parsing failure: {message}"
+        );
+    });
@@ -2,3 +2,25 @@ use wgpu_test::{gpu_test, GpuTestConfiguration};
 
 #[gpu_test]
 static INITIALIZE: GpuTestConfiguration = GpuTestConfiguration::new().run_sync(|_ctx| {});
+
+#[cfg(not(target_arch = "wasm32"))]
+#[gpu_test]
+static ADAPTER_FROM_NAME: GpuTestConfiguration = GpuTestConfiguration::new().run_sync(|ctx| {
+    let name = &ctx.adapter_info.name;
+
+    // Names are matched case insensitively as substrings.
+    for query in [name.clone(), name.to_uppercase(), name.to_lowercase()] {
+        let adapter = wgpu::util::initialize_adapter_from_name(&ctx.instance, None, &query, None)
+            .expect("the adapter under test should match its own name");
+        let chosen = adapter.get_info().name.to_lowercase();
+        assert!(chosen.contains(&name.to_lowercase()), "{chosen}");
+    }
+
+    assert!(wgpu::util::initialize_adapter_from_name(
+        &ctx.instance,
+        None,
+        "no adapter is called this",
+        Some(wgpu::PowerPreference::HighPerformance),
+    )
+    .is_none());
+});
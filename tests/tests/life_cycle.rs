@@ -1,3 +1,5 @@
+use std::sync::{Arc, Mutex};
+
 use wgpu_test::{fail, gpu_test, GpuTestConfiguration};
 
 #[gpu_test]
@@ -93,3 +95,144 @@ static TEXTURE_DESTROY: GpuTestConfiguration = GpuTestConfiguration::new().run_s
 
     texture.destroy();
 });
+
+#[gpu_test]
+static BUFFER_DESTROY_BEFORE_COPY: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        let source = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("destroyed source"),
+            size: 256,
+            usage: wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let destination = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: 256,
+            usage: wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        source.destroy();
+
+        ctx.device.push_error_scope(wgpu::ErrorFilter::Validation);
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        encoder.copy_buffer_to_buffer(&source, 0, &destination, 0, 256);
+        drop(encoder.finish());
+        let error = pollster::block_on(ctx.device.pop_error_scope())
+            .expect("copy from a destroyed buffer was not rejected")
+            .to_string();
+        assert!(error.contains("destroyed source"), "{error:?}");
+    });
+
+#[gpu_test]
+static DESTROY_WHILE_IN_FLIGHT: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        let source = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: 1 << 20,
+            usage: wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let destination = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: 1 << 20,
+            usage: wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+            label: None,
+            size: wgpu::Extent3d {
+                width: 256,
+                height: 256,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        encoder.copy_buffer_to_buffer(&source, 0, &destination, 0, 1 << 20);
+        encoder.copy_buffer_to_texture(
+            wgpu::ImageCopyBuffer {
+                buffer: &source,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(256 * 4),
+                    rows_per_image: None,
+                },
+            },
+            texture.as_image_copy(),
+            texture.size(),
+        );
+        ctx.queue.submit(Some(encoder.finish()));
+
+        // The memory must stay alive until the submission completes.
+        source.destroy();
+        destination.destroy();
+        texture.destroy();
+        ctx.device.poll(wgpu::MaintainBase::Wait);
+    });
+
+#[gpu_test]
+static BUFFER_DESTROY_CANCELS_MAP: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        let buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: 256,
+            usage: wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let result = Arc::new(Mutex::new(None));
+        let callback_result = Arc::clone(&result);
+        buffer.slice(..).map_async(wgpu::MapMode::Read, move |res| {
+            *callback_result.lock().unwrap() = Some(res);
+        });
+        buffer.destroy();
+        ctx.device.poll(wgpu::MaintainBase::Wait);
+
+        assert_eq!(
+            result.lock().unwrap().take(),
+            Some(Err(wgpu::BufferAsyncError::Destroyed))
+        );
+    });
+
+#[gpu_test]
+static BUFFER_DESTROY_WITH_LIVE_VIEW: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        let buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: 256,
+            usage: wgpu::BufferUsages::MAP_WRITE,
+            mapped_at_creation: true,
+        });
+
+        let view = buffer.slice(..).get_mapped_range();
+        // Panics abort on wasm, so they can't be caught there.
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let destroy = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                buffer.destroy();
+            }));
+            let panic = destroy.expect_err("destroying a buffer with a live view must panic");
+            let message = panic
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+                .expect("panic payload should be a message");
+            assert!(
+                message.contains("still has accessible mapped views"),
+                "{message}"
+            );
+        }
+        drop(view);
+
+        buffer.destroy();
+    });
@@ -0,0 +1,146 @@
+//! Tests for [`wgpu::Features::MAPPABLE_PRIMARY_BUFFERS`], which lets a buffer be both
+//! mapped by the CPU and used directly by the GPU.
+
+use wgpu_test::{gpu_test, GpuTestConfiguration, TestParameters, TestingContext};
+
+const SIZE: u32 = 4;
+
+const SHADER: &str = r#"
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+}
+
+@vertex
+fn vs_main(@location(0) position: vec2<f32>, @location(1) color: vec4<f32>) -> VertexOutput {
+    return VertexOutput(vec4<f32>(position, 0.0, 1.0), color);
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return in.color;
+}
+"#;
+
+/// A fullscreen triangle with every vertex colored `color`, as `xy` + `rgba`.
+fn triangle(color: [f32; 4]) -> [f32; 18] {
+    let mut vertices = [0.0; 18];
+    for (vertex, position) in
+        vertices
+            .chunks_exact_mut(6)
+            .zip([[-1.0, -1.0], [3.0, -1.0], [-1.0, 3.0]])
+    {
+        vertex[..2].copy_from_slice(&position);
+        vertex[2..].copy_from_slice(&color);
+    }
+    vertices
+}
+
+/// Map `buffer` for writing and fill it with `vertices`.
+fn write_vertices(ctx: &TestingContext, buffer: &wgpu::Buffer, vertices: &[f32]) {
+    buffer
+        .slice(..)
+        .map_async(wgpu::MapMode::Write, Result::unwrap);
+    ctx.device.poll(wgpu::Maintain::Wait);
+    buffer
+        .slice(..)
+        .get_mapped_range_mut()
+        .copy_from_slice(bytemuck::cast_slice(vertices));
+    buffer.unmap();
+}
+
+#[gpu_test]
+static MAPPABLE_VERTEX_BUFFER: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().features(wgpu::Features::MAPPABLE_PRIMARY_BUFFERS))
+    .run_sync(|ctx| {
+        let shader = ctx
+            .device
+            .create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: None,
+                source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+            });
+        let pipeline = ctx
+            .device
+            .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: None,
+                layout: None,
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[wgpu::VertexBufferLayout {
+                        array_stride: 24,
+                        step_mode: wgpu::VertexStepMode::Vertex,
+                        attributes: &wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x4],
+                    }],
+                },
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_main",
+                    targets: &[Some(wgpu::TextureFormat::Rgba8Unorm.into())],
+                }),
+                multiview: None,
+            });
+
+        // The vertex buffer is written through a mapping, without a staging copy.
+        let vertex_buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("vertices"),
+            size: std::mem::size_of::<[f32; 18]>() as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::MAP_WRITE,
+            mapped_at_creation: false,
+        });
+
+        let target = ctx.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("target"),
+            size: wgpu::Extent3d {
+                width: SIZE,
+                height: SIZE,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let target_view = target.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let draw = |color: [f32; 4]| {
+            write_vertices(&ctx, &vertex_buffer, &triangle(color));
+
+            let readback_buffers = wgpu_test::image::ReadbackBuffers::new(&ctx.device, &target);
+            let mut encoder = ctx
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+            {
+                let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: None,
+                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                        view: &target_view,
+                        resolve_target: None,
+                        ops: wgpu::Operations::default(),
+                    })],
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+                pass.set_pipeline(&pipeline);
+                pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+                pass.draw(0..3, 0..1);
+            }
+            readback_buffers.copy_from(&ctx.device, &mut encoder, &target);
+            ctx.queue.submit(Some(encoder.finish()));
+
+            let expected = color
+                .map(|channel| (channel * 255.0) as u8)
+                .repeat((SIZE * SIZE) as usize);
+            readback_buffers.assert_buffer_contents(&ctx.device, &expected);
+        };
+
+        // Remapping the buffer after the GPU used it must pick up the new contents.
+        draw([1.0, 0.0, 0.0, 1.0]);
+        draw([0.0, 1.0, 0.0, 1.0]);
+    });
@@ -0,0 +1,24 @@
+// Draws one triangle per group of three vertices. Triangle `n` covers column `n` of a
+// four pixel wide target, and spills into column `n + 1`, which the next draw overwrites.
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) @interpolate(flat) column: u32,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    let column = vertex_index / 3u;
+    let corner = vertex_index % 3u;
+    let x = f32(column) * 0.5 - 1.0 + f32(corner == 1u);
+    let y = select(-1.0, 3.0, corner == 2u);
+
+    var out: VertexOutput;
+    out.position = vec4<f32>(x, y, 0.0, 1.0);
+    out.column = column;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(f32(in.column) / 3.0, 1.0, 0.0, 1.0);
+}
@@ -0,0 +1,454 @@
+//! Regression tests for `multi_draw_indirect` and `multi_draw_indexed_indirect`,
+//! and their `_count` variants, with large draw counts.
+//!
+//! Issuing a multi-draw whose count runs past the end of the indirect buffer
+//! used to be passed straight through to the backend, where it could read out of
+//! bounds and reset the GPU. These tests record the same stream of draws both
+//! in bounds and one record past the end of the buffer.
+
+use wgpu::util::{BufferInitDescriptor, DeviceExt};
+
+use wgpu_test::{
+    gpu_test, record_and_finish, valid, GpuTestConfiguration, TestParameters, TestingContext,
+};
+
+const TEXTURE_WIDTH: u32 = 4;
+const TEXTURE_HEIGHT: u32 = 4;
+const BUFFER_SIZE: usize = (TEXTURE_WIDTH * TEXTURE_HEIGHT * 4) as usize;
+
+/// Number of draw records stored in the indirect buffer.
+const RECORD_COUNT: usize = 4096;
+
+fn multi_draw_params() -> TestParameters {
+    TestParameters::default()
+        .features(wgpu::Features::MULTI_DRAW_INDIRECT)
+        .downlevel_flags(wgpu::DownlevelFlags::INDIRECT_EXECUTION)
+}
+
+/// Without [`wgpu::Features::MULTI_DRAW_INDIRECT`], multi-draws are emulated with a
+/// loop of single indirect draws.
+fn emulated_params() -> TestParameters {
+    TestParameters::default().downlevel_flags(wgpu::DownlevelFlags::INDIRECT_EXECUTION)
+}
+
+fn multi_draw_count_params() -> TestParameters {
+    TestParameters::default()
+        .features(wgpu::Features::MULTI_DRAW_INDIRECT_COUNT)
+        .downlevel_flags(wgpu::DownlevelFlags::INDIRECT_EXECUTION)
+}
+
+/// Build an indirect buffer holding [`RECORD_COUNT`] draws of the same full screen
+/// triangle, then render `count` of them into an offscreen target.
+///
+/// If `count_offset` is `Some`, the draw count is read from a count buffer at that
+/// offset instead, with `count` as the maximum.
+///
+/// If the draw is valid, every pixel of the target must be green. If it isn't, the
+/// draw must be rejected with a validation error.
+fn multi_draw_indirect_test_impl(
+    ctx: &TestingContext,
+    count: usize,
+    indexed: bool,
+    count_offset: Option<u64>,
+) {
+    // Substrings the validation error must contain, if the draw is invalid.
+    let expected_error = if count > RECORD_COUNT {
+        let mut expected = vec![
+            "indirect buffer = `indirect buffer`".to_string(),
+            format!("at most {RECORD_COUNT} draws fit"),
+        ];
+        if count_offset.is_none() {
+            expected.push(format!("count {count}"));
+        }
+        Some(expected)
+    } else {
+        match count_offset {
+            Some(offset) if offset % 4 != 0 => Some(vec![format!(
+                "count offset {offset} is not a multiple of 4"
+            )]),
+            _ => None,
+        }
+    };
+
+    let mut indirect_contents = Vec::new();
+    for _ in 0..RECORD_COUNT {
+        if indexed {
+            indirect_contents.extend_from_slice(
+                wgpu::util::DrawIndexedIndirectArgs {
+                    index_count: 3,
+                    instance_count: 1,
+                    first_index: 0,
+                    base_vertex: 0,
+                    first_instance: 0,
+                }
+                .as_bytes(),
+            );
+        } else {
+            indirect_contents.extend_from_slice(
+                wgpu::util::DrawIndirectArgs {
+                    vertex_count: 3,
+                    instance_count: 1,
+                    first_vertex: 0,
+                    first_instance: 0,
+                }
+                .as_bytes(),
+            );
+        }
+    }
+
+    let indirect_buffer = ctx.device.create_buffer_init(&BufferInitDescriptor {
+        label: Some("indirect buffer"),
+        contents: &indirect_contents,
+        usage: wgpu::BufferUsages::INDIRECT,
+    });
+
+    let index_buffer = ctx.device.create_buffer_init(&BufferInitDescriptor {
+        label: Some("index buffer"),
+        contents: bytemuck::cast_slice(&[0u32, 1, 2, 0]),
+        usage: wgpu::BufferUsages::INDEX,
+    });
+
+    // The count is written at offsets 0, 4 and 8, so every aligned count offset the tests
+    // use reads it. Unaligned offsets are rejected before the buffer is read.
+    let count_buffer = ctx.device.create_buffer_init(&BufferInitDescriptor {
+        label: Some("count buffer"),
+        contents: &[(count as u32).to_le_bytes(); 3].concat(),
+        usage: wgpu::BufferUsages::INDIRECT,
+    });
+
+    let texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("Offscreen texture"),
+        size: wgpu::Extent3d {
+            width: TEXTURE_WIDTH,
+            height: TEXTURE_HEIGHT,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: wgpu::TextureUsages::COPY_SRC | wgpu::TextureUsages::RENDER_ATTACHMENT,
+        view_formats: &[],
+    });
+    let texture_view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    let shader = ctx
+        .device
+        .create_shader_module(wgpu::include_wgsl!("green.wgsl"));
+
+    let pipeline = ctx
+        .device
+        .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Pipeline"),
+            layout: None,
+            vertex: wgpu::VertexState {
+                entry_point: "vs_main",
+                module: &shader,
+                buffers: &[],
+            },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                entry_point: "fs_main",
+                module: &shader,
+                targets: &[Some(wgpu::TextureFormat::Rgba8Unorm.into())],
+            }),
+            multiview: None,
+        });
+
+    let readback_buffer = wgpu_test::image::ReadbackBuffers::new(&ctx.device, &texture);
+
+    let encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+    let result = record_and_finish(&ctx.device, encoder, |encoder| {
+        {
+            let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Renderpass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &texture_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            rpass.set_pipeline(&pipeline);
+            if indexed {
+                rpass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+            }
+            match (indexed, count_offset) {
+                (false, None) => rpass.multi_draw_indirect(&indirect_buffer, 0, count as u32),
+                (true, None) => {
+                    rpass.multi_draw_indexed_indirect(&indirect_buffer, 0, count as u32)
+                }
+                (false, Some(offset)) => rpass.multi_draw_indirect_count(
+                    &indirect_buffer,
+                    0,
+                    &count_buffer,
+                    offset,
+                    count as u32,
+                ),
+                (true, Some(offset)) => rpass.multi_draw_indexed_indirect_count(
+                    &indirect_buffer,
+                    0,
+                    &count_buffer,
+                    offset,
+                    count as u32,
+                ),
+            }
+        }
+        readback_buffer.copy_from(&ctx.device, encoder, &texture);
+    });
+
+    if let Some(expected_error) = expected_error {
+        let error = result.expect_err("invalid multi-draw was not rejected");
+        for expected in expected_error {
+            assert!(error.contains(&expected), "{expected:?} not in {error:?}");
+        }
+        return;
+    }
+
+    ctx.queue.submit(Some(result.unwrap()));
+
+    let expected = [0, 255, 0, 255].repeat(BUFFER_SIZE / 4);
+    readback_buffer.assert_buffer_contents(&ctx.device, &expected);
+}
+
+#[gpu_test]
+static MULTI_DRAW_INDIRECT: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(multi_draw_params())
+    .run_sync(|ctx| multi_draw_indirect_test_impl(&ctx, RECORD_COUNT, false, None));
+
+#[gpu_test]
+static MULTI_DRAW_INDEXED_INDIRECT: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(multi_draw_params())
+    .run_sync(|ctx| multi_draw_indirect_test_impl(&ctx, RECORD_COUNT, true, None));
+
+#[gpu_test]
+static MULTI_DRAW_INDIRECT_OUT_OF_BOUNDS: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(multi_draw_params())
+    .run_sync(|ctx| multi_draw_indirect_test_impl(&ctx, RECORD_COUNT + 1, false, None));
+
+#[gpu_test]
+static MULTI_DRAW_INDEXED_INDIRECT_OUT_OF_BOUNDS: GpuTestConfiguration =
+    GpuTestConfiguration::new()
+        .parameters(multi_draw_params())
+        .run_sync(|ctx| multi_draw_indirect_test_impl(&ctx, RECORD_COUNT + 1, true, None));
+
+#[gpu_test]
+static MULTI_DRAW_INDIRECT_COUNT: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(multi_draw_count_params())
+    .run_sync(|ctx| multi_draw_indirect_test_impl(&ctx, RECORD_COUNT, false, Some(4)));
+
+#[gpu_test]
+static MULTI_DRAW_INDEXED_INDIRECT_COUNT: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(multi_draw_count_params())
+    .run_sync(|ctx| multi_draw_indirect_test_impl(&ctx, RECORD_COUNT, true, Some(4)));
+
+#[gpu_test]
+static MULTI_DRAW_INDIRECT_COUNT_OUT_OF_BOUNDS: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(multi_draw_count_params())
+    .run_sync(|ctx| multi_draw_indirect_test_impl(&ctx, RECORD_COUNT + 1, false, Some(0)));
+
+#[gpu_test]
+static MULTI_DRAW_INDIRECT_COUNT_UNALIGNED: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(multi_draw_count_params())
+    .run_sync(|ctx| multi_draw_indirect_test_impl(&ctx, RECORD_COUNT, false, Some(2)));
+
+#[gpu_test]
+static MULTI_DRAW_INDEXED_INDIRECT_COUNT_UNALIGNED: GpuTestConfiguration =
+    GpuTestConfiguration::new()
+        .parameters(multi_draw_count_params())
+        .run_sync(|ctx| multi_draw_indirect_test_impl(&ctx, RECORD_COUNT, true, Some(2)));
+
+#[gpu_test]
+static MULTI_DRAW_INDIRECT_EMULATED_OUT_OF_BOUNDS: GpuTestConfiguration =
+    GpuTestConfiguration::new()
+        .parameters(emulated_params())
+        .run_sync(|ctx| multi_draw_indirect_test_impl(&ctx, RECORD_COUNT + 1, false, None));
+
+#[gpu_test]
+static MULTI_DRAW_INDEXED_INDIRECT_EMULATED_OUT_OF_BOUNDS: GpuTestConfiguration =
+    GpuTestConfiguration::new()
+        .parameters(emulated_params())
+        .run_sync(|ctx| multi_draw_indirect_test_impl(&ctx, RECORD_COUNT + 1, true, None));
+
+/// Number of draws in the emulated multi-draw tests, one per column of the target.
+const COLUMN_COUNT: u32 = TEXTURE_WIDTH;
+
+/// Draw one triangle per column of the target, with `draw`, and check that each column
+/// has its own color.
+///
+/// Each draw covers a different range of vertices or indices, so draws that read the
+/// wrong record, or are issued out of order, show up as a wrong column.
+fn emulated_multi_draw_test_impl(
+    ctx: &TestingContext,
+    indexed: bool,
+    draw: impl for<'a> FnOnce(&mut wgpu::RenderPass<'a>, &'a wgpu::Buffer),
+) {
+    let mut indirect_contents = Vec::new();
+    for column in 0..COLUMN_COUNT {
+        if indexed {
+            indirect_contents.extend_from_slice(
+                wgpu::util::DrawIndexedIndirectArgs {
+                    index_count: 3,
+                    instance_count: 1,
+                    first_index: column * 3,
+                    base_vertex: 0,
+                    first_instance: 0,
+                }
+                .as_bytes(),
+            );
+        } else {
+            indirect_contents.extend_from_slice(
+                wgpu::util::DrawIndirectArgs {
+                    vertex_count: 3,
+                    instance_count: 1,
+                    first_vertex: column * 3,
+                    first_instance: 0,
+                }
+                .as_bytes(),
+            );
+        }
+    }
+
+    let indirect_buffer = ctx.device.create_buffer_init(&BufferInitDescriptor {
+        label: Some("indirect buffer"),
+        contents: &indirect_contents,
+        usage: wgpu::BufferUsages::INDIRECT,
+    });
+
+    let indices: Vec<u32> = (0..COLUMN_COUNT * 3).collect();
+    let index_buffer = ctx.device.create_buffer_init(&BufferInitDescriptor {
+        label: Some("index buffer"),
+        contents: bytemuck::cast_slice(&indices),
+        usage: wgpu::BufferUsages::INDEX,
+    });
+
+    let texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("Offscreen texture"),
+        size: wgpu::Extent3d {
+            width: TEXTURE_WIDTH,
+            height: TEXTURE_HEIGHT,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: wgpu::TextureUsages::COPY_SRC | wgpu::TextureUsages::RENDER_ATTACHMENT,
+        view_formats: &[],
+    });
+    let texture_view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    let shader = ctx
+        .device
+        .create_shader_module(wgpu::include_wgsl!("columns.wgsl"));
+
+    let pipeline = ctx
+        .device
+        .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Pipeline"),
+            layout: None,
+            vertex: wgpu::VertexState {
+                entry_point: "vs_main",
+                module: &shader,
+                buffers: &[],
+            },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                entry_point: "fs_main",
+                module: &shader,
+                targets: &[Some(wgpu::TextureFormat::Rgba8Unorm.into())],
+            }),
+            multiview: None,
+        });
+
+    let readback_buffer = wgpu_test::image::ReadbackBuffers::new(&ctx.device, &texture);
+
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+    {
+        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Renderpass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &texture_view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        rpass.set_pipeline(&pipeline);
+        if indexed {
+            rpass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+        }
+        draw(&mut rpass, &indirect_buffer);
+    }
+    readback_buffer.copy_from(&ctx.device, &mut encoder, &texture);
+    let command_buffer = valid(&ctx.device, || encoder.finish());
+    ctx.queue.submit(Some(command_buffer));
+
+    let row: Vec<u8> = (0..COLUMN_COUNT)
+        .flat_map(|column| [(column * 255 / (COLUMN_COUNT - 1)) as u8, 255, 0, 255])
+        .collect();
+    let expected = row.repeat(TEXTURE_HEIGHT as usize);
+    readback_buffer.assert_buffer_contents(&ctx.device, &expected);
+}
+
+#[gpu_test]
+static MULTI_DRAW_INDIRECT_EMULATED: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(emulated_params())
+    .run_sync(|ctx| {
+        emulated_multi_draw_test_impl(&ctx, false, |rpass, indirect_buffer| {
+            rpass.multi_draw_indirect(indirect_buffer, 0, COLUMN_COUNT)
+        })
+    });
+
+#[gpu_test]
+static MULTI_DRAW_INDEXED_INDIRECT_EMULATED: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(emulated_params())
+    .run_sync(|ctx| {
+        emulated_multi_draw_test_impl(&ctx, true, |rpass, indirect_buffer| {
+            rpass.multi_draw_indexed_indirect(indirect_buffer, 0, COLUMN_COUNT)
+        })
+    });
+
+/// The same draws as [`MULTI_DRAW_INDIRECT_EMULATED`], issued one at a time.
+#[gpu_test]
+static MULTI_DRAW_INDIRECT_MANUAL: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(emulated_params())
+    .run_sync(|ctx| {
+        emulated_multi_draw_test_impl(&ctx, false, |rpass, indirect_buffer| {
+            let stride = std::mem::size_of::<wgpu::util::DrawIndirectArgs>() as u64;
+            for column in 0..COLUMN_COUNT as u64 {
+                rpass.draw_indirect(indirect_buffer, column * stride);
+            }
+        })
+    });
+
+/// The same draws as [`MULTI_DRAW_INDEXED_INDIRECT_EMULATED`], issued one at a time.
+#[gpu_test]
+static MULTI_DRAW_INDEXED_INDIRECT_MANUAL: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(emulated_params())
+    .run_sync(|ctx| {
+        emulated_multi_draw_test_impl(&ctx, true, |rpass, indirect_buffer| {
+            let stride = std::mem::size_of::<wgpu::util::DrawIndexedIndirectArgs>() as u64;
+            for column in 0..COLUMN_COUNT as u64 {
+                rpass.draw_indexed_indirect(indirect_buffer, column * stride);
+            }
+        })
+    });
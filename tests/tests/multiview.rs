@@ -0,0 +1,135 @@
+//! Tests for multiview render passes, where one draw renders to every layer of an
+//! array attachment and the shader tells the layers apart with `@builtin(view_index)`.
+
+use std::num::NonZeroU32;
+
+use wgpu_test::{
+    fail, fullscreen_shader, gpu_test, image::ReadbackBuffers, GpuTestConfiguration,
+    TestParameters, TestingContext,
+};
+
+const SIZE: u32 = 4;
+const LAYERS: u32 = 2;
+
+const FRAGMENT_SHADER: &str = r#"
+@fragment
+fn fs_main(@builtin(view_index) view_index: i32) -> @location(0) vec4<f32> {
+    if view_index == 0 {
+        return vec4<f32>(1.0, 0.0, 0.0, 1.0);
+    }
+    return vec4<f32>(0.0, 1.0, 0.0, 1.0);
+}
+"#;
+
+fn multiview_params() -> TestParameters {
+    TestParameters::default().features(wgpu::Features::MULTIVIEW)
+}
+
+fn create_pipeline(ctx: &TestingContext, multiview: Option<NonZeroU32>) -> wgpu::RenderPipeline {
+    let shader = fullscreen_shader(&ctx.device, FRAGMENT_SHADER);
+
+    ctx.device
+        .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: None,
+            layout: None,
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::TextureFormat::Rgba8Unorm.into())],
+            }),
+            multiview,
+        })
+}
+
+fn create_target(ctx: &TestingContext) -> (wgpu::Texture, wgpu::TextureView) {
+    let texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("target"),
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: LAYERS,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let view = texture.create_view(&wgpu::TextureViewDescriptor {
+        dimension: Some(wgpu::TextureViewDimension::D2Array),
+        array_layer_count: Some(LAYERS),
+        ..Default::default()
+    });
+    (texture, view)
+}
+
+fn begin_render_pass<'a>(
+    encoder: &'a mut wgpu::CommandEncoder,
+    view: &'a wgpu::TextureView,
+) -> wgpu::RenderPass<'a> {
+    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: None,
+        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+            view,
+            resolve_target: None,
+            ops: wgpu::Operations::default(),
+        })],
+        depth_stencil_attachment: None,
+        timestamp_writes: None,
+        occlusion_query_set: None,
+    })
+}
+
+#[gpu_test]
+static MULTIVIEW_VIEW_INDEX: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(multiview_params())
+    .run_sync(|ctx| {
+        let pipeline = create_pipeline(&ctx, NonZeroU32::new(LAYERS));
+        let (texture, view) = create_target(&ctx);
+
+        let readback = ReadbackBuffers::new(&ctx.device, &texture);
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = begin_render_pass(&mut encoder, &view);
+            pass.set_pipeline(&pipeline);
+            pass.draw(0..3, 0..1);
+        }
+        readback.copy_from(&ctx.device, &mut encoder, &texture);
+        ctx.queue.submit(Some(encoder.finish()));
+
+        // Layer 0 is red and layer 1 green.
+        let pixels_per_layer = (SIZE * SIZE) as usize;
+        let mut expected = [255u8, 0, 0, 255].repeat(pixels_per_layer);
+        expected.extend([0u8, 255, 0, 255].repeat(pixels_per_layer));
+        readback.assert_buffer_contents(&ctx.device, &expected);
+    });
+
+#[gpu_test]
+static MULTIVIEW_PIPELINE_MISMATCH: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(multiview_params())
+    .run_sync(|ctx| {
+        // The pass targets two layers, but the pipeline renders a single view.
+        let pipeline = create_pipeline(&ctx, None);
+        let (_texture, view) = create_target(&ctx);
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        fail(&ctx.device, || {
+            let mut pass = begin_render_pass(&mut encoder, &view);
+            pass.set_pipeline(&pipeline);
+            pass.draw(0..3, 0..1);
+        });
+    });
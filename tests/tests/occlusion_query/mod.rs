@@ -1,5 +1,7 @@
 use std::borrow::Cow;
-use wgpu_test::{gpu_test, FailureCase, GpuTestConfiguration, TestParameters};
+use wgpu_test::{
+    fail, gpu_test, FailureCase, GpuTestConfiguration, TestParameters, TestingContext,
+};
 
 #[gpu_test]
 static OCCLUSION_QUERY: GpuTestConfiguration = GpuTestConfiguration::new()
@@ -126,3 +128,204 @@ static OCCLUSION_QUERY: GpuTestConfiguration = GpuTestConfiguration::new()
         assert_ne!(query_data[1], 0);
         assert_eq!(query_data[2], 0);
     });
+
+const SIZE: u32 = 64;
+
+fn create_depth_view(ctx: &TestingContext) -> wgpu::TextureView {
+    let depth_texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("Depth texture"),
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Depth32Float,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        view_formats: &[],
+    });
+    depth_texture.create_view(&wgpu::TextureViewDescriptor::default())
+}
+
+/// Setup pipeline using a simple shader with hardcoded vertices.
+///
+/// Vertices `0..3` are a triangle at z = 0.0 and `4..7` the same triangle at z = 0.5.
+fn create_pipeline(ctx: &TestingContext) -> wgpu::RenderPipeline {
+    let shader = ctx
+        .device
+        .create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Shader module"),
+            source: wgpu::ShaderSource::Wgsl(Cow::Borrowed(include_str!("shader.wgsl"))),
+        });
+    ctx.device
+        .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Pipeline"),
+            layout: None,
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            fragment: None,
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float,
+                depth_write_enabled: true,
+                depth_compare: wgpu::CompareFunction::Less,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        })
+}
+
+fn create_query_set(ctx: &TestingContext, count: u32) -> wgpu::QuerySet {
+    ctx.device.create_query_set(&wgpu::QuerySetDescriptor {
+        label: Some("Query set"),
+        ty: wgpu::QueryType::Occlusion,
+        count,
+    })
+}
+
+fn begin_render_pass<'a>(
+    encoder: &'a mut wgpu::CommandEncoder,
+    depth_texture_view: &'a wgpu::TextureView,
+    query_set: &'a wgpu::QuerySet,
+) -> wgpu::RenderPass<'a> {
+    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some("Render pass"),
+        color_attachments: &[],
+        depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+            view: depth_texture_view,
+            depth_ops: Some(wgpu::Operations {
+                load: wgpu::LoadOp::Clear(1.0),
+                store: wgpu::StoreOp::Store,
+            }),
+            stencil_ops: None,
+        }),
+        timestamp_writes: None,
+        occlusion_query_set: Some(query_set),
+    })
+}
+
+/// Resolve every query in `query_set`, submit `encoder` and read the results back.
+fn resolve_queries(
+    ctx: &TestingContext,
+    mut encoder: wgpu::CommandEncoder,
+    query_set: &wgpu::QuerySet,
+    count: u32,
+) -> Vec<u64> {
+    let query_buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("Query buffer"),
+        size: std::mem::size_of::<u64>() as u64 * count as u64,
+        usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC,
+        mapped_at_creation: false,
+    });
+    encoder.resolve_query_set(query_set, 0..count, &query_buffer, 0);
+
+    let mapping_buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("Mapping buffer"),
+        size: query_buffer.size(),
+        usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+    encoder.copy_buffer_to_buffer(&query_buffer, 0, &mapping_buffer, 0, query_buffer.size());
+
+    ctx.queue.submit(Some(encoder.finish()));
+
+    mapping_buffer
+        .slice(..)
+        .map_async(wgpu::MapMode::Read, |_| ());
+    ctx.device.poll(wgpu::Maintain::Wait);
+    let query_buffer_view = mapping_buffer.slice(..).get_mapped_range();
+    bytemuck::cast_slice::<u8, u64>(&query_buffer_view).to_vec()
+}
+
+#[gpu_test]
+static OCCLUSION_QUERY_SCISSORED: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().expect_fail(FailureCase::webgl2()))
+    .run_sync(|ctx| {
+        let depth_texture_view = create_depth_view(&ctx);
+        let pipeline = create_pipeline(&ctx);
+        let query_set = create_query_set(&ctx, 2);
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut render_pass = begin_render_pass(&mut encoder, &depth_texture_view, &query_set);
+            render_pass.set_pipeline(&pipeline);
+
+            // Scissored out: the triangle doesn't reach the top left corner.
+            render_pass.set_scissor_rect(0, 0, 4, 4);
+            render_pass.begin_occlusion_query(0);
+            render_pass.draw(0..3, 0..1);
+            render_pass.end_occlusion_query();
+
+            // Visible
+            render_pass.set_scissor_rect(0, 0, SIZE, SIZE);
+            render_pass.begin_occlusion_query(1);
+            render_pass.draw(0..3, 0..1);
+            render_pass.end_occlusion_query();
+        }
+
+        let query_data = resolve_queries(&ctx, encoder, &query_set, 2);
+
+        assert_eq!(query_data[0], 0);
+        assert_ne!(query_data[1], 0);
+    });
+
+#[gpu_test]
+static OCCLUSION_QUERY_NESTED: GpuTestConfiguration = GpuTestConfiguration::new().run_sync(|ctx| {
+    let depth_texture_view = create_depth_view(&ctx);
+    let query_set = create_query_set(&ctx, 2);
+
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    fail(&ctx.device, || {
+        let mut render_pass = begin_render_pass(&mut encoder, &depth_texture_view, &query_set);
+        render_pass.begin_occlusion_query(0);
+        render_pass.begin_occlusion_query(1);
+        render_pass.end_occlusion_query();
+        render_pass.end_occlusion_query();
+    });
+});
+
+#[gpu_test]
+static OCCLUSION_QUERY_OUT_OF_BOUNDS: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        let depth_texture_view = create_depth_view(&ctx);
+        let query_set = create_query_set(&ctx, 2);
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        fail(&ctx.device, || {
+            let mut render_pass = begin_render_pass(&mut encoder, &depth_texture_view, &query_set);
+            render_pass.begin_occlusion_query(2);
+            render_pass.end_occlusion_query();
+        });
+    });
+
+#[gpu_test]
+static OCCLUSION_QUERY_RESOLVE_MISSING_USAGE: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| {
+        let query_set = create_query_set(&ctx, 1);
+        let buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Query buffer"),
+            size: std::mem::size_of::<u64>() as u64,
+            usage: wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        fail(&ctx.device, || {
+            encoder.resolve_query_set(&query_set, 0..1, &buffer, 0);
+        });
+    });
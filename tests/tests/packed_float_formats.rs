@@ -0,0 +1,230 @@
+//! Tests for the packed float formats [`wgpu::TextureFormat::Rgb9e5Ufloat`] and
+//! [`wgpu::TextureFormat::Rg11b10Float`].
+
+use wgpu::util::DeviceExt;
+use wgpu_test::{
+    fullscreen_shader, gpu_test, image::ReadbackBuffers, read_buffer, GpuTestConfiguration,
+    TestParameters,
+};
+
+const SIZE: u32 = 2;
+
+/// Pack 9 bit mantissas sharing a single exponent, which has a bias of 15. Each channel is
+/// `mantissa * 2^(exponent - 15 - 9)`.
+fn rgb9e5(r: u32, g: u32, b: u32, exponent: u32) -> u32 {
+    r | g << 9 | b << 18 | exponent << 27
+}
+
+/// Decode an unsigned float with a 5 bit exponent and `mantissa_bits` bits of mantissa.
+fn decode_ufloat(bits: u32, mantissa_bits: u32) -> f32 {
+    let mantissa = (bits & ((1 << mantissa_bits) - 1)) as f32 / (1 << mantissa_bits) as f32;
+    let exponent = (bits >> mantissa_bits) & 0x1f;
+    if exponent == 0 {
+        mantissa * 2f32.powi(-14)
+    } else {
+        (1.0 + mantissa) * 2f32.powi(exponent as i32 - 15)
+    }
+}
+
+#[gpu_test]
+static RGB9E5UFLOAT_SAMPLE: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().downlevel_flags(wgpu::DownlevelFlags::COMPUTE_SHADERS))
+    .run_sync(|ctx| {
+        let texels = [
+            (rgb9e5(256, 128, 64, 16), [1.0, 0.5, 0.25]),
+            (0, [0.0, 0.0, 0.0]),
+            (rgb9e5(384, 0, 192, 17), [3.0, 0.0, 1.5]),
+            (rgb9e5(1, 256, 0, 17), [1.0 / 128.0, 2.0, 0.0]),
+        ];
+        let data: Vec<u32> = texels.iter().map(|&(packed, _)| packed).collect();
+
+        // Texels are 4 bytes, like any other 32 bit format.
+        let texture = ctx.device.create_texture_with_data(
+            &ctx.queue,
+            &wgpu::TextureDescriptor {
+                label: Some("rgb9e5"),
+                size: wgpu::Extent3d {
+                    width: SIZE,
+                    height: SIZE,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgb9e5Ufloat,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            },
+            wgpu::util::TextureDataOrder::LayerMajor,
+            bytemuck::cast_slice(&data),
+        );
+
+        let shader = ctx
+            .device
+            .create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: None,
+                source: wgpu::ShaderSource::Wgsl(
+                    r#"
+                    @group(0) @binding(0)
+                    var source: texture_2d<f32>;
+                    @group(0) @binding(1)
+                    var<storage, read_write> output: array<vec4<f32>>;
+
+                    @compute @workgroup_size(1)
+                    fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+                        output[id.y * 2u + id.x] = textureLoad(source, id.xy, 0);
+                    }
+                    "#
+                    .into(),
+                ),
+            });
+        let pipeline = ctx
+            .device
+            .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: None,
+                layout: None,
+                module: &shader,
+                entry_point: "main",
+            });
+
+        let output = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("output"),
+            size: (texels.len() * 16) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let bind_group = ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &pipeline.get_bind_group_layout(0),
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(
+                        &texture.create_view(&wgpu::TextureViewDescriptor::default()),
+                    ),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: output.as_entire_binding(),
+                },
+            ],
+        });
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(&pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(SIZE, SIZE, 1);
+        }
+        ctx.queue.submit(Some(encoder.finish()));
+
+        let data = read_buffer(&ctx.device, &ctx.queue, &output);
+        let decoded: &[[f32; 4]] = bytemuck::cast_slice(&data);
+        for (&(packed, [r, g, b]), texel) in texels.iter().zip(decoded) {
+            assert_eq!(*texel, [r, g, b, 1.0], "texel {packed:#010x}");
+        }
+    });
+
+#[gpu_test]
+static RG11B10FLOAT_RENDER_AND_BLEND: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().features(wgpu::Features::RG11B10UFLOAT_RENDERABLE))
+    .run_sync(|ctx| {
+        let format = wgpu::TextureFormat::Rg11b10Float;
+        let shader = fullscreen_shader(
+            &ctx.device,
+            r#"
+            @fragment
+            fn fs_main() -> @location(0) vec4<f32> {
+                return vec4<f32>(0.5, 2.0, 0.125, 1.0);
+            }
+            "#,
+        );
+        // Additive blending, as when accumulating a bloom chain.
+        let additive = wgpu::BlendComponent {
+            src_factor: wgpu::BlendFactor::One,
+            dst_factor: wgpu::BlendFactor::One,
+            operation: wgpu::BlendOperation::Add,
+        };
+        let pipeline = ctx
+            .device
+            .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: None,
+                layout: None,
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                },
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_main",
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState {
+                            color: additive,
+                            alpha: additive,
+                        }),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                multiview: None,
+            });
+
+        let texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("rg11b10"),
+            size: wgpu::Extent3d {
+                width: SIZE,
+                height: SIZE,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let readback = ReadbackBuffers::new(&ctx.device, &texture);
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: None,
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::RED),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_pipeline(&pipeline);
+            pass.draw(0..3, 0..1);
+        }
+        readback.copy_from(&ctx.device, &mut encoder, &texture);
+        ctx.queue.submit(Some(encoder.finish()));
+
+        for texel in readback.contents(&ctx.device).chunks_exact(4) {
+            let bits = u32::from_le_bytes(texel.try_into().unwrap());
+            let decoded = [
+                decode_ufloat(bits & 0x7ff, 6),
+                decode_ufloat((bits >> 11) & 0x7ff, 6),
+                decode_ufloat(bits >> 22, 5),
+            ];
+            // The red the target was cleared to, plus what the shader wrote.
+            assert_eq!(decoded, [1.5, 2.0, 0.125]);
+        }
+    });
@@ -12,7 +12,10 @@ static PARTIALLY_BOUNDED_ARRAY: GpuTestConfiguration = GpuTestConfiguration::new
                     | wgpu::Features::PARTIALLY_BOUND_BINDING_ARRAY
                     | wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES,
             )
-            .limits(wgpu::Limits::downlevel_defaults()),
+            .limits(wgpu::Limits {
+                max_binding_array_elements: 4,
+                ..wgpu::Limits::downlevel_defaults()
+            }),
     )
     .run_sync(|ctx| {
         let device = &ctx.device;
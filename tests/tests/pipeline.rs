@@ -1,18 +1,11 @@
-use wgpu_test::{fail, gpu_test, FailureCase, GpuTestConfiguration, TestParameters};
+use wgpu_test::{fail, gpu_test, valid, GpuTestConfiguration, TestParameters};
 
 // Create an invalid shader and a compute pipeline that uses it
 // with a default bindgroup layout, and then ask for that layout.
 // Validation should fail, but wgpu should not panic.
 #[gpu_test]
 static PIPELINE_DEFAULT_LAYOUT_BAD_MODULE: GpuTestConfiguration = GpuTestConfiguration::new()
-    .parameters(
-        TestParameters::default()
-            // https://github.com/gfx-rs/wgpu/issues/4167
-            .expect_fail(FailureCase::always().panic("Pipeline is invalid")),
-    )
     .run_sync(|ctx| {
-        ctx.device.push_error_scope(wgpu::ErrorFilter::Validation);
-
         fail(&ctx.device, || {
             let module = ctx
                 .device
@@ -33,3 +26,99 @@ static PIPELINE_DEFAULT_LAYOUT_BAD_MODULE: GpuTestConfiguration = GpuTestConfigu
             pipeline.get_bind_group_layout(0);
         });
     });
+
+const SPARSE_GROUPS_SHADER: &str = "
+@group(0) @binding(0)
+var<storage, read_write> first: array<u32>;
+
+@group(2) @binding(0)
+var<storage, read_write> second: array<u32>;
+
+@compute @workgroup_size(1)
+fn main() {
+    second[0] = first[0];
+}
+";
+
+fn sparse_groups_pipeline(ctx: &wgpu_test::TestingContext) -> wgpu::ComputePipeline {
+    let module = ctx
+        .device
+        .create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: None,
+            source: wgpu::ShaderSource::Wgsl(SPARSE_GROUPS_SHADER.into()),
+        });
+
+    ctx.device
+        .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("sparse groups pipeline"),
+            layout: None,
+            module: &module,
+            entry_point: "main",
+        })
+}
+
+// A default layout has an empty group for every index the shader skips, which can be
+// fetched and bound like any other group.
+#[gpu_test]
+static PIPELINE_DEFAULT_LAYOUT_EMPTY_GROUP: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().downlevel_flags(wgpu::DownlevelFlags::COMPUTE_SHADERS))
+    .run_sync(|ctx| {
+        let pipeline = sparse_groups_pipeline(&ctx);
+        let buffers = [0, 1].map(|_| {
+            ctx.device.create_buffer(&wgpu::BufferDescriptor {
+                label: None,
+                size: 4,
+                usage: wgpu::BufferUsages::STORAGE,
+                mapped_at_creation: false,
+            })
+        });
+
+        let bind_groups = valid(&ctx.device, || {
+            [Some(&buffers[0]), None, Some(&buffers[1])]
+                .iter()
+                .enumerate()
+                .map(|(index, buffer)| {
+                    let entries: Vec<_> = buffer
+                        .iter()
+                        .map(|buffer| wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: buffer.as_entire_binding(),
+                        })
+                        .collect();
+                    ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                        label: None,
+                        layout: &pipeline.get_bind_group_layout(index as u32),
+                        entries: &entries,
+                    })
+                })
+                .collect::<Vec<_>>()
+        });
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        valid(&ctx.device, || {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(&pipeline);
+            for (index, bind_group) in bind_groups.iter().enumerate() {
+                pass.set_bind_group(index as u32, bind_group, &[]);
+            }
+            pass.dispatch_workgroups(1, 1, 1);
+        });
+        ctx.queue.submit(Some(encoder.finish()));
+    });
+
+// Asking for a group past the last one the shader uses is an error.
+#[gpu_test]
+static PIPELINE_DEFAULT_LAYOUT_GROUP_OUT_OF_RANGE: GpuTestConfiguration =
+    GpuTestConfiguration::new()
+        .parameters(
+            TestParameters::default().downlevel_flags(wgpu::DownlevelFlags::COMPUTE_SHADERS),
+        )
+        .run_sync(|ctx| {
+            let pipeline = sparse_groups_pipeline(&ctx);
+
+            fail(&ctx.device, || {
+                pipeline.get_bind_group_layout(3);
+            });
+        });
@@ -1,5 +1,6 @@
 //! Tests for buffer copy validation.
 
+use wgpu::util::DeviceExt;
 use wgpu_test::{fail, gpu_test, GpuTestConfiguration};
 
 #[gpu_test]
@@ -45,3 +46,70 @@ static QUEUE_WRITE_TEXTURE_OVERFLOW: GpuTestConfiguration =
             );
         });
     });
+
+#[gpu_test]
+static QUEUE_WRITE_BUFFER_WITH: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        let buffer = ctx
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: None,
+                contents: &[0xFF; 32],
+                usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            });
+
+        {
+            let mut view = ctx
+                .queue
+                .write_buffer_with(&buffer, 0, wgpu::BufferSize::new(8).unwrap())
+                .unwrap();
+            view.copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+        // A view that is dropped without being written to writes zeroes.
+        drop(
+            ctx.queue
+                .write_buffer_with(&buffer, 16, wgpu::BufferSize::new(8).unwrap())
+                .unwrap(),
+        );
+        ctx.queue.submit(None);
+
+        buffer
+            .slice(..)
+            .map_async(wgpu::MapMode::Read, Result::unwrap);
+        ctx.device.poll(wgpu::Maintain::Wait);
+        let data = buffer.slice(..).get_mapped_range();
+        assert_eq!(&data[0..8], &[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(&data[8..16], &[0xFF; 8]);
+        assert_eq!(&data[16..24], &[0; 8]);
+        assert_eq!(&data[24..32], &[0xFF; 8]);
+    });
+
+#[gpu_test]
+static QUEUE_WRITE_BUFFER_WITH_VALIDATION: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| {
+        let create_buffer = |usage| {
+            ctx.device.create_buffer(&wgpu::BufferDescriptor {
+                label: None,
+                size: 16,
+                usage,
+                mapped_at_creation: false,
+            })
+        };
+        let buffer = create_buffer(wgpu::BufferUsages::COPY_DST);
+        let no_copy_dst = create_buffer(wgpu::BufferUsages::COPY_SRC);
+
+        let invalid_writes = [
+            ("missing COPY_DST", &no_copy_dst, 0, 16),
+            ("unaligned offset", &buffer, 2, 8),
+            ("unaligned size", &buffer, 0, 6),
+            ("overrun", &buffer, 8, 12),
+        ];
+        for (case, buffer, offset, size) in invalid_writes {
+            log::info!("Testing write_buffer_with with {case}");
+            let view = fail(&ctx.device, || {
+                ctx.queue
+                    .write_buffer_with(buffer, offset, wgpu::BufferSize::new(size).unwrap())
+            });
+            assert!(view.is_none());
+        }
+    });
@@ -0,0 +1,212 @@
+//! Tests for the rasterization options on [`wgpu::PrimitiveState`] that sit behind features.
+
+use wgpu::util::DeviceExt;
+use wgpu_test::{
+    gpu_test, image::ReadbackBuffers, GpuTestConfiguration, TestParameters, TestingContext,
+};
+
+const SIZE: u32 = 16;
+
+const SHADER: &str = r#"
+@vertex
+fn vs_main(@location(0) position: vec4<f32>) -> @builtin(position) vec4<f32> {
+    return position;
+}
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    return vec4<f32>(1.0, 1.0, 1.0, 1.0);
+}
+"#;
+
+/// Draw the triangle list `vertices` (clip space `xyzw`) with `primitive` into a
+/// [`SIZE`]x[`SIZE`] target cleared to transparent black, and return whether each
+/// pixel was written, row by row.
+fn rasterize(
+    ctx: &TestingContext,
+    primitive: wgpu::PrimitiveState,
+    vertices: &[[f32; 4]],
+) -> Vec<bool> {
+    let shader = ctx
+        .device
+        .create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: None,
+            source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+        });
+    let pipeline = ctx
+        .device
+        .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: None,
+            layout: None,
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: 16,
+                    step_mode: wgpu::VertexStepMode::Vertex,
+                    attributes: &wgpu::vertex_attr_array![0 => Float32x4],
+                }],
+            },
+            primitive,
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::TextureFormat::Rgba8Unorm.into())],
+            }),
+            multiview: None,
+        });
+
+    let vertex_buffer = ctx
+        .device
+        .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("vertices"),
+            contents: bytemuck::cast_slice(vertices),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+
+    let target = ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("target"),
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let target_view = target.create_view(&wgpu::TextureViewDescriptor::default());
+
+    let readback = ReadbackBuffers::new(&ctx.device, &target);
+
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    {
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: None,
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &target_view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        pass.set_pipeline(&pipeline);
+        pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+        pass.draw(0..vertices.len() as u32, 0..1);
+    }
+    readback.copy_from(&ctx.device, &mut encoder, &target);
+    ctx.queue.submit(Some(encoder.finish()));
+
+    readback
+        .contents(&ctx.device)
+        .chunks_exact(4)
+        .map(|pixel| pixel != [0, 0, 0, 0])
+        .collect()
+}
+
+fn pixel(pixels: &[bool], x: u32, y: u32) -> bool {
+    pixels[(y * SIZE + x) as usize]
+}
+
+/// A triangle covering the whole target that lies entirely between the eye and the near plane.
+#[gpu_test]
+static UNCLIPPED_DEPTH: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().features(wgpu::Features::DEPTH_CLIP_CONTROL))
+    .run_sync(|ctx| {
+        let vertices = [
+            [-1.0, -1.0, -0.5, 1.0],
+            [3.0, -1.0, -0.5, 1.0],
+            [-1.0, 3.0, -0.5, 1.0],
+        ];
+
+        let clipped = rasterize(&ctx, wgpu::PrimitiveState::default(), &vertices);
+        assert!(
+            clipped.iter().all(|&written| !written),
+            "geometry before the near plane was not clipped"
+        );
+
+        let unclipped = rasterize(
+            &ctx,
+            wgpu::PrimitiveState {
+                unclipped_depth: true,
+                ..Default::default()
+            },
+            &vertices,
+        );
+        assert!(
+            pixel(&unclipped, SIZE / 2, SIZE / 2),
+            "geometry before the near plane was clipped with unclipped_depth"
+        );
+    });
+
+/// A sliver along the bottom edge of the target that doesn't cover a single pixel center.
+#[gpu_test]
+static CONSERVATIVE_RASTERIZATION: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().features(wgpu::Features::CONSERVATIVE_RASTERIZATION))
+    .run_sync(|ctx| {
+        let vertices = [
+            [-1.0, -1.0, 0.0, 1.0],
+            [1.0, -1.0, 0.0, 1.0],
+            [1.0, -0.95, 0.0, 1.0],
+        ];
+
+        let count = |pixels: Vec<bool>| pixels.into_iter().filter(|&written| written).count();
+        let regular = count(rasterize(&ctx, wgpu::PrimitiveState::default(), &vertices));
+        let conservative = count(rasterize(
+            &ctx,
+            wgpu::PrimitiveState {
+                conservative: true,
+                ..Default::default()
+            },
+            &vertices,
+        ));
+
+        assert!(
+            conservative > regular,
+            "conservative rasterization wrote {conservative} pixels, regular wrote {regular}"
+        );
+    });
+
+/// A triangle drawn as lines leaves its interior untouched.
+#[gpu_test]
+static POLYGON_MODE_LINE: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().features(wgpu::Features::POLYGON_MODE_LINE))
+    .run_sync(|ctx| {
+        let vertices = [
+            [-0.75, -0.75, 0.0, 1.0],
+            [0.75, -0.75, 0.0, 1.0],
+            [0.0, 0.75, 0.0, 1.0],
+        ];
+
+        let filled = rasterize(&ctx, wgpu::PrimitiveState::default(), &vertices);
+        assert!(pixel(&filled, SIZE / 2, SIZE / 2));
+
+        let lines = rasterize(
+            &ctx,
+            wgpu::PrimitiveState {
+                polygon_mode: wgpu::PolygonMode::Line,
+                ..Default::default()
+            },
+            &vertices,
+        );
+        assert!(
+            !pixel(&lines, SIZE / 2, SIZE / 2),
+            "the interior of the triangle was filled"
+        );
+        assert!(
+            lines.iter().any(|&written| written),
+            "the edges of the triangle were not drawn"
+        );
+    });
@@ -0,0 +1,186 @@
+//! Tests for read-only depth attachments, which are attached with `depth_ops: None`, and
+//! for discarding depth with [`wgpu::StoreOp::Discard`].
+
+use wgpu_test::{
+    fail, fullscreen_shader, gpu_test, image::ReadbackBuffers, valid, GpuTestConfiguration,
+    TestingContext,
+};
+
+const SIZE: u32 = 4;
+const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+const CLEAR_DEPTH: f32 = 0.25;
+
+const FRAGMENT_SHADER: &str = r#"
+@group(0) @binding(0)
+var depth: texture_depth_2d;
+
+@fragment
+fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let value = textureLoad(depth, vec2<i32>(position.xy), 0);
+    return vec4<f32>(value, 0.0, 0.0, 1.0);
+}
+"#;
+
+fn create_pipeline(ctx: &TestingContext, depth_write_enabled: bool) -> wgpu::RenderPipeline {
+    let shader = fullscreen_shader(&ctx.device, FRAGMENT_SHADER);
+
+    ctx.device
+        .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: None,
+            layout: None,
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: DEPTH_FORMAT,
+                depth_write_enabled,
+                depth_compare: wgpu::CompareFunction::Always,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::TextureFormat::Rgba8Unorm.into())],
+            }),
+            multiview: None,
+        })
+}
+
+fn create_texture(
+    ctx: &TestingContext,
+    format: wgpu::TextureFormat,
+    usage: wgpu::TextureUsages,
+) -> wgpu::Texture {
+    ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage,
+        view_formats: &[],
+    })
+}
+
+/// Begin a pass that attaches `depth_view` read-only.
+fn begin_read_only_pass<'a>(
+    encoder: &'a mut wgpu::CommandEncoder,
+    color_view: &'a wgpu::TextureView,
+    depth_view: &'a wgpu::TextureView,
+) -> wgpu::RenderPass<'a> {
+    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some("read-only depth pass"),
+        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+            view: color_view,
+            resolve_target: None,
+            ops: wgpu::Operations::default(),
+        })],
+        depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+            view: depth_view,
+            depth_ops: None,
+            stencil_ops: None,
+        }),
+        timestamp_writes: None,
+        occlusion_query_set: None,
+    })
+}
+
+#[gpu_test]
+static READ_ONLY_DEPTH_REJECTS_DEPTH_WRITES: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| {
+        let pipeline = create_pipeline(&ctx, true);
+        let color = create_texture(
+            &ctx,
+            wgpu::TextureFormat::Rgba8Unorm,
+            wgpu::TextureUsages::RENDER_ATTACHMENT,
+        );
+        let color_view = color.create_view(&wgpu::TextureViewDescriptor::default());
+        let depth = create_texture(&ctx, DEPTH_FORMAT, wgpu::TextureUsages::RENDER_ATTACHMENT);
+        let depth_view = depth.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        fail(&ctx.device, || {
+            let mut pass = begin_read_only_pass(&mut encoder, &color_view, &depth_view);
+            pass.set_pipeline(&pipeline);
+        });
+    });
+
+/// Clear the depth texture to [`CLEAR_DEPTH`] with `store`, then attach it read-only
+/// while sampling it, and check that every pixel saw `expected_depth`.
+fn sample_read_only_depth(ctx: &TestingContext, store: wgpu::StoreOp, expected_depth: f32) {
+    let pipeline = create_pipeline(ctx, false);
+    let color = create_texture(
+        ctx,
+        wgpu::TextureFormat::Rgba8Unorm,
+        wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+    );
+    let color_view = color.create_view(&wgpu::TextureViewDescriptor::default());
+    let depth = create_texture(
+        ctx,
+        DEPTH_FORMAT,
+        wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+    );
+    let depth_view = depth.create_view(&wgpu::TextureViewDescriptor::default());
+
+    let bind_group = ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: None,
+        layout: &pipeline.get_bind_group_layout(0),
+        entries: &[wgpu::BindGroupEntry {
+            binding: 0,
+            resource: wgpu::BindingResource::TextureView(&depth_view),
+        }],
+    });
+    let readback_buffers = ReadbackBuffers::new(&ctx.device, &color);
+
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some("depth clear pass"),
+        color_attachments: &[],
+        depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+            view: &depth_view,
+            depth_ops: Some(wgpu::Operations {
+                load: wgpu::LoadOp::Clear(CLEAR_DEPTH),
+                store,
+            }),
+            stencil_ops: None,
+        }),
+        timestamp_writes: None,
+        occlusion_query_set: None,
+    });
+    // The depth texture is attached read-only and sampled by the same draw.
+    valid(&ctx.device, || {
+        let mut pass = begin_read_only_pass(&mut encoder, &color_view, &depth_view);
+        pass.set_pipeline(&pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.draw(0..3, 0..1);
+    });
+    readback_buffers.copy_from(&ctx.device, &mut encoder, &color);
+    ctx.queue.submit(Some(encoder.finish()));
+
+    let red = (expected_depth * 255.0).round() as u8;
+    let expected = [red, 0, 0, 255].repeat((SIZE * SIZE) as usize);
+    readback_buffers.assert_buffer_contents(&ctx.device, &expected);
+}
+
+#[gpu_test]
+static READ_ONLY_DEPTH_SAMPLED_IN_PASS: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| sample_read_only_depth(&ctx, wgpu::StoreOp::Store, CLEAR_DEPTH));
+
+/// A discarded clear leaves nothing behind: the texture reads as zero afterwards.
+#[gpu_test]
+static DISCARDED_DEPTH_LOADS_AS_ZERO: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| sample_read_only_depth(&ctx, wgpu::StoreOp::Discard, 0.0));
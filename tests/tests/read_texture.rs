@@ -0,0 +1,142 @@
+//! Tests for [`wgpu::util::read_texture_to_rgba8`].
+#![cfg(not(target_arch = "wasm32"))]
+
+use wgpu_test::{gpu_test, GpuTestConfiguration, TestingContext};
+
+// Deliberately not a multiple of the row alignment, so rows need their padding stripped.
+const WIDTH: u32 = 3;
+const HEIGHT: u32 = 2;
+
+/// RGBA gradient, with every channel of every texel distinct.
+fn gradient() -> Vec<u8> {
+    (0..HEIGHT)
+        .flat_map(|y| (0..WIDTH).map(move |x| (x, y)))
+        .flat_map(|(x, y)| [x as u8 * 80, y as u8 * 120, 10 + x as u8, 255 - y as u8])
+        .collect()
+}
+
+fn create_texture(ctx: &TestingContext, format: wgpu::TextureFormat, data: &[u8]) -> wgpu::Texture {
+    let size = wgpu::Extent3d {
+        width: WIDTH,
+        height: HEIGHT,
+        depth_or_array_layers: 1,
+    };
+    let texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("source"),
+        size,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsages::COPY_SRC | wgpu::TextureUsages::COPY_DST,
+        view_formats: &[],
+    });
+    ctx.queue.write_texture(
+        texture.as_image_copy(),
+        data,
+        wgpu::ImageDataLayout {
+            offset: 0,
+            bytes_per_row: Some(WIDTH * 4),
+            rows_per_image: None,
+        },
+        size,
+    );
+    texture
+}
+
+#[gpu_test]
+static READ_TEXTURE_RGBA: GpuTestConfiguration = GpuTestConfiguration::new().run_sync(|ctx| {
+    let expected = gradient();
+    let texture = create_texture(&ctx, wgpu::TextureFormat::Rgba8Unorm, &expected);
+
+    let pixels = wgpu::util::read_texture_to_rgba8(&ctx.device, &ctx.queue, &texture, 0, 0)
+        .expect("Rgba8Unorm is supported");
+    assert_eq!(pixels, expected);
+});
+
+#[gpu_test]
+static READ_TEXTURE_BGRA_SRGB: GpuTestConfiguration = GpuTestConfiguration::new().run_sync(|ctx| {
+    let expected = gradient();
+    let mut bgra = expected.clone();
+    for texel in bgra.chunks_exact_mut(4) {
+        texel.swap(0, 2);
+    }
+    let texture = create_texture(&ctx, wgpu::TextureFormat::Bgra8UnormSrgb, &bgra);
+
+    let pixels = wgpu::util::read_texture_to_rgba8(&ctx.device, &ctx.queue, &texture, 0, 0)
+        .expect("Bgra8UnormSrgb is supported");
+    assert_eq!(pixels, expected);
+});
+
+#[gpu_test]
+static READ_TEXTURE_UNSUPPORTED_FORMAT: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| {
+        let texture = create_texture(
+            &ctx,
+            wgpu::TextureFormat::R32Float,
+            &vec![0; (WIDTH * HEIGHT * 4) as usize],
+        );
+
+        let result = wgpu::util::read_texture_to_rgba8(&ctx.device, &ctx.queue, &texture, 0, 0);
+        assert_eq!(
+            result,
+            Err(wgpu::util::ReadTextureError::UnsupportedFormat(
+                wgpu::TextureFormat::R32Float
+            ))
+        );
+    });
+
+#[gpu_test]
+static READ_TEXTURE_RENDERED: GpuTestConfiguration = GpuTestConfiguration::new().run_sync(|ctx| {
+    // 0.2 maps exactly onto 51 in a unorm channel.
+    let expected = [255, 51, 0, 255].repeat((WIDTH * HEIGHT) as usize);
+
+    for format in [
+        wgpu::TextureFormat::Rgba8Unorm,
+        wgpu::TextureFormat::Bgra8Unorm,
+    ] {
+        let texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("render target"),
+            size: wgpu::Extent3d {
+                width: WIDTH,
+                height: HEIGHT,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("clear"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color {
+                        r: 1.0,
+                        g: 0.2,
+                        b: 0.0,
+                        a: 1.0,
+                    }),
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        ctx.queue.submit(Some(encoder.finish()));
+
+        let pixels = wgpu::util::read_texture_to_rgba8(&ctx.device, &ctx.queue, &texture, 0, 0)
+            .expect("format is supported");
+        assert_eq!(pixels, expected, "{format:?}");
+    }
+});
@@ -0,0 +1,188 @@
+//! Tests for recording draws into a [`wgpu::RenderBundle`] and replaying them with
+//! [`wgpu::RenderPass::execute_bundles`].
+
+use wgpu_test::{
+    fail, fullscreen_shader, gpu_test, image::ReadbackBuffers, valid, GpuTestConfiguration,
+    TestingContext,
+};
+
+const WIDTH: u32 = 64;
+const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8Unorm;
+
+const FRAGMENT_SHADER: &str = r#"
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    return vec4<f32>(0.0, 1.0, 0.0, 1.0);
+}
+"#;
+
+fn create_pipeline(ctx: &TestingContext) -> wgpu::RenderPipeline {
+    let shader = fullscreen_shader(&ctx.device, FRAGMENT_SHADER);
+
+    ctx.device
+        .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: None,
+            layout: None,
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(FORMAT.into())],
+            }),
+            multiview: None,
+        })
+}
+
+fn create_bundle(
+    ctx: &TestingContext,
+    pipeline: &wgpu::RenderPipeline,
+    format: wgpu::TextureFormat,
+) -> wgpu::RenderBundle {
+    let mut encoder =
+        ctx.device
+            .create_render_bundle_encoder(&wgpu::RenderBundleEncoderDescriptor {
+                label: Some("bundle"),
+                color_formats: &[Some(format)],
+                depth_stencil: None,
+                sample_count: 1,
+                multiview: None,
+            });
+    encoder.set_pipeline(pipeline);
+    encoder.draw(0..3, 0..1);
+    encoder.finish(&wgpu::RenderBundleDescriptor {
+        label: Some("bundle"),
+    })
+}
+
+fn create_target(ctx: &TestingContext, format: wgpu::TextureFormat) -> wgpu::Texture {
+    ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("target"),
+        size: wgpu::Extent3d {
+            width: WIDTH,
+            height: WIDTH,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    })
+}
+
+/// Draw the triangle into a fresh texture, either directly or by executing `bundle`,
+/// and check that it covered the whole target.
+fn render_and_check(
+    ctx: &TestingContext,
+    pipeline: &wgpu::RenderPipeline,
+    bundle: Option<&wgpu::RenderBundle>,
+) {
+    let target = create_target(ctx, FORMAT);
+    let target_view = target.create_view(&wgpu::TextureViewDescriptor::default());
+    let readback = ReadbackBuffers::new(&ctx.device, &target);
+
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    {
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: None,
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &target_view,
+                resolve_target: None,
+                ops: wgpu::Operations::default(),
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        match bundle {
+            Some(bundle) => pass.execute_bundles([bundle]),
+            None => {
+                pass.set_pipeline(pipeline);
+                pass.draw(0..3, 0..1);
+            }
+        }
+    }
+    readback.copy_from(&ctx.device, &mut encoder, &target);
+    let command_buffer = valid(&ctx.device, || encoder.finish());
+    ctx.queue.submit(Some(command_buffer));
+
+    let expected = [0u8, 255, 0, 255].repeat((WIDTH * WIDTH) as usize);
+    readback.assert_buffer_contents(&ctx.device, &expected);
+}
+
+#[gpu_test]
+static RENDER_BUNDLE_MATCHES_DIRECT_DRAW: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| {
+        let pipeline = create_pipeline(&ctx);
+        let bundle = create_bundle(&ctx, &pipeline, FORMAT);
+
+        render_and_check(&ctx, &pipeline, None);
+        render_and_check(&ctx, &pipeline, Some(&bundle));
+    });
+
+#[gpu_test]
+static RENDER_BUNDLE_INCOMPATIBLE_FORMAT: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| {
+        let pipeline = create_pipeline(&ctx);
+        let bundle = create_bundle(&ctx, &pipeline, FORMAT);
+        let target = create_target(&ctx, wgpu::TextureFormat::Bgra8Unorm);
+        let target_view = target.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        fail(&ctx.device, || {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: None,
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &target_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations::default(),
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.execute_bundles([&bundle]);
+        });
+    });
+
+#[gpu_test]
+static RENDER_BUNDLE_RESETS_PASS_STATE: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| {
+        let pipeline = create_pipeline(&ctx);
+        let bundle = create_bundle(&ctx, &pipeline, FORMAT);
+        let target = create_target(&ctx, FORMAT);
+        let target_view = target.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        // The pipeline set before the bundle is gone after it, so the draw has no pipeline.
+        fail(&ctx.device, || {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: None,
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &target_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations::default(),
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_pipeline(&pipeline);
+            pass.execute_bundles([&bundle]);
+            pass.draw(0..3, 0..1);
+        });
+    });
@@ -6,36 +6,69 @@ mod regression {
 }
 
 mod bgra8unorm_storage;
+mod bind_group_compat;
 mod bind_group_layout_dedup;
+mod binding_arrays;
+mod bounds_checks;
 mod buffer;
+mod buffer_binding;
 mod buffer_copy;
+mod buffer_suballocation;
 mod buffer_usages;
 mod clear_texture;
+mod compilation_info;
 mod create_surface_error;
+mod debug_group;
+mod depth32float_stencil8;
+mod depth_only_pass;
 mod device;
+mod dispatch_indirect;
+mod download_buffer;
+mod draw_indirect;
+mod dual_source_blending;
 mod encoder;
 mod external_texture;
 mod float32_filterable;
+mod generate_mipmaps;
+mod glsl;
 mod instance;
 mod life_cycle;
+mod mappable_primary_buffers;
 mod mem_leaks;
+mod multi_draw_indirect;
+mod multiview;
 mod nv12_texture;
 mod occlusion_query;
+mod packed_float_formats;
 mod partially_bounded_arrays;
 mod pipeline;
 mod poll;
 mod push_constants;
 mod query_set;
 mod queue_transfer;
+mod rasterization;
+mod read_only_depth;
+mod read_texture;
+mod redundant_state;
+mod render_bundle;
 mod resource_descriptor_accessor;
 mod resource_error;
+mod sampler;
 mod scissor_tests;
 mod shader;
 mod shader_primitive_index;
 mod shader_view_format;
+mod spirv_passthrough;
+mod staging_belt;
+mod texture_blitter;
 mod texture_bounds;
+mod texture_compression_astc;
+mod texture_compression_bc;
+mod texture_compression_etc2;
+mod texture_data_order;
 mod transfer;
 mod vertex_indices;
+mod viewport;
 mod write_texture;
 mod zero_init_texture_after_discard;
 
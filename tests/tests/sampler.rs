@@ -0,0 +1,521 @@
+//! Tests for [`wgpu::SamplerDescriptor`] validation and border colors.
+
+use wgpu::util::DeviceExt;
+use wgpu_test::{
+    fail, gpu_test, read_buffer, valid, FailureCase, GpuTestConfiguration, TestParameters,
+    TestingContext, FULLSCREEN_TRIANGLE_WGSL,
+};
+
+const LINEAR: wgpu::FilterMode = wgpu::FilterMode::Linear;
+const NEAREST: wgpu::FilterMode = wgpu::FilterMode::Nearest;
+
+#[gpu_test]
+static SAMPLER_ANISOTROPY_VALIDATION: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        // (anisotropy_clamp, [mag, min, mipmap], expected error)
+        let cases = [
+            (1, [NEAREST, NEAREST, NEAREST], None),
+            (1, [LINEAR, NEAREST, LINEAR], None),
+            (2, [LINEAR, LINEAR, LINEAR], None),
+            (16, [LINEAR, LINEAR, LINEAR], None),
+            // Clamped to 16 rather than rejected.
+            (64, [LINEAR, LINEAR, LINEAR], None),
+            (0, [LINEAR, LINEAR, LINEAR], Some("Must be at least 1")),
+            (4, [NEAREST, LINEAR, LINEAR], Some("MagFilter")),
+            (4, [LINEAR, NEAREST, LINEAR], Some("MinFilter")),
+            (4, [LINEAR, LINEAR, NEAREST], Some("MipmapFilter")),
+        ];
+
+        for (anisotropy_clamp, [mag_filter, min_filter, mipmap_filter], expected) in cases {
+            ctx.device.push_error_scope(wgpu::ErrorFilter::Validation);
+            let _sampler = ctx.device.create_sampler(&wgpu::SamplerDescriptor {
+                label: Some("anisotropic sampler"),
+                mag_filter,
+                min_filter,
+                mipmap_filter,
+                anisotropy_clamp,
+                ..Default::default()
+            });
+            let error = pollster::block_on(ctx.device.pop_error_scope());
+
+            let filters = [mag_filter, min_filter, mipmap_filter];
+            let case = format!("anisotropy_clamp {anisotropy_clamp} with {filters:?}");
+            match (error, expected) {
+                (None, None) => {}
+                // WebGPU reports its own messages.
+                (Some(_), Some(_)) if ctx.adapter_info.backend == wgpu::Backend::BrowserWebGpu => {}
+                (Some(error), Some(expected)) => {
+                    let message = error.to_string();
+                    assert!(
+                        message.contains(expected),
+                        "{case}: error does not mention {expected:?}: {message}"
+                    );
+                    assert!(
+                        message.contains("anisotropic sampler"),
+                        "{case}: error does not name the sampler: {message}"
+                    );
+                }
+                (error, expected) => {
+                    panic!("{case}: expected error {expected:?}, got {error:?}")
+                }
+            }
+        }
+    });
+
+fn clamp_to_border(border_color: wgpu::SamplerBorderColor) -> wgpu::SamplerDescriptor<'static> {
+    wgpu::SamplerDescriptor {
+        label: Some("border sampler"),
+        address_mode_u: wgpu::AddressMode::ClampToBorder,
+        address_mode_v: wgpu::AddressMode::ClampToBorder,
+        border_color: Some(border_color),
+        ..Default::default()
+    }
+}
+
+#[gpu_test]
+static SAMPLER_CLAMP_TO_BORDER_REQUIRES_FEATURE: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(
+        TestParameters::default().skip(FailureCase::backend(wgpu::Backends::BROWSER_WEBGPU)),
+    )
+    .run_sync(|ctx| {
+        fail(&ctx.device, || {
+            ctx.device
+                .create_sampler(&clamp_to_border(wgpu::SamplerBorderColor::OpaqueWhite))
+        });
+    });
+
+#[gpu_test]
+static SAMPLER_CLAMP_TO_ZERO: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().features(wgpu::Features::ADDRESS_MODE_CLAMP_TO_ZERO))
+    .run_sync(|ctx| {
+        valid(&ctx.device, || {
+            ctx.device
+                .create_sampler(&clamp_to_border(wgpu::SamplerBorderColor::Zero))
+        });
+        // Other border colors still need `ADDRESS_MODE_CLAMP_TO_BORDER`.
+        fail(&ctx.device, || {
+            ctx.device
+                .create_sampler(&clamp_to_border(wgpu::SamplerBorderColor::OpaqueBlack))
+        });
+    });
+
+/// Sample a green 1x1 texture inside and outside of its bounds with `sampler`, returning both
+/// colors.
+fn sample_in_and_out_of_bounds(ctx: &TestingContext, sampler: &wgpu::Sampler) -> [[f32; 4]; 2] {
+    let texture = ctx.device.create_texture_with_data(
+        &ctx.queue,
+        &wgpu::TextureDescriptor {
+            label: None,
+            size: wgpu::Extent3d {
+                width: 1,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        },
+        wgpu::util::TextureDataOrder::LayerMajor,
+        &[0, 255, 0, 255],
+    );
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    let shader = ctx
+        .device
+        .create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: None,
+            source: wgpu::ShaderSource::Wgsl(
+                r#"
+                @group(0) @binding(0) var tex: texture_2d<f32>;
+                @group(0) @binding(1) var samp: sampler;
+                @group(0) @binding(2) var<storage, read_write> colors: array<vec4<f32>, 2>;
+
+                @compute @workgroup_size(1)
+                fn main() {
+                    colors[0] = textureSampleLevel(tex, samp, vec2<f32>(0.5, 0.5), 0.0);
+                    colors[1] = textureSampleLevel(tex, samp, vec2<f32>(4.0, 4.0), 0.0);
+                }
+                "#
+                .into(),
+            ),
+        });
+    let pipeline = ctx
+        .device
+        .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: None,
+            layout: None,
+            module: &shader,
+            entry_point: "main",
+        });
+
+    let storage = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("colors"),
+        size: std::mem::size_of::<[[f32; 4]; 2]>() as u64,
+        usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+        mapped_at_creation: false,
+    });
+    let bind_group = ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: None,
+        layout: &pipeline.get_bind_group_layout(0),
+        entries: &[
+            wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&view),
+            },
+            wgpu::BindGroupEntry {
+                binding: 1,
+                resource: wgpu::BindingResource::Sampler(sampler),
+            },
+            wgpu::BindGroupEntry {
+                binding: 2,
+                resource: storage.as_entire_binding(),
+            },
+        ],
+    });
+
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    {
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+        pass.set_pipeline(&pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.dispatch_workgroups(1, 1, 1);
+    }
+    ctx.queue.submit(Some(encoder.finish()));
+
+    bytemuck::pod_read_unaligned(&read_buffer(&ctx.device, &ctx.queue, &storage))
+}
+
+#[gpu_test]
+static SAMPLER_CLAMP_TO_BORDER: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(
+        TestParameters::default()
+            .features(wgpu::Features::ADDRESS_MODE_CLAMP_TO_BORDER)
+            .downlevel_flags(wgpu::DownlevelFlags::COMPUTE_SHADERS),
+    )
+    .run_sync(|ctx| {
+        let cases = [
+            (
+                wgpu::SamplerBorderColor::TransparentBlack,
+                [0.0, 0.0, 0.0, 0.0],
+            ),
+            (wgpu::SamplerBorderColor::OpaqueBlack, [0.0, 0.0, 0.0, 1.0]),
+            (wgpu::SamplerBorderColor::OpaqueWhite, [1.0, 1.0, 1.0, 1.0]),
+        ];
+
+        for (border_color, expected) in cases {
+            let sampler = ctx.device.create_sampler(&clamp_to_border(border_color));
+            let [inside, outside] = sample_in_and_out_of_bounds(&ctx, &sampler);
+            assert_eq!(inside, [0.0, 1.0, 0.0, 1.0], "{border_color:?}: texel");
+            assert_eq!(outside, expected, "{border_color:?}: border");
+        }
+    });
+
+fn sampler_layout(
+    ctx: &TestingContext,
+    sample_type: wgpu::TextureSampleType,
+    sampler_type: wgpu::SamplerBindingType,
+) -> wgpu::BindGroupLayout {
+    ctx.device
+        .create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: None,
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type,
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Sampler(sampler_type),
+                    count: None,
+                },
+            ],
+        })
+}
+
+#[gpu_test]
+static SAMPLER_COMPARISON_BINDING_MISMATCH: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| {
+        let texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+            label: None,
+            size: wgpu::Extent3d {
+                width: 1,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Depth32Float,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let comparison = ctx.device.create_sampler(&wgpu::SamplerDescriptor {
+            compare: Some(wgpu::CompareFunction::LessEqual),
+            ..Default::default()
+        });
+        let non_filtering = ctx
+            .device
+            .create_sampler(&wgpu::SamplerDescriptor::default());
+
+        let cases = [
+            (wgpu::SamplerBindingType::Comparison, &comparison, true),
+            (wgpu::SamplerBindingType::NonFiltering, &non_filtering, true),
+            (wgpu::SamplerBindingType::Comparison, &non_filtering, false),
+            (wgpu::SamplerBindingType::NonFiltering, &comparison, false),
+        ];
+
+        for (sampler_type, sampler, is_valid) in cases {
+            let layout = sampler_layout(&ctx, wgpu::TextureSampleType::Depth, sampler_type);
+            ctx.device.push_error_scope(wgpu::ErrorFilter::Validation);
+            ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: None,
+                layout: &layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(&view),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::Sampler(sampler),
+                    },
+                ],
+            });
+            let error = pollster::block_on(ctx.device.pop_error_scope());
+
+            match error {
+                None => assert!(
+                    is_valid,
+                    "{sampler_type:?}: binding a mismatched sampler succeeded"
+                ),
+                Some(error) => {
+                    assert!(!is_valid, "{sampler_type:?}: unexpected error: {error}");
+                    if ctx.adapter_info.backend != wgpu::Backend::BrowserWebGpu {
+                        let message = error.to_string();
+                        assert!(
+                            message.contains("Sampler binding 1"),
+                            "{sampler_type:?}: error does not name the binding: {message}"
+                        );
+                    }
+                }
+            }
+        }
+    });
+
+#[gpu_test]
+static DEPTH_TEXTURE_FILTERING_SAMPLER: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().downlevel_flags(wgpu::DownlevelFlags::COMPUTE_SHADERS))
+    .run_sync(|ctx| {
+        let shader = ctx
+            .device
+            .create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: None,
+                source: wgpu::ShaderSource::Wgsl(
+                    r#"
+                    @group(0) @binding(0) var depth: texture_depth_2d;
+                    @group(0) @binding(1) var samp: sampler;
+
+                    @compute @workgroup_size(1)
+                    fn main() {
+                        let _value = textureSampleLevel(depth, samp, vec2<f32>(0.5, 0.5), 0);
+                    }
+                    "#
+                    .into(),
+                ),
+            });
+
+        for sampler_type in [
+            wgpu::SamplerBindingType::NonFiltering,
+            wgpu::SamplerBindingType::Filtering,
+        ] {
+            let bind_group_layout =
+                sampler_layout(&ctx, wgpu::TextureSampleType::Depth, sampler_type);
+            let layout = ctx
+                .device
+                .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                    label: None,
+                    bind_group_layouts: &[&bind_group_layout],
+                    push_constant_ranges: &[],
+                });
+            let create_pipeline = || {
+                ctx.device
+                    .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                        label: None,
+                        layout: Some(&layout),
+                        module: &shader,
+                        entry_point: "main",
+                    })
+            };
+
+            if sampler_type == wgpu::SamplerBindingType::Filtering {
+                fail(&ctx.device, create_pipeline);
+            } else {
+                valid(&ctx.device, create_pipeline);
+            }
+        }
+    });
+
+/// Percentage closer filtering of a 2x1 depth texture whose left texel was covered by a
+/// draw at depth 0.0, and whose right texel kept its clear depth of 1.0.
+#[gpu_test]
+static SAMPLER_COMPARE_DEPTH: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().downlevel_flags(
+        wgpu::DownlevelFlags::COMPUTE_SHADERS | wgpu::DownlevelFlags::COMPARISON_SAMPLERS,
+    ))
+    .run_sync(|ctx| {
+        let compute_wgsl = r#"
+            @group(0) @binding(0) var depth: texture_depth_2d;
+            @group(0) @binding(1) var shadow: sampler_comparison;
+            @group(0) @binding(2) var<storage, read_write> results: array<f32, 3>;
+
+            @compute @workgroup_size(1)
+            fn cs_main() {
+                results[0] = textureSampleCompareLevel(depth, shadow, vec2<f32>(0.25, 0.5), 0.5);
+                results[1] = textureSampleCompareLevel(depth, shadow, vec2<f32>(0.75, 0.5), 0.5);
+                results[2] = textureSampleCompareLevel(depth, shadow, vec2<f32>(0.5, 0.5), 0.5);
+            }
+        "#;
+        let shader = ctx
+            .device
+            .create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: None,
+                source: wgpu::ShaderSource::Wgsl(
+                    format!("{FULLSCREEN_TRIANGLE_WGSL}{compute_wgsl}").into(),
+                ),
+            });
+
+        let depth_format = wgpu::TextureFormat::Depth32Float;
+        let depth = ctx.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("shadow map"),
+            size: wgpu::Extent3d {
+                width: 2,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: depth_format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let depth_view = depth.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let depth_pipeline = ctx
+            .device
+            .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("shadow pipeline"),
+                layout: None,
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                },
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: depth_format,
+                    depth_write_enabled: true,
+                    depth_compare: wgpu::CompareFunction::Always,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+                multisample: wgpu::MultisampleState::default(),
+                fragment: None,
+                multiview: None,
+            });
+        let compare_pipeline =
+            ctx.device
+                .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                    label: Some("compare pipeline"),
+                    layout: None,
+                    module: &shader,
+                    entry_point: "cs_main",
+                });
+
+        let sampler = ctx.device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("shadow sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            compare: Some(wgpu::CompareFunction::LessEqual),
+            ..Default::default()
+        });
+        let results = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("results"),
+            size: std::mem::size_of::<[f32; 3]>() as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let bind_group = ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &compare_pipeline.get_bind_group_layout(0),
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&depth_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: results.as_entire_binding(),
+                },
+            ],
+        });
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("shadow pass"),
+                color_attachments: &[],
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: &depth_view,
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(1.0),
+                        store: wgpu::StoreOp::Store,
+                    }),
+                    stencil_ops: None,
+                }),
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_pipeline(&depth_pipeline);
+            // Only the left texel is drawn to.
+            pass.set_scissor_rect(0, 0, 1, 1);
+            pass.draw(0..3, 0..1);
+        }
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(&compare_pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(1, 1, 1);
+        }
+        ctx.queue.submit(Some(encoder.finish()));
+
+        let [left, right, between]: [f32; 3] =
+            bytemuck::pod_read_unaligned(&read_buffer(&ctx.device, &ctx.queue, &results));
+
+        // A reference depth of 0.5 is behind the left texel and in front of the right one.
+        assert_eq!(left, 0.0, "left texel");
+        assert_eq!(right, 1.0, "right texel");
+        assert!(
+            (between - 0.5).abs() < 0.05,
+            "filtering between the texels gave {between}"
+        );
+    });
@@ -1,5 +1,7 @@
 use wgpu::{util::DeviceExt, DownlevelFlags, Limits, TextureFormat};
-use wgpu_test::{gpu_test, GpuTestConfiguration, TestParameters, TestingContext};
+use wgpu_test::{
+    fail, gpu_test, FailureCase, GpuTestConfiguration, TestParameters, TestingContext,
+};
 
 #[gpu_test]
 static REINTERPRET_SRGB: GpuTestConfiguration = GpuTestConfiguration::new()
@@ -55,6 +57,149 @@ static REINTERPRET_SRGB: GpuTestConfiguration = GpuTestConfiguration::new()
         );
     });
 
+#[gpu_test]
+static RENDER_TO_LINEAR_VIEW_OF_SRGB: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(
+        TestParameters::default()
+            .downlevel_flags(DownlevelFlags::VIEW_FORMATS)
+            .limits(Limits::downlevel_defaults()),
+    )
+    .run_sync(|ctx| {
+        let shader = ctx
+            .device
+            .create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: None,
+                source: wgpu::ShaderSource::Wgsl(
+                    r#"
+                    @vertex
+                    fn vs_main(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4<f32> {
+                        let x = f32(i32(vertex_index) / 2) * 4.0 - 1.0;
+                        let y = 1.0 - f32(i32(vertex_index) & 1) * 4.0;
+                        return vec4<f32>(x, y, 0.0, 1.0);
+                    }
+
+                    @fragment
+                    fn fs_main() -> @location(0) vec4<f32> {
+                        return vec4<f32>(0.5, 0.25, 0.0, 1.0);
+                    }
+                    "#
+                    .into(),
+                ),
+            });
+        let pipeline = ctx
+            .device
+            .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: None,
+                layout: None,
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                },
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_main",
+                    targets: &[Some(TextureFormat::Rgba8Unorm.into())],
+                }),
+                multiview: None,
+            });
+
+        let texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+            label: None,
+            size: wgpu::Extent3d {
+                width: 2,
+                height: 2,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[TextureFormat::Rgba8Unorm],
+        });
+        let linear_view = texture.create_view(&wgpu::TextureViewDescriptor {
+            format: Some(TextureFormat::Rgba8Unorm),
+            ..Default::default()
+        });
+        let readback = wgpu_test::image::ReadbackBuffers::new(&ctx.device, &texture);
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: None,
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    ops: wgpu::Operations::default(),
+                    resolve_target: None,
+                    view: &linear_view,
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            rpass.set_pipeline(&pipeline);
+            rpass.draw(0..3, 0..1);
+        }
+        readback.copy_from(&ctx.device, &mut encoder, &texture);
+        ctx.queue.submit(Some(encoder.finish()));
+
+        // Through an sRGB view these would have been gamma encoded to 188 and 137.
+        readback.assert_buffer_contents(&ctx.device, &[128, 64, 0, 255].repeat(4));
+    });
+
+#[gpu_test]
+static UNLISTED_VIEW_FORMAT: GpuTestConfiguration = GpuTestConfiguration::new()
+    // Browsers format their own error messages.
+    .parameters(
+        TestParameters::default()
+            .downlevel_flags(DownlevelFlags::VIEW_FORMATS)
+            .skip(FailureCase::backend(wgpu::Backends::BROWSER_WEBGPU)),
+    )
+    .run_sync(|ctx| {
+        let descriptor = wgpu::TextureDescriptor {
+            label: None,
+            size: wgpu::Extent3d {
+                width: 2,
+                height: 2,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        };
+
+        // Only the sRGB-ness can differ between the texture and its view formats.
+        fail(&ctx.device, || {
+            ctx.device.create_texture(&wgpu::TextureDescriptor {
+                view_formats: &[TextureFormat::Bgra8Unorm],
+                ..descriptor
+            })
+        });
+
+        // Without `view_formats`, the texture can only be viewed in its own format.
+        let texture = ctx.device.create_texture(&descriptor);
+        ctx.device.push_error_scope(wgpu::ErrorFilter::Validation);
+        texture.create_view(&wgpu::TextureViewDescriptor {
+            format: Some(TextureFormat::Rgba8Unorm),
+            ..Default::default()
+        });
+        let error = pollster::block_on(ctx.device.pop_error_scope())
+            .expect("viewing the texture in an unlisted format was accepted");
+        let message = error.to_string();
+        assert!(
+            message.contains("allowed view formats are [Rgba8UnormSrgb]"),
+            "the error does not list the allowed formats: {message}"
+        );
+    });
+
 fn reinterpret(
     ctx: &TestingContext,
     shader: &wgpu::ShaderModule,
@@ -0,0 +1,182 @@
+//! Tests for SPIR-V shader modules, both parsed by naga and passed through to the driver with
+//! [`wgpu::Features::SPIRV_SHADER_PASSTHROUGH`].
+
+use std::borrow::Cow;
+
+use wgpu_test::{
+    fail, gpu_test, read_buffer, GpuTestConfiguration, TestParameters, TestingContext,
+};
+
+const VALUE: u32 = 42;
+
+/// A SPIR-V 1.0 compute shader that writes [`VALUE`] to the `u32` at binding 0.
+#[rustfmt::skip]
+const SHADER: &[u32] = &[
+    // Header: magic, version 1.0, generator, id bound, schema
+    0x0723_0203, 0x0001_0000, 0, 13, 0,
+    // OpCapability Shader
+    0x0002_0011, 1,
+    // OpMemoryModel Logical GLSL450
+    0x0003_000E, 0, 1,
+    // OpEntryPoint GLCompute %1 "main"
+    0x0005_000F, 5, 1, 0x6E69_616D, 0,
+    // OpExecutionMode %1 LocalSize 1 1 1
+    0x0006_0010, 1, 17, 1, 1, 1,
+    // OpDecorate %5 BufferBlock
+    0x0003_0047, 5, 3,
+    // OpMemberDecorate %5 0 Offset 0
+    0x0005_0048, 5, 0, 35, 0,
+    // OpDecorate %8 DescriptorSet 0
+    0x0004_0047, 8, 34, 0,
+    // OpDecorate %8 Binding 0
+    0x0004_0047, 8, 33, 0,
+    // %2 = OpTypeVoid
+    0x0002_0013, 2,
+    // %3 = OpTypeFunction %2
+    0x0003_0021, 3, 2,
+    // %4 = OpTypeInt 32 0
+    0x0004_0015, 4, 32, 0,
+    // %5 = OpTypeStruct %4
+    0x0003_001E, 5, 4,
+    // %6 = OpTypePointer Uniform %5
+    0x0004_0020, 6, 2, 5,
+    // %7 = OpTypePointer Uniform %4
+    0x0004_0020, 7, 2, 4,
+    // %8 = OpVariable %6 Uniform
+    0x0004_003B, 6, 8, 2,
+    // %9 = OpConstant %4 0
+    0x0004_002B, 4, 9, 0,
+    // %10 = OpConstant %4 VALUE
+    0x0004_002B, 4, 10, VALUE,
+    // %1 = OpFunction %2 None %3
+    0x0005_0036, 2, 1, 0, 3,
+    // %11 = OpLabel
+    0x0002_00F8, 11,
+    // %12 = OpAccessChain %7 %8 %9
+    0x0005_0041, 7, 12, 8, 9,
+    // OpStore %12 %10
+    0x0003_003E, 12, 10,
+    // OpReturn
+    0x0001_00FD,
+    // OpFunctionEnd
+    0x0001_0038,
+];
+
+fn create_layout(ctx: &TestingContext) -> wgpu::PipelineLayout {
+    let bind_group_layout = ctx
+        .device
+        .create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: None,
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Storage { read_only: false },
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+    ctx.device
+        .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: None,
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        })
+}
+
+/// Dispatch the `main` entry point of `module` and return the value it wrote.
+fn dispatch(ctx: &TestingContext, module: &wgpu::ShaderModule) -> u32 {
+    let layout = create_layout(ctx);
+    let pipeline = ctx
+        .device
+        .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: None,
+            layout: Some(&layout),
+            module,
+            entry_point: "main",
+        });
+
+    let output = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("output"),
+        size: 4,
+        usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+        mapped_at_creation: false,
+    });
+    let bind_group = ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: None,
+        layout: &pipeline.get_bind_group_layout(0),
+        entries: &[wgpu::BindGroupEntry {
+            binding: 0,
+            resource: output.as_entire_binding(),
+        }],
+    });
+
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    {
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+        pass.set_pipeline(&pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.dispatch_workgroups(1, 1, 1);
+    }
+    ctx.queue.submit(Some(encoder.finish()));
+
+    bytemuck::pod_read_unaligned(&read_buffer(&ctx.device, &ctx.queue, &output))
+}
+
+#[gpu_test]
+static SPIRV_SHADER_PARSED: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().downlevel_flags(wgpu::DownlevelFlags::COMPUTE_SHADERS))
+    .run_sync(|ctx| {
+        let module = ctx
+            .device
+            .create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("parsed"),
+                source: wgpu::ShaderSource::SpirV(Cow::Borrowed(SHADER)),
+            });
+        assert_eq!(dispatch(&ctx, &module), VALUE);
+    });
+
+#[gpu_test]
+static SPIRV_SHADER_PASSTHROUGH: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(
+        TestParameters::default()
+            .features(wgpu::Features::SPIRV_SHADER_PASSTHROUGH)
+            .downlevel_flags(wgpu::DownlevelFlags::COMPUTE_SHADERS),
+    )
+    .run_sync(|ctx| {
+        let module = unsafe {
+            ctx.device
+                .create_shader_module_spirv(&wgpu::ShaderModuleDescriptorSpirV {
+                    label: Some("passthrough"),
+                    source: Cow::Borrowed(SHADER),
+                })
+        };
+        assert_eq!(dispatch(&ctx, &module), VALUE);
+
+        // Passthrough modules aren't reflected, so they need an explicit layout...
+        fail(&ctx.device, || {
+            ctx.device
+                .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                    label: None,
+                    layout: None,
+                    module: &module,
+                    entry_point: "main",
+                })
+        });
+
+        // ...but their entry points are still checked.
+        let layout = create_layout(&ctx);
+        fail(&ctx.device, || {
+            ctx.device
+                .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                    label: None,
+                    layout: Some(&layout),
+                    module: &module,
+                    entry_point: "missing",
+                })
+        });
+    });
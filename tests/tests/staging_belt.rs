@@ -0,0 +1,176 @@
+//! Tests for [`wgpu::util::StagingBelt`].
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::sync::Arc;
+
+use wgpu::util::StagingBelt;
+use wgpu_test::{gpu_test, GpuTestConfiguration, TestingContext};
+
+const CHUNK_SIZE: wgpu::BufferAddress = 256;
+const FRAMES: u8 = 16;
+
+fn create_target(device: &wgpu::Device) -> wgpu::Buffer {
+    device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("target"),
+        size: CHUNK_SIZE,
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::COPY_SRC,
+        mapped_at_creation: false,
+    })
+}
+
+/// Fill `target` with `value` through the belt and submit, without ever calling `recall`.
+fn write_frame(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    belt: &mut StagingBelt,
+    target: &wgpu::Buffer,
+    value: u8,
+) {
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    belt.write_buffer(
+        &mut encoder,
+        target,
+        0,
+        wgpu::BufferSize::new(CHUNK_SIZE).unwrap(),
+        device,
+    )
+    .fill(value);
+    belt.finish();
+    queue.submit(Some(encoder.finish()));
+}
+
+#[gpu_test]
+static STAGING_BELT_AUTO_RECALL: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        let TestingContext {
+            instance,
+            adapter_info,
+            device,
+            queue,
+            ..
+        } = ctx;
+        let queue = Arc::new(queue);
+
+        let target = create_target(&device);
+        let mut belt = StagingBelt::new_with_auto_recall(CHUNK_SIZE, &queue);
+
+        for frame in 0..FRAMES {
+            write_frame(&device, &queue, &mut belt, &target, frame);
+            device.poll(wgpu::Maintain::Wait);
+        }
+
+        // One chunk is being written, one is waiting for its work-done callback and
+        // one may still be getting mapped; anything beyond that is a leak.
+        let report = instance.generate_report();
+        let buffers = report
+            .hub_report(adapter_info.backend)
+            .buffers
+            .num_allocated;
+        assert!(
+            buffers <= 1 + 3,
+            "{buffers} buffers are alive after {FRAMES} frames"
+        );
+
+        // Reused chunks must still deliver the right data.
+        let readback = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("readback"),
+            size: CHUNK_SIZE,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        encoder.copy_buffer_to_buffer(&target, 0, &readback, 0, CHUNK_SIZE);
+        queue.submit(Some(encoder.finish()));
+
+        readback.slice(..).map_async(wgpu::MapMode::Read, |_| ());
+        device.poll(wgpu::Maintain::Wait);
+        let data = readback.slice(..).get_mapped_range();
+        assert!(data.iter().all(|&byte| byte == FRAMES - 1));
+        drop(data);
+
+        // Once the queue is gone, closed chunks are simply dropped.
+        write_frame(&device, &queue, &mut belt, &target, 0);
+        drop(queue);
+        belt.finish();
+    });
+
+#[gpu_test]
+static STAGING_BELT_AUTO_RECALL_DEVICE_DESTROYED: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        let queue = Arc::new(ctx.queue);
+
+        let target = create_target(&ctx.device);
+        let mut belt = StagingBelt::new_with_auto_recall(CHUNK_SIZE, &queue);
+
+        write_frame(&ctx.device, &queue, &mut belt, &target, 0);
+        write_frame(&ctx.device, &queue, &mut belt, &target, 1);
+
+        ctx.device.destroy();
+
+        // Hands the last chunk to `on_submitted_work_done`, whose callback then tries to
+        // map it on a lost device. That raises a validation error, but the belt itself
+        // must not panic.
+        ctx.device.push_error_scope(wgpu::ErrorFilter::Validation);
+        belt.finish();
+        ctx.device.poll(wgpu::Maintain::Wait);
+        let _ = pollster::block_on(ctx.device.pop_error_scope());
+        drop(belt);
+    });
+
+#[gpu_test]
+static STAGING_BELT_RECALL: GpuTestConfiguration = GpuTestConfiguration::new().run_sync(|ctx| {
+    // One write that fits in a chunk and one that needs a dedicated, larger chunk.
+    const SMALL: wgpu::BufferAddress = CHUNK_SIZE / 4;
+    const LARGE: wgpu::BufferAddress = CHUNK_SIZE * 2;
+
+    let target = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("target"),
+        size: SMALL + LARGE,
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+    let mut belt = StagingBelt::new(CHUNK_SIZE);
+    let buffer_count = || {
+        ctx.instance
+            .generate_report()
+            .hub_report(ctx.adapter_info.backend)
+            .buffers
+            .num_allocated
+    };
+
+    let mut counts = Vec::new();
+    for frame in 0..FRAMES {
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        // The large write goes first, so it can't lose the large chunk to the small one.
+        for (offset, size) in [(SMALL, LARGE), (0, SMALL)] {
+            belt.write_buffer(
+                &mut encoder,
+                &target,
+                offset,
+                wgpu::BufferSize::new(size).unwrap(),
+                &ctx.device,
+            )
+            .fill(frame);
+        }
+        belt.finish();
+        ctx.queue.submit(Some(encoder.finish()));
+        belt.recall();
+        ctx.device.poll(wgpu::Maintain::Wait);
+        counts.push(buffer_count());
+    }
+
+    // After the first frame, every write is served by a recalled chunk.
+    assert!(
+        counts.iter().all(|&count| count == counts[0]),
+        "buffer count didn't stabilize: {counts:?}"
+    );
+
+    target
+        .slice(..)
+        .map_async(wgpu::MapMode::Read, Result::unwrap);
+    ctx.device.poll(wgpu::Maintain::Wait);
+    let data = target.slice(..).get_mapped_range();
+    assert!(data.iter().all(|&byte| byte == FRAMES - 1));
+});
@@ -0,0 +1,166 @@
+//! Tests for [`wgpu::util::TextureBlitter`].
+
+use wgpu_test::{gpu_test, image::ReadbackBuffers, GpuTestConfiguration, TestingContext};
+
+const TARGET_SIZE: u32 = 256;
+
+/// Create a 2x2 source texture holding `texels`, in row major order.
+fn create_source(
+    ctx: &TestingContext,
+    format: wgpu::TextureFormat,
+    texels: &[u8],
+) -> wgpu::Texture {
+    let size = wgpu::Extent3d {
+        width: 2,
+        height: 2,
+        depth_or_array_layers: 1,
+    };
+    let texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("blit source"),
+        size,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        view_formats: &[],
+    });
+    ctx.queue.write_texture(
+        texture.as_image_copy(),
+        texels,
+        wgpu::ImageDataLayout {
+            offset: 0,
+            bytes_per_row: Some(2 * format.block_copy_size(None).unwrap()),
+            rows_per_image: None,
+        },
+        size,
+    );
+    texture
+}
+
+/// Blit `source` into a fresh `TARGET_SIZE` square target of `target_format` with
+/// `blitter`, and check that each quadrant of the target holds the matching texel of
+/// `expected_texels`.
+fn blit_and_check(
+    ctx: &TestingContext,
+    blitter: &wgpu::util::TextureBlitter,
+    source: &wgpu::Texture,
+    target_format: wgpu::TextureFormat,
+    expected_texels: [[u8; 4]; 4],
+) {
+    let target = ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("blit target"),
+        size: wgpu::Extent3d {
+            width: TARGET_SIZE,
+            height: TARGET_SIZE,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: target_format,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+
+    let readback = ReadbackBuffers::new(&ctx.device, &target);
+
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    blitter.copy_to_format(
+        &ctx.device,
+        &mut encoder,
+        &source.create_view(&wgpu::TextureViewDescriptor::default()),
+        &target.create_view(&wgpu::TextureViewDescriptor::default()),
+        target_format,
+    );
+    readback.copy_from(&ctx.device, &mut encoder, &target);
+    ctx.queue.submit(Some(encoder.finish()));
+
+    let half = TARGET_SIZE / 2;
+    let expected: Vec<u8> = (0..TARGET_SIZE)
+        .flat_map(|y| (0..TARGET_SIZE).map(move |x| (x, y)))
+        .flat_map(|(x, y)| expected_texels[(y / half * 2 + x / half) as usize])
+        .collect();
+    readback.assert_buffer_contents(&ctx.device, &expected);
+}
+
+#[gpu_test]
+static BLIT_NEAREST_UPSCALE: GpuTestConfiguration = GpuTestConfiguration::new().run_sync(|ctx| {
+    let texels = [
+        [255, 0, 0, 255],
+        [0, 255, 0, 255],
+        [0, 0, 255, 255],
+        [255, 255, 255, 255],
+    ];
+    let source = create_source(&ctx, wgpu::TextureFormat::Rgba8Unorm, &texels.concat());
+    let blitter = wgpu::util::TextureBlitter::new(&ctx.device, wgpu::TextureFormat::Rgba8Unorm);
+
+    blit_and_check(
+        &ctx,
+        &blitter,
+        &source,
+        wgpu::TextureFormat::Rgba8Unorm,
+        texels,
+    );
+});
+
+#[gpu_test]
+static BLIT_TO_OTHER_FORMATS: GpuTestConfiguration = GpuTestConfiguration::new().run_sync(|ctx| {
+    let texels = [
+        [255, 0, 0, 255],
+        [0, 255, 0, 255],
+        [0, 0, 255, 255],
+        [255, 255, 255, 255],
+    ];
+    let source = create_source(&ctx, wgpu::TextureFormat::Rgba8Unorm, &texels.concat());
+    let blitter = wgpu::util::TextureBlitter::new(&ctx.device, wgpu::TextureFormat::Rgba8Unorm);
+
+    // The sRGB pipeline is created on the first blit and reused on the second, and the
+    // pipeline for the original format keeps working in between. 0 and 1 are encoded
+    // the same way in sRGB and linear formats.
+    for format in [
+        wgpu::TextureFormat::Rgba8UnormSrgb,
+        wgpu::TextureFormat::Rgba8Unorm,
+        wgpu::TextureFormat::Rgba8UnormSrgb,
+    ] {
+        blit_and_check(&ctx, &blitter, &source, format, texels);
+    }
+});
+
+#[gpu_test]
+static BLIT_FLOAT_TO_UNORM: GpuTestConfiguration = GpuTestConfiguration::new().run_sync(|ctx| {
+    // Half precision floats: 2.0, -1.0, 1.0 and 0.0.
+    const TWO: u16 = 0x4000;
+    const MINUS_ONE: u16 = 0xBC00;
+    const ONE: u16 = 0x3C00;
+    const ZERO: u16 = 0x0000;
+
+    let texels: [[u16; 4]; 4] = [
+        [TWO, ZERO, ZERO, ONE],
+        [MINUS_ONE, ONE, ZERO, ONE],
+        [ZERO, ZERO, TWO, TWO],
+        [MINUS_ONE, MINUS_ONE, MINUS_ONE, ONE],
+    ];
+    let source = create_source(
+        &ctx,
+        wgpu::TextureFormat::Rgba16Float,
+        bytemuck::cast_slice(&texels),
+    );
+
+    // Everything out of range is clamped to 0..=1 when written to the unorm target.
+    let blitter = wgpu::util::TextureBlitter::new(&ctx.device, wgpu::TextureFormat::Rgba8Unorm);
+    blit_and_check(
+        &ctx,
+        &blitter,
+        &source,
+        wgpu::TextureFormat::Rgba8Unorm,
+        [
+            [255, 0, 0, 255],
+            [0, 255, 0, 255],
+            [0, 0, 255, 255],
+            [0, 0, 0, 255],
+        ],
+    );
+});
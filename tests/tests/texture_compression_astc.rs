@@ -0,0 +1,71 @@
+//! Tests for the ASTC block compressed formats, which need
+//! [`wgpu::Features::TEXTURE_COMPRESSION_ASTC`].
+
+use wgpu_test::{fail, gpu_test, valid, GpuTestConfiguration, TestParameters};
+
+// Blocks are 10x6 texels and 16 bytes, whatever their size.
+const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Astc {
+    block: wgpu::AstcBlock::B10x6,
+    channel: wgpu::AstcChannel::Unorm,
+};
+const BLOCK_BYTES: u32 = 16;
+
+#[gpu_test]
+static ASTC_NON_SQUARE_BLOCK_COPIES: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().features(wgpu::Features::TEXTURE_COMPRESSION_ASTC))
+    .run_sync(|ctx| {
+        assert_eq!(FORMAT.block_dimensions(), (10, 6));
+        assert_eq!(FORMAT.block_copy_size(None), Some(BLOCK_BYTES));
+
+        // Level 0 is 40x12 texels, or 4x2 blocks. Level 2 is 10x3, which rounds up to a
+        // single block.
+        let texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("astc"),
+            size: wgpu::Extent3d {
+                width: 40,
+                height: 12,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 3,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: FORMAT,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        let data = [0; (4 * 2 * BLOCK_BYTES) as usize];
+        let write = |mip_level, x, width, height| {
+            ctx.queue.write_texture(
+                wgpu::ImageCopyTexture {
+                    texture: &texture,
+                    mip_level,
+                    origin: wgpu::Origin3d { x, y: 0, z: 0 },
+                    aspect: wgpu::TextureAspect::All,
+                },
+                &data,
+                wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(4 * BLOCK_BYTES),
+                    rows_per_image: None,
+                },
+                wgpu::Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers: 1,
+                },
+            )
+        };
+
+        valid(&ctx.device, || write(0, 0, 40, 12));
+        valid(&ctx.device, || write(0, 20, 20, 6));
+        // Widths and origins are multiples of 10 texels, not of the 6 texel block height.
+        fail(&ctx.device, || write(0, 0, 12, 6));
+        fail(&ctx.device, || write(0, 0, 15, 6));
+        fail(&ctx.device, || write(0, 5, 10, 6));
+        fail(&ctx.device, || write(0, 0, 10, 4));
+
+        // At the edge of level 2, the copy covers the whole block past the 10x3 texels.
+        valid(&ctx.device, || write(2, 0, 10, 6));
+        fail(&ctx.device, || write(2, 0, 10, 3));
+        fail(&ctx.device, || write(2, 0, 5, 6));
+    });
@@ -0,0 +1,250 @@
+//! Tests for the BCn block compressed formats, which need
+//! [`wgpu::Features::TEXTURE_COMPRESSION_BC`].
+
+use wgpu_test::{
+    fail, gpu_test, image::ReadbackBuffers, valid, GpuTestConfiguration, TestParameters,
+    TestingContext,
+};
+
+const SIZE: u32 = 8;
+const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Bc1RgbaUnorm;
+const BLOCK_BYTES: u32 = 8;
+
+/// Encode a BC1 block from its two RGB565 endpoints and sixteen 2-bit indices, one per
+/// texel in row major order.
+fn bc1_block(color0: u16, color1: u16, indices: [u8; 16]) -> [u8; 8] {
+    let indices = indices
+        .iter()
+        .enumerate()
+        .fold(0u32, |bits, (i, &index)| bits | ((index as u32) << (i * 2)));
+    let mut block = [0; 8];
+    block[0..2].copy_from_slice(&color0.to_le_bytes());
+    block[2..4].copy_from_slice(&color1.to_le_bytes());
+    block[4..8].copy_from_slice(&indices.to_le_bytes());
+    block
+}
+
+/// Four blocks, in row major order: solid red, green and blue, then a block whose top row
+/// is white and the rest black. Only the endpoints are used, so the decoded colors are exact.
+fn blocks() -> Vec<u8> {
+    const RED: u16 = 0xF800;
+    const GREEN: u16 = 0x07E0;
+    const BLUE: u16 = 0x001F;
+    const WHITE: u16 = 0xFFFF;
+    const BLACK: u16 = 0x0000;
+
+    let mut striped = [1; 16];
+    striped[..4].fill(0);
+    [
+        bc1_block(RED, BLACK, [0; 16]),
+        bc1_block(GREEN, BLACK, [0; 16]),
+        bc1_block(BLUE, BLACK, [0; 16]),
+        bc1_block(WHITE, BLACK, striped),
+    ]
+    .concat()
+}
+
+fn expected_texel(x: u32, y: u32) -> [u8; 4] {
+    match (x / 4, y / 4) {
+        (0, 0) => [255, 0, 0, 255],
+        (1, 0) => [0, 255, 0, 255],
+        (0, 1) => [0, 0, 255, 255],
+        _ if y == 4 => [255, 255, 255, 255],
+        _ => [0, 0, 0, 255],
+    }
+}
+
+fn create_texture(ctx: &TestingContext, mip_level_count: u32) -> wgpu::Texture {
+    ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("bc1"),
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: FORMAT,
+        usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        view_formats: &[],
+    })
+}
+
+#[gpu_test]
+static BC1_UPLOAD_AND_SAMPLE: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().features(wgpu::Features::TEXTURE_COMPRESSION_BC))
+    .run_sync(|ctx| {
+        let texture = create_texture(&ctx, 1);
+        // Rows are counted in blocks: two rows of two blocks each.
+        ctx.queue.write_texture(
+            texture.as_image_copy(),
+            &blocks(),
+            wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(2 * BLOCK_BYTES),
+                rows_per_image: None,
+            },
+            texture.size(),
+        );
+
+        let shader = ctx
+            .device
+            .create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: None,
+                source: wgpu::ShaderSource::Wgsl(
+                    r#"
+                    @group(0) @binding(0)
+                    var source: texture_2d<f32>;
+
+                    @vertex
+                    fn vs_main(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4<f32> {
+                        let x = f32(i32(vertex_index) / 2) * 4.0 - 1.0;
+                        let y = 1.0 - f32(i32(vertex_index) & 1) * 4.0;
+                        return vec4<f32>(x, y, 0.0, 1.0);
+                    }
+
+                    @fragment
+                    fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+                        return textureLoad(source, vec2<i32>(position.xy), 0);
+                    }
+                    "#
+                    .into(),
+                ),
+            });
+        let pipeline = ctx
+            .device
+            .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: None,
+                layout: None,
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                },
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_main",
+                    targets: &[Some(wgpu::TextureFormat::Rgba8Unorm.into())],
+                }),
+                multiview: None,
+            });
+        let bind_group = ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &pipeline.get_bind_group_layout(0),
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(
+                    &texture.create_view(&wgpu::TextureViewDescriptor::default()),
+                ),
+            }],
+        });
+
+        let target = ctx.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("target"),
+            size: texture.size(),
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let target_view = target.create_view(&wgpu::TextureViewDescriptor::default());
+        let readback = ReadbackBuffers::new(&ctx.device, &target);
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: None,
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &target_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations::default(),
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_pipeline(&pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.draw(0..3, 0..1);
+        }
+        readback.copy_from(&ctx.device, &mut encoder, &target);
+        ctx.queue.submit(Some(encoder.finish()));
+
+        let expected: Vec<u8> = (0..SIZE)
+            .flat_map(|y| (0..SIZE).map(move |x| expected_texel(x, y)))
+            .flatten()
+            .collect();
+        readback.assert_buffer_contents(&ctx.device, &expected);
+    });
+
+#[gpu_test]
+static BC1_VALIDATION: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().features(wgpu::Features::TEXTURE_COMPRESSION_BC))
+    .run_sync(|ctx| {
+        // Compressed formats can't be rendered to or written as storage.
+        for usage in [
+            wgpu::TextureUsages::RENDER_ATTACHMENT,
+            wgpu::TextureUsages::STORAGE_BINDING,
+        ] {
+            fail(&ctx.device, || {
+                ctx.device.create_texture(&wgpu::TextureDescriptor {
+                    label: None,
+                    size: wgpu::Extent3d {
+                        width: SIZE,
+                        height: SIZE,
+                        depth_or_array_layers: 1,
+                    },
+                    mip_level_count: 1,
+                    sample_count: 1,
+                    dimension: wgpu::TextureDimension::D2,
+                    format: FORMAT,
+                    usage,
+                    view_formats: &[],
+                })
+            });
+        }
+
+        let texture = create_texture(&ctx, 3);
+        let block = [0; BLOCK_BYTES as usize];
+        let write = |mip_level, origin, width| {
+            ctx.queue.write_texture(
+                wgpu::ImageCopyTexture {
+                    texture: &texture,
+                    mip_level,
+                    origin,
+                    aspect: wgpu::TextureAspect::All,
+                },
+                &block,
+                wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: None,
+                    rows_per_image: None,
+                },
+                wgpu::Extent3d {
+                    width,
+                    height: width,
+                    depth_or_array_layers: 1,
+                },
+            )
+        };
+
+        valid(&ctx.device, || {
+            write(0, wgpu::Origin3d { x: 4, y: 4, z: 0 }, 4)
+        });
+        // Origins and extents are whole blocks.
+        fail(&ctx.device, || {
+            write(0, wgpu::Origin3d { x: 2, y: 0, z: 0 }, 4)
+        });
+        fail(&ctx.device, || write(0, wgpu::Origin3d::ZERO, 2));
+        // Level 2 is 2x2 texels, but is still copied as a whole 4x4 block.
+        valid(&ctx.device, || write(2, wgpu::Origin3d::ZERO, 4));
+        fail(&ctx.device, || write(2, wgpu::Origin3d::ZERO, 2));
+    });
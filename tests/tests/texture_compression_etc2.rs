@@ -0,0 +1,186 @@
+//! Tests for the ETC2 and EAC block compressed formats, which need
+//! [`wgpu::Features::TEXTURE_COMPRESSION_ETC2`].
+
+use wgpu::util::DeviceExt;
+use wgpu_test::{
+    fail, gpu_test, image::ReadbackBuffers, valid, GpuTestConfiguration, TestParameters,
+};
+
+const SIZE: u32 = 8;
+
+/// ETC1 compatible blocks in individual mode, with 4 bit base colors and the smallest
+/// modifier table (±2, ±8). Every texel of `WHITE` adds 2 to white and every texel of
+/// `BLACK` subtracts 2 from black, so both clamp to exact values.
+const WHITE: [u8; 8] = [0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00];
+const BLACK: [u8; 8] = [0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00];
+
+fn texture_descriptor(
+    format: wgpu::TextureFormat,
+    mip_level_count: u32,
+) -> wgpu::TextureDescriptor<'static> {
+    wgpu::TextureDescriptor {
+        label: Some("etc2"),
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        view_formats: &[],
+    }
+}
+
+#[gpu_test]
+static ETC2_COPY_VALIDATION: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().features(wgpu::Features::TEXTURE_COMPRESSION_ETC2))
+    .run_sync(|ctx| {
+        // Levels of 8x8, 4x4 and 2x2 texels, the last padded to a whole block.
+        let blocks = [WHITE; 6].concat();
+        let texture = valid(&ctx.device, || {
+            ctx.device.create_texture_with_data(
+                &ctx.queue,
+                &texture_descriptor(wgpu::TextureFormat::Etc2Rgb8Unorm, 3),
+                wgpu::util::TextureDataOrder::LayerMajor,
+                &blocks,
+            )
+        });
+
+        let write = |mip_level, x, width| {
+            ctx.queue.write_texture(
+                wgpu::ImageCopyTexture {
+                    texture: &texture,
+                    mip_level,
+                    origin: wgpu::Origin3d { x, y: 0, z: 0 },
+                    aspect: wgpu::TextureAspect::All,
+                },
+                &WHITE,
+                wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: None,
+                    rows_per_image: None,
+                },
+                wgpu::Extent3d {
+                    width,
+                    height: 4,
+                    depth_or_array_layers: 1,
+                },
+            )
+        };
+        valid(&ctx.device, || write(0, 4, 4));
+        fail(&ctx.device, || write(0, 2, 4));
+        fail(&ctx.device, || write(0, 0, 2));
+        valid(&ctx.device, || write(2, 0, 4));
+    });
+
+#[gpu_test]
+static ETC2_UPLOAD_AND_SAMPLE: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(TestParameters::default().features(wgpu::Features::TEXTURE_COMPRESSION_ETC2))
+    .run_sync(|ctx| {
+        // A 2x2 checkerboard of blocks.
+        let texture = ctx.device.create_texture_with_data(
+            &ctx.queue,
+            &texture_descriptor(wgpu::TextureFormat::Etc2Rgb8Unorm, 1),
+            wgpu::util::TextureDataOrder::LayerMajor,
+            &[WHITE, BLACK, BLACK, WHITE].concat(),
+        );
+
+        let shader = ctx
+            .device
+            .create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: None,
+                source: wgpu::ShaderSource::Wgsl(
+                    r#"
+                    @group(0) @binding(0)
+                    var source: texture_2d<f32>;
+
+                    @vertex
+                    fn vs_main(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4<f32> {
+                        let x = f32(i32(vertex_index) / 2) * 4.0 - 1.0;
+                        let y = 1.0 - f32(i32(vertex_index) & 1) * 4.0;
+                        return vec4<f32>(x, y, 0.0, 1.0);
+                    }
+
+                    @fragment
+                    fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+                        return textureLoad(source, vec2<i32>(position.xy), 0);
+                    }
+                    "#
+                    .into(),
+                ),
+            });
+        let pipeline = ctx
+            .device
+            .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: None,
+                layout: None,
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                },
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_main",
+                    targets: &[Some(wgpu::TextureFormat::Rgba8Unorm.into())],
+                }),
+                multiview: None,
+            });
+        let bind_group = ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &pipeline.get_bind_group_layout(0),
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(
+                    &texture.create_view(&wgpu::TextureViewDescriptor::default()),
+                ),
+            }],
+        });
+
+        let target = ctx.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("target"),
+            size: texture.size(),
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let target_view = target.create_view(&wgpu::TextureViewDescriptor::default());
+        let readback = ReadbackBuffers::new(&ctx.device, &target);
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: None,
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &target_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations::default(),
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_pipeline(&pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.draw(0..3, 0..1);
+        }
+        readback.copy_from(&ctx.device, &mut encoder, &target);
+        ctx.queue.submit(Some(encoder.finish()));
+
+        let expected: Vec<u8> = (0..SIZE)
+            .flat_map(|y| (0..SIZE).map(move |x| (x / 4 + y / 4) % 2 == 0))
+            .flat_map(|white| if white { [255; 4] } else { [0, 0, 0, 255] })
+            .collect();
+        readback.assert_buffer_contents(&ctx.device, &expected);
+    });
@@ -0,0 +1,175 @@
+//! Tests for [`wgpu::util::DeviceExt::create_texture_with_data`] and the different
+//! [`wgpu::util::TextureDataOrder`]s it accepts.
+
+use wgpu::util::{DeviceExt, TextureDataOrder};
+use wgpu_test::{gpu_test, GpuTestConfiguration, TestingContext};
+
+const SIZE: u32 = 4;
+const LAYERS: u32 = 2;
+const MIPS: u32 = 3;
+
+fn texture_descriptor() -> wgpu::TextureDescriptor<'static> {
+    wgpu::TextureDescriptor {
+        label: Some("texture with data"),
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: LAYERS,
+        },
+        mip_level_count: MIPS,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    }
+}
+
+/// Tightly packed contents of a single layer of a single mip.
+fn subresource_data(layer: u32, mip: u32) -> Vec<u8> {
+    let mip_size = SIZE >> mip;
+    (0..mip_size * mip_size * 4)
+        .map(|i| (layer * 100 + mip * 30 + i) as u8)
+        .collect()
+}
+
+fn texture_data(order: TextureDataOrder) -> Vec<u8> {
+    let mut data = Vec::new();
+    match order {
+        TextureDataOrder::LayerMajor => {
+            for layer in 0..LAYERS {
+                for mip in 0..MIPS {
+                    data.extend(subresource_data(layer, mip));
+                }
+            }
+        }
+        TextureDataOrder::MipMajor => {
+            for mip in 0..MIPS {
+                for layer in 0..LAYERS {
+                    data.extend(subresource_data(layer, mip));
+                }
+            }
+        }
+    }
+    data
+}
+
+/// Copy every subresource of `texture` out and return them in layer major order.
+fn read_texture(ctx: &TestingContext, texture: &wgpu::Texture) -> Vec<Vec<u8>> {
+    let row_pitch = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT as u64;
+    let subresource_size = row_pitch * SIZE as u64;
+
+    let readback = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("readback"),
+        size: subresource_size * (LAYERS * MIPS) as u64,
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    for layer in 0..LAYERS {
+        for mip in 0..MIPS {
+            let mip_size = SIZE >> mip;
+            encoder.copy_texture_to_buffer(
+                wgpu::ImageCopyTexture {
+                    texture,
+                    mip_level: mip,
+                    origin: wgpu::Origin3d {
+                        x: 0,
+                        y: 0,
+                        z: layer,
+                    },
+                    aspect: wgpu::TextureAspect::All,
+                },
+                wgpu::ImageCopyBuffer {
+                    buffer: &readback,
+                    layout: wgpu::ImageDataLayout {
+                        offset: subresource_size * (layer * MIPS + mip) as u64,
+                        bytes_per_row: Some(row_pitch as u32),
+                        rows_per_image: None,
+                    },
+                },
+                wgpu::Extent3d {
+                    width: mip_size,
+                    height: mip_size,
+                    depth_or_array_layers: 1,
+                },
+            );
+        }
+    }
+    ctx.queue.submit(Some(encoder.finish()));
+
+    readback
+        .slice(..)
+        .map_async(wgpu::MapMode::Read, Result::unwrap);
+    ctx.device.poll(wgpu::Maintain::Wait);
+    let mapped = readback.slice(..).get_mapped_range();
+
+    let mut result = Vec::new();
+    for layer in 0..LAYERS {
+        for mip in 0..MIPS {
+            let mip_size = SIZE >> mip;
+            let base = subresource_size * (layer * MIPS + mip) as u64;
+            let mut subresource = Vec::new();
+            for row in 0..mip_size as u64 {
+                let start = (base + row * row_pitch) as usize;
+                subresource.extend_from_slice(&mapped[start..start + mip_size as usize * 4]);
+            }
+            result.push(subresource);
+        }
+    }
+    result
+}
+
+#[gpu_test]
+static TEXTURE_DATA_ORDER: GpuTestConfiguration = GpuTestConfiguration::new().run_sync(|ctx| {
+    let mut expected = Vec::new();
+    for layer in 0..LAYERS {
+        for mip in 0..MIPS {
+            expected.push(subresource_data(layer, mip));
+        }
+    }
+
+    for order in [TextureDataOrder::LayerMajor, TextureDataOrder::MipMajor] {
+        let texture = ctx.device.create_texture_with_data(
+            &ctx.queue,
+            &texture_descriptor(),
+            order,
+            &texture_data(order),
+        );
+
+        assert_eq!(read_texture(&ctx, &texture), expected, "{order:?}");
+    }
+});
+
+#[gpu_test]
+static TEXTURE_DATA_WRONG_SIZE: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        let mut data = texture_data(TextureDataOrder::LayerMajor);
+        let expected_len = data.len();
+        data.pop();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            ctx.device.create_texture_with_data(
+                &ctx.queue,
+                &texture_descriptor(),
+                TextureDataOrder::LayerMajor,
+                &data,
+            )
+        }));
+
+        let payload = result.expect_err("mismatched data length must panic");
+        let message = payload
+            .downcast_ref::<String>()
+            .expect("panic payload should be a formatted message");
+        assert!(
+            message.contains(&format!("exactly {expected_len} bytes")),
+            "{message}"
+        );
+        assert!(
+            message.contains(&format!("{} bytes were provided", expected_len - 1)),
+            "{message}"
+        );
+    });
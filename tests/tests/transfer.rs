@@ -1,4 +1,4 @@
-use wgpu_test::{fail, gpu_test, GpuTestConfiguration};
+use wgpu_test::{fail, gpu_test, valid, GpuTestConfiguration, TestingContext};
 
 #[gpu_test]
 static COPY_OVERFLOW_Z: GpuTestConfiguration = GpuTestConfiguration::new().run_sync(|ctx| {
@@ -63,3 +63,174 @@ static COPY_OVERFLOW_Z: GpuTestConfiguration = GpuTestConfiguration::new().run_s
         ctx.queue.submit(Some(encoder.finish()));
     });
 });
+
+const MIPPED_SIZE: u32 = 16;
+
+/// A `MIPPED_SIZE` square R8Uint texture with three mip levels and three layers, where each
+/// texel of mip 0 of the first layer holds its index.
+fn create_mipped_texture(ctx: &TestingContext) -> wgpu::Texture {
+    let texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        dimension: wgpu::TextureDimension::D2,
+        size: wgpu::Extent3d {
+            width: MIPPED_SIZE,
+            height: MIPPED_SIZE,
+            depth_or_array_layers: 3,
+        },
+        format: wgpu::TextureFormat::R8Uint,
+        usage: wgpu::TextureUsages::COPY_SRC | wgpu::TextureUsages::COPY_DST,
+        mip_level_count: 3,
+        sample_count: 1,
+        view_formats: &[],
+    });
+    let data: Vec<u8> = (0..MIPPED_SIZE * MIPPED_SIZE).map(|i| i as u8).collect();
+    ctx.queue.write_texture(
+        texture.as_image_copy(),
+        &data,
+        wgpu::ImageDataLayout {
+            offset: 0,
+            bytes_per_row: Some(MIPPED_SIZE),
+            rows_per_image: None,
+        },
+        wgpu::Extent3d {
+            width: MIPPED_SIZE,
+            height: MIPPED_SIZE,
+            depth_or_array_layers: 1,
+        },
+    );
+    texture
+}
+
+fn copy_within(
+    encoder: &mut wgpu::CommandEncoder,
+    texture: &wgpu::Texture,
+    (src_mip, src_origin): (u32, wgpu::Origin3d),
+    (dst_mip, dst_origin): (u32, wgpu::Origin3d),
+    size: wgpu::Extent3d,
+) {
+    encoder.copy_texture_to_texture(
+        wgpu::ImageCopyTexture {
+            texture,
+            mip_level: src_mip,
+            origin: src_origin,
+            aspect: wgpu::TextureAspect::All,
+        },
+        wgpu::ImageCopyTexture {
+            texture,
+            mip_level: dst_mip,
+            origin: dst_origin,
+            aspect: wgpu::TextureAspect::All,
+        },
+        size,
+    );
+}
+
+#[gpu_test]
+static COPY_WITHIN_TEXTURE_BETWEEN_MIPS: GpuTestConfiguration = GpuTestConfiguration::new()
+    .run_sync(|ctx| {
+        let texture = create_mipped_texture(&ctx);
+        let size = MIPPED_SIZE >> 2;
+        let readback = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: (wgpu::COPY_BYTES_PER_ROW_ALIGNMENT * size) as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        // Copy the 4x4 block at (4, 4) of mip 0 into mip 2, which is 4x4 texels.
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        valid(&ctx.device, || {
+            copy_within(
+                &mut encoder,
+                &texture,
+                (0, wgpu::Origin3d { x: 4, y: 4, z: 0 }),
+                (2, wgpu::Origin3d::ZERO),
+                wgpu::Extent3d {
+                    width: size,
+                    height: size,
+                    depth_or_array_layers: 1,
+                },
+            )
+        });
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture: &texture,
+                mip_level: 2,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyBuffer {
+                buffer: &readback,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT),
+                    rows_per_image: None,
+                },
+            },
+            wgpu::Extent3d {
+                width: size,
+                height: size,
+                depth_or_array_layers: 1,
+            },
+        );
+        ctx.queue.submit(Some(encoder.finish()));
+
+        readback.slice(..).map_async(wgpu::MapMode::Read, |_| ());
+        ctx.device.poll(wgpu::Maintain::Wait);
+        let data = readback.slice(..).get_mapped_range();
+        for y in 0..size {
+            let row = &data[(y * wgpu::COPY_BYTES_PER_ROW_ALIGNMENT) as usize..][..size as usize];
+            let expected: Vec<u8> = (0..size)
+                .map(|x| ((y + 4) * MIPPED_SIZE + x + 4) as u8)
+                .collect();
+            assert_eq!(row, expected, "row {y} of mip 2");
+        }
+    });
+
+#[gpu_test]
+static COPY_WITHIN_TEXTURE_OVERLAP: GpuTestConfiguration =
+    GpuTestConfiguration::new().run_sync(|ctx| {
+        let texture = create_mipped_texture(&ctx);
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        let layer = |z| wgpu::Origin3d { x: 0, y: 0, z };
+        let size = |depth_or_array_layers| wgpu::Extent3d {
+            width: 4,
+            height: 4,
+            depth_or_array_layers,
+        };
+
+        // Different layers of the same mip don't overlap.
+        valid(&ctx.device, || {
+            copy_within(
+                &mut encoder,
+                &texture,
+                (0, layer(0)),
+                (0, layer(2)),
+                size(1),
+            )
+        });
+        // Layers 0..2 and 1..3 share layer 1.
+        fail(&ctx.device, || {
+            copy_within(
+                &mut encoder,
+                &texture,
+                (0, layer(0)),
+                (0, layer(1)),
+                size(2),
+            )
+        });
+        // Disjoint regions of the same subresource still count as overlapping.
+        fail(&ctx.device, || {
+            copy_within(
+                &mut encoder,
+                &texture,
+                (0, wgpu::Origin3d::ZERO),
+                (0, wgpu::Origin3d { x: 8, y: 8, z: 0 }),
+                size(1),
+            )
+        });
+    });
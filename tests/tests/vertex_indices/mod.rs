@@ -86,6 +86,8 @@ enum TestCase {
     DrawNonZeroFirstVertex,
     /// A single draw call with 6 vertices and a vertex offset of 3
     DrawBaseVertex,
+    /// A single draw call with indices 3..9 and a vertex offset of -3
+    DrawNegativeBaseVertex,
     /// A single draw call with 3 vertices and 2 instances
     DrawInstanced,
     /// Two draw calls with 3 vertices and 0..1 and 1..2 instances.
@@ -93,14 +95,22 @@ enum TestCase {
 }
 
 impl TestCase {
-    const ARRAY: [Self; 5] = [
+    const ARRAY: [Self; 6] = [
         Self::Draw,
         Self::DrawNonZeroFirstVertex,
         Self::DrawBaseVertex,
+        Self::DrawNegativeBaseVertex,
         Self::DrawInstanced,
         Self::DrawNonZeroFirstInstance,
     ];
 
+    /// Whether any draw call in this test case has a vertex offset
+    fn uses_base_vertex(&self) -> bool {
+        self.draws()
+            .iter()
+            .any(|draw| draw.base_vertex.is_some_and(|base_vertex| base_vertex != 0))
+    }
+
     // Get the draw calls for this test case
     fn draws(&self) -> &'static [Draw] {
         match self {
@@ -126,6 +136,11 @@ impl TestCase {
                 instance: 0..1,
                 base_vertex: Some(3),
             }],
+            TestCase::DrawNegativeBaseVertex => &[Draw {
+                vertex: 3..9,
+                instance: 0..1,
+                base_vertex: Some(-3),
+            }],
             TestCase::DrawInstanced => &[Draw {
                 vertex: 0..3,
                 instance: 0..2,
@@ -206,6 +221,13 @@ impl Test {
 
                 &[0, 0, 0, 3, 4, 5, 6, 7, 8]
             }
+            TestCase::DrawNegativeBaseVertex => {
+                if !first_vert_instance_supported {
+                    return &[0, 0, 0, 3, 4, 5, 6, 7, 8];
+                }
+
+                &[0, 1, 2, 3, 4, 5, 0, 0, 0]
+            }
             TestCase::Draw | TestCase::DrawInstanced => &[0, 1, 2, 3, 4, 5],
             TestCase::DrawNonZeroFirstVertex => {
                 if !first_vert_instance_supported {
@@ -321,8 +343,17 @@ fn vertex_index_common(ctx: TestingContext) {
         )
         .create_view(&wgpu::TextureViewDescriptor::default());
 
-    let mut tests = Vec::with_capacity(5 * 2 * 2);
+    // Draws with a vertex offset are a validation error without BASE_VERTEX.
+    let base_vertex_supported = ctx
+        .adapter_downlevel_capabilities
+        .flags
+        .contains(wgpu::DownlevelFlags::BASE_VERTEX);
+
+    let mut tests = Vec::with_capacity(TestCase::ARRAY.len() * 2 * 2);
     for case in TestCase::ARRAY {
+        if case.uses_base_vertex() && !base_vertex_supported {
+            continue;
+        }
         for id_source in IdSource::ARRAY {
             for draw_call_kind in DrawCallKind::ARRAY {
                 tests.push(Test {
@@ -0,0 +1,178 @@
+//! Tests for [`wgpu::RenderPass::set_viewport`] validation and depth range mapping.
+
+use wgpu_test::{fail, gpu_test, valid, GpuTestConfiguration, TestingContext};
+
+const SIZE: u32 = 4;
+const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+
+fn create_depth_texture(ctx: &TestingContext) -> wgpu::Texture {
+    ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("depth"),
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: DEPTH_FORMAT,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    })
+}
+
+fn begin_depth_pass<'a>(
+    encoder: &'a mut wgpu::CommandEncoder,
+    depth_view: &'a wgpu::TextureView,
+) -> wgpu::RenderPass<'a> {
+    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: None,
+        color_attachments: &[],
+        depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+            view: depth_view,
+            depth_ops: Some(wgpu::Operations {
+                load: wgpu::LoadOp::Clear(1.0),
+                store: wgpu::StoreOp::Store,
+            }),
+            stencil_ops: None,
+        }),
+        timestamp_writes: None,
+        occlusion_query_set: None,
+    })
+}
+
+#[gpu_test]
+static SET_VIEWPORT_INVALID: GpuTestConfiguration = GpuTestConfiguration::new().run_sync(|ctx| {
+    let depth = create_depth_texture(&ctx);
+    let depth_view = depth.create_view(&wgpu::TextureViewDescriptor::default());
+    let size = SIZE as f32;
+
+    let invalid_viewports = [
+        ("NaN origin", [f32::NAN, 0.0, size, size, 0.0, 1.0]),
+        ("NaN size", [0.0, 0.0, f32::NAN, size, 0.0, 1.0]),
+        ("negative origin", [-1.0, 0.0, size, size, 0.0, 1.0]),
+        ("zero width", [0.0, 0.0, 0.0, size, 0.0, 1.0]),
+        ("negative height", [0.0, 0.0, size, -1.0, 0.0, 1.0]),
+        ("outside the target", [1.0, 0.0, size, size, 0.0, 1.0]),
+        ("NaN depth", [0.0, 0.0, size, size, f32::NAN, 1.0]),
+        ("negative min depth", [0.0, 0.0, size, size, -0.5, 1.0]),
+        ("max depth above 1", [0.0, 0.0, size, size, 0.0, 1.5]),
+        ("reversed depth", [0.0, 0.0, size, size, 1.0, 0.0]),
+    ];
+
+    for (case, [x, y, w, h, min_depth, max_depth]) in invalid_viewports {
+        log::info!("Testing viewport with {case}");
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        fail(&ctx.device, || {
+            let mut pass = begin_depth_pass(&mut encoder, &depth_view);
+            pass.set_viewport(x, y, w, h, min_depth, max_depth);
+        });
+    }
+
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    valid(&ctx.device, || {
+        let mut pass = begin_depth_pass(&mut encoder, &depth_view);
+        pass.set_viewport(0.0, 0.0, size, size, 0.5, 0.5);
+    });
+});
+
+#[gpu_test]
+static SET_VIEWPORT_DEPTH_RANGE: GpuTestConfiguration = GpuTestConfiguration::new().run_sync(|ctx| {
+    // A fullscreen triangle at z = 0.25, which the viewport maps to 0.5.
+    let shader = ctx
+        .device
+        .create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: None,
+            source: wgpu::ShaderSource::Wgsl(
+                r#"
+                @vertex
+                fn vs_main(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4<f32> {
+                    let x = f32(i32(vertex_index) / 2) * 4.0 - 1.0;
+                    let y = 1.0 - f32(i32(vertex_index) & 1) * 4.0;
+                    return vec4<f32>(x, y, 0.25, 1.0);
+                }
+                "#
+                .into(),
+            ),
+        });
+    let pipeline = ctx
+        .device
+        .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: None,
+            layout: None,
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: DEPTH_FORMAT,
+                depth_write_enabled: true,
+                depth_compare: wgpu::CompareFunction::Always,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState::default(),
+            fragment: None,
+            multiview: None,
+        });
+
+    let depth = create_depth_texture(&ctx);
+    let depth_view = depth.create_view(&wgpu::TextureViewDescriptor::default());
+    let bytes_per_row = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+    let readback = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("readback"),
+        size: (bytes_per_row * SIZE) as u64,
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+
+    let mut encoder = ctx
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    {
+        let mut pass = begin_depth_pass(&mut encoder, &depth_view);
+        pass.set_pipeline(&pipeline);
+        pass.set_viewport(0.0, 0.0, SIZE as f32, SIZE as f32, 0.5, 0.5);
+        pass.draw(0..3, 0..1);
+    }
+    encoder.copy_texture_to_buffer(
+        wgpu::ImageCopyTexture {
+            texture: &depth,
+            mip_level: 0,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::DepthOnly,
+        },
+        wgpu::ImageCopyBuffer {
+            buffer: &readback,
+            layout: wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(bytes_per_row),
+                rows_per_image: None,
+            },
+        },
+        wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+    );
+    ctx.queue.submit(Some(encoder.finish()));
+
+    readback.slice(..).map_async(wgpu::MapMode::Read, |_| ());
+    ctx.device.poll(wgpu::Maintain::Wait);
+    let data = readback.slice(..).get_mapped_range();
+    for row in data.chunks_exact(bytes_per_row as usize) {
+        let depths: &[f32] = bytemuck::cast_slice(&row[..(SIZE * 4) as usize]);
+        assert!(
+            depths.iter().all(|&depth| depth == 0.5),
+            "depth was not mapped to 0.5: {depths:?}"
+        );
+    }
+});
@@ -39,6 +39,10 @@ pub enum BindGroupLayoutEntryError {
     StorageTextureReadWrite,
     #[error("Arrays of bindings unsupported for this type of binding")]
     ArrayUnsupported,
+    #[error(
+        "Binding array has {count} elements, but the `max_binding_array_elements` limit is {limit}"
+    )]
+    ArrayTooLarge { count: u32, limit: u32 },
     #[error("Multisampled binding with sample type `TextureSampleType::Float` must have filterable set to false.")]
     SampleTypeFloatFilterableBindingMultisampled,
     #[error(transparent)]
@@ -90,7 +94,8 @@ pub enum CreateBindGroupError {
     )]
     BindingArrayPartialLengthMismatch { actual: usize, expected: usize },
     #[error(
-        "Binding count declared with exactly {expected} items, but {actual} items were provided"
+        "Binding count declared with exactly {expected} items, but {actual} items were provided. \
+        Binding fewer items requires `Features::PARTIALLY_BOUND_BINDING_ARRAY`"
     )]
     BindingArrayLengthMismatch { actual: usize, expected: usize },
     #[error("Array binding provided zero elements")]
@@ -101,9 +106,10 @@ pub enum CreateBindGroupError {
         range: Range<wgt::BufferAddress>,
         size: u64,
     },
-    #[error("Buffer binding size {actual} is less than minimum {min}")]
+    #[error("Buffer binding {binding} has size {actual}, which is less than the minimum binding size {min} of its layout")]
     BindingSizeTooSmall {
         buffer: BufferId,
+        binding: u32,
         actual: u64,
         min: u64,
     },
@@ -4,12 +4,13 @@ use crate::{
     binding_model::{BindGroup, LateMinBufferBindingSizeMismatch, PipelineLayout},
     device::SHADER_STAGE_COUNT,
     hal_api::HalApi,
-    id::BindGroupId,
+    id::{BindGroupId, TypedId},
     pipeline::LateSizedBufferGroup,
     resource::Resource,
 };
 
 use arrayvec::ArrayVec;
+use bit_vec::BitVec;
 
 type BindGroupMask = u8;
 
@@ -209,6 +210,10 @@ mod compat {
             })
         }
 
+        pub fn is_assigned(&self, index: usize) -> bool {
+            self.entries[index].assigned.is_some()
+        }
+
         pub fn bgl_diff(&self) -> Vec<String> {
             for e in &self.entries {
                 if !e.is_valid() {
@@ -256,6 +261,40 @@ impl<A: HalApi> EntryPayload<A> {
     }
 }
 
+/// Bind groups whose usage and init actions are already part of a pass.
+///
+/// Setting the same bind group again doesn't change what the pass uses, so that
+/// only has to be merged the first time. Bind groups are tracked by id index: the
+/// registry guards are held until the pass is encoded, so an index can't be reused
+/// by another bind group meanwhile.
+#[derive(Debug)]
+pub(super) struct MergedBindGroups {
+    merged: BitVec,
+}
+
+impl MergedBindGroups {
+    pub(super) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            merged: BitVec::from_elem(capacity, false),
+        }
+    }
+
+    /// Mark `id` as merged, returning `true` if it wasn't already.
+    pub(super) fn insert(&mut self, id: BindGroupId) -> bool {
+        let (index, _, _) = id.unzip();
+        let index = index as usize;
+        if index >= self.merged.len() {
+            self.merged.grow(index + 1 - self.merged.len(), false);
+        }
+
+        if self.merged[index] {
+            return false;
+        }
+        self.merged.set(index, true);
+        true
+    }
+}
+
 #[derive(Debug, Default)]
 pub(super) struct Binder<A: HalApi> {
     pub(super) pipeline_layout: Option<Arc<PipelineLayout<A>>>,
@@ -366,10 +405,24 @@ impl<A: HalApi> Binder<A> {
             .map(move |index| payloads[index].group.as_ref().unwrap().as_info().id())
     }
 
+    pub(super) fn list_active_payloads(
+        &self,
+    ) -> impl Iterator<Item = (usize, &EntryPayload<A>)> + '_ {
+        let payloads = &self.payloads;
+        self.manager
+            .list_active()
+            .map(move |index| (index, &payloads[index]))
+    }
+
     pub(super) fn invalid_mask(&self) -> BindGroupMask {
         self.manager.invalid_mask()
     }
 
+    /// Whether a bind group has been set at `index`, compatible or not.
+    pub(super) fn is_group_set(&self, index: u32) -> bool {
+        self.manager.is_assigned(index as usize)
+    }
+
     pub(super) fn bgl_diff(&self) -> Vec<String> {
         self.manager.bgl_diff()
     }
@@ -449,3 +502,24 @@ pub fn compute_nonoverlapping_ranges(
 
     output_ranges
 }
+
+#[cfg(test)]
+mod tests {
+    use super::MergedBindGroups;
+    use crate::id::{BindGroupId, TypedId};
+
+    #[test]
+    fn merged_bind_groups() {
+        let first = BindGroupId::zip(0, 1, wgt::Backend::Empty);
+        let second = BindGroupId::zip(5, 1, wgt::Backend::Empty);
+
+        let mut merged = MergedBindGroups::with_capacity(2);
+        assert!(merged.insert(first));
+        assert!(!merged.insert(first));
+
+        // Indices past the initial capacity are tracked as well.
+        assert!(merged.insert(second));
+        assert!(!merged.insert(second));
+        assert!(!merged.insert(first));
+    }
+}
@@ -522,7 +522,7 @@ impl RenderBundleEncoder {
                     index_count,
                     instance_count,
                     first_index,
-                    base_vertex: _,
+                    base_vertex,
                     first_instance,
                 } => {
                     let scope = PassErrorScope::Draw {
@@ -530,6 +530,11 @@ impl RenderBundleEncoder {
                         indirect: false,
                         pipeline: state.pipeline_id(),
                     };
+                    if base_vertex != 0 {
+                        device
+                            .require_downlevel_flags(wgt::DownlevelFlags::BASE_VERTEX)
+                            .map_pass_err(scope)?;
+                    }
                     let pipeline = state.pipeline(scope)?;
                     let used_bind_groups = pipeline.used_bind_groups;
                     let index = match state.index {
@@ -631,7 +636,7 @@ impl RenderBundleEncoder {
 
                     buffer_memory_init_actions.extend(buffer.initialization_status.read().create_action(
                         buffer,
-                        offset..(offset + mem::size_of::<wgt::DrawIndirectArgs>() as u64),
+                        offset..(offset + mem::size_of::<wgt::DrawIndexedIndirectArgs>() as u64),
                         MemoryInitKind::NeedsInitializedMemory,
                     ));
 
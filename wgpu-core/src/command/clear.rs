@@ -122,20 +122,20 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
             if size % wgt::COPY_BUFFER_ALIGNMENT != 0 {
                 return Err(ClearError::UnalignedFillSize(size));
             }
-            let destination_end_offset = offset + size;
-            if destination_end_offset > dst_buffer.size {
-                return Err(ClearError::BufferOverrun {
-                    start_offset: offset,
-                    end_offset: destination_end_offset,
-                    buffer_size: dst_buffer.size,
-                });
-            }
         }
 
         let end = match size {
-            Some(size) => offset + size,
+            Some(size) => offset.saturating_add(size),
             None => dst_buffer.size,
         };
+        // With no size, an offset past the end of the buffer gives a reversed range.
+        if end > dst_buffer.size || offset > end {
+            return Err(ClearError::BufferOverrun {
+                start_offset: offset,
+                end_offset: end,
+                buffer_size: dst_buffer.size,
+            });
+        }
         if offset == end {
             log::trace!("Ignoring fill_buffer of size 0");
             return Ok(());
@@ -174,6 +174,10 @@ pub struct ComputePassDescriptor<'a> {
 pub enum DispatchError {
     #[error("Compute pipeline must be set")]
     MissingPipeline,
+    #[error(
+        "Bind group at index {index} is required by the current compute pipeline, but is not set"
+    )]
+    MissingBindGroup { index: u32 },
     #[error("Incompatible bind group at index {index} in the current compute pipeline")]
     IncompatibleBindGroup { index: u32, diff: Vec<String> },
     #[error(
@@ -203,6 +207,17 @@ pub enum ComputePassErrorInner {
     InvalidQuerySet(id::QuerySetId),
     #[error("Indirect buffer {0:?} is invalid or destroyed")]
     InvalidIndirectBuffer(id::BufferId),
+    #[error("Indirect dispatch offset {offset} into indirect buffer {buffer:?} of size {buffer_size} is not a multiple of 4")]
+    UnalignedIndirectBufferOffset {
+        buffer: id::BufferId,
+        offset: wgt::BufferAddress,
+        buffer_size: wgt::BufferAddress,
+    },
+    #[error("Indirect dispatch offset {offset} into indirect buffer {buffer:?} is too large for the dispatch size to be validated")]
+    UnvalidatableIndirectBufferOffset {
+        buffer: id::BufferId,
+        offset: wgt::BufferAddress,
+    },
     #[error("Indirect buffer uses bytes {offset}..{end_offset} which overruns indirect buffer of size {buffer_size}")]
     IndirectBufferOverrun {
         offset: u64,
@@ -217,6 +232,8 @@ pub enum ComputePassErrorInner {
     MissingBufferUsage(#[from] MissingBufferUsageError),
     #[error("Cannot pop debug group, because number of pushed debug groups is zero")]
     InvalidPopDebugGroup,
+    #[error("{0} debug groups were pushed, but not popped before the end of the pass")]
+    MissingPopDebugGroup(u32),
     #[error(transparent)]
     Dispatch(#[from] DispatchError),
     #[error(transparent)]
@@ -241,6 +258,10 @@ impl PrettyError for ComputePassErrorInner {
             Self::InvalidIndirectBuffer(id) => {
                 fmt.buffer_label(&id);
             }
+            Self::UnalignedIndirectBufferOffset { buffer, .. }
+            | Self::UnvalidatableIndirectBufferOffset { buffer, .. } => {
+                fmt.buffer_label_with_key(&buffer, "indirect buffer");
+            }
             Self::Dispatch(DispatchError::IncompatibleBindGroup { ref diff, .. }) => {
                 for d in diff {
                     fmt.note(&d);
@@ -285,6 +306,9 @@ struct State<A: HalApi> {
     pipeline: Option<id::ComputePipelineId>,
     scope: UsageScope<A>,
     debug_scope_depth: u32,
+    /// The push constants set for the current pipeline layout, so they can be
+    /// restored after clamping an indirect dispatch.
+    push_constants: Vec<u32>,
 }
 
 impl<A: HalApi> State<A> {
@@ -294,6 +318,9 @@ impl<A: HalApi> State<A> {
             //let (expected, provided) = self.binder.entries[index as usize].info();
             let index = bind_mask.trailing_zeros();
 
+            if !self.binder.is_group_set(index) {
+                return Err(DispatchError::MissingBindGroup { index });
+            }
             return Err(DispatchError::IncompatibleBindGroup {
                 index,
                 diff: self.binder.bgl_diff(),
@@ -418,6 +445,7 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
             pipeline: None,
             scope: UsageScope::new(&*buffer_guard, &*texture_guard),
             debug_scope_depth: 0,
+            push_constants: Vec::new(),
         };
         let mut temp_offsets = Vec::new();
         let mut dynamic_offset_count = 0;
@@ -614,6 +642,18 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                         }
 
                         // Clear push constant ranges
+                        let push_constants_end = pipeline
+                            .layout
+                            .push_constant_ranges
+                            .iter()
+                            .map(|range| range.range.end)
+                            .max()
+                            .unwrap_or(0);
+                        state.push_constants.clear();
+                        state.push_constants.resize(
+                            (push_constants_end / wgt::PUSH_CONSTANT_ALIGNMENT) as usize,
+                            0,
+                        );
                         let non_overlapping = super::bind::compute_nonoverlapping_ranges(
                             &pipeline.layout.push_constant_ranges,
                         );
@@ -666,6 +706,10 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                         )
                         .map_pass_err(scope)?;
 
+                    let values_start = (offset / wgt::PUSH_CONSTANT_ALIGNMENT) as usize;
+                    state.push_constants[values_start..values_start + data_slice.len()]
+                        .copy_from_slice(data_slice);
+
                     unsafe {
                         raw.set_push_constants(
                             pipeline_layout.raw(),
@@ -723,13 +767,58 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                         .require_downlevel_flags(wgt::DownlevelFlags::INDIRECT_EXECUTION)
                         .map_pass_err(scope)?;
 
+                    // With indirect validation the arguments are read by a
+                    // clamping dispatch instead of the indirect command itself.
+                    // Buffers created from hal buffers have no bind group to
+                    // read them with, and are dispatched from directly.
+                    let validation = match device.indirect_validation {
+                        Some(ref validation) => {
+                            let buffer = buffer_guard
+                                .get(buffer_id)
+                                .map_err(|_| {
+                                    ComputePassErrorInner::InvalidIndirectBuffer(buffer_id)
+                                })
+                                .map_pass_err(scope)?;
+                            let src_offsets = validation.src_offsets(buffer.size, offset);
+                            match (buffer.indirect_validation_bind_group.as_ref(), src_offsets) {
+                                (Some(bind_group), Some(src_offsets)) => {
+                                    Some((validation, bind_group, src_offsets))
+                                }
+                                (Some(_), None) => {
+                                    return Err(
+                                        ComputePassErrorInner::UnvalidatableIndirectBufferOffset {
+                                            buffer: buffer_id,
+                                            offset,
+                                        },
+                                    )
+                                    .map_pass_err(scope);
+                                }
+                                (None, _) => None,
+                            }
+                        }
+                        None => None,
+                    };
+                    let indirect_use = if validation.is_some() {
+                        hal::BufferUses::STORAGE_READ
+                    } else {
+                        hal::BufferUses::INDIRECT
+                    };
+
                     let indirect_buffer = state
                         .scope
                         .buffers
-                        .merge_single(&*buffer_guard, buffer_id, hal::BufferUses::INDIRECT)
+                        .merge_single(&*buffer_guard, buffer_id, indirect_use)
                         .map_pass_err(scope)?;
                     check_buffer_usage(indirect_buffer.usage, wgt::BufferUsages::INDIRECT)
                         .map_pass_err(scope)?;
+                    if offset % 4 != 0 {
+                        return Err(ComputePassErrorInner::UnalignedIndirectBufferOffset {
+                            buffer: buffer_id,
+                            offset,
+                            buffer_size: indirect_buffer.size,
+                        })
+                        .map_pass_err(scope);
+                    }
 
                     let end_offset = offset + mem::size_of::<wgt::DispatchIndirectArgs>() as u64;
                     if end_offset > indirect_buffer.size {
@@ -766,8 +855,57 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                             &snatch_guard,
                         )
                         .map_pass_err(scope)?;
+
+                    let Some((validation, bind_group, src_offsets)) = validation else {
+                        unsafe {
+                            raw.dispatch_indirect(buf_raw, offset);
+                        }
+                        continue;
+                    };
+
+                    unsafe {
+                        validation.encode(raw, bind_group, src_offsets);
+                    }
+
+                    // The clamping dispatch replaced the pipeline, bind groups
+                    // and push constants, so restore them before dispatching.
+                    let pipeline = &pipeline_guard[state.pipeline.unwrap()];
+                    let pipeline_layout = state.binder.pipeline_layout.as_ref().unwrap();
                     unsafe {
-                        raw.dispatch_indirect(buf_raw, offset);
+                        raw.set_compute_pipeline(pipeline.raw());
+                    }
+                    for (index, payload) in state.binder.list_active_payloads() {
+                        let raw_bg = payload
+                            .group
+                            .as_ref()
+                            .and_then(|group| group.raw(&snatch_guard))
+                            .ok_or(ComputePassErrorInner::InvalidBindGroup(index))
+                            .map_pass_err(scope)?;
+                        unsafe {
+                            raw.set_bind_group(
+                                pipeline_layout.raw(),
+                                index as u32,
+                                raw_bg,
+                                &payload.dynamic_offsets,
+                            );
+                        }
+                    }
+                    for range in super::bind::compute_nonoverlapping_ranges(
+                        &pipeline_layout.push_constant_ranges,
+                    ) {
+                        let start = (range.range.start / wgt::PUSH_CONSTANT_ALIGNMENT) as usize;
+                        let end = (range.range.end / wgt::PUSH_CONSTANT_ALIGNMENT) as usize;
+                        unsafe {
+                            raw.set_push_constants(
+                                pipeline_layout.raw(),
+                                wgt::ShaderStages::COMPUTE,
+                                range.range.start,
+                                &state.push_constants[start..end],
+                            );
+                        }
+                    }
+                    unsafe {
+                        raw.dispatch_indirect(validation.dst_buffer(), 0);
                     }
                 }
                 ComputeCommand::PushDebugGroup { color: _, len } => {
@@ -856,6 +994,13 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
             }
         }
 
+        if state.debug_scope_depth > 0 {
+            return Err(ComputePassErrorInner::MissingPopDebugGroup(
+                state.debug_scope_depth,
+            ))
+            .map_pass_err(pass_scope);
+        }
+
         unsafe {
             raw.end_compute_pass();
         }
@@ -25,6 +25,10 @@ pub enum DrawError {
     MissingVertexBuffer { index: u32 },
     #[error("Index buffer must be set")]
     MissingIndexBuffer,
+    #[error(
+        "Bind group at index {index} is required by the current render pipeline, but is not set"
+    )]
+    MissingBindGroup { index: u32 },
     #[error("Incompatible bind group at index {index} in the current render pipeline")]
     IncompatibleBindGroup { index: u32, diff: Vec<String> },
     #[error("Vertex {last_vertex} extends beyond limit {vertex_limit} imposed by the buffer in slot {slot}. Did you bind the correct `Vertex` step-rate vertex buffer?")]
@@ -87,9 +91,9 @@ pub enum RenderCommandError {
     MissingTextureUsage(#[from] MissingTextureUsageError),
     #[error(transparent)]
     PushConstants(#[from] PushConstantUploadError),
-    #[error("Viewport has invalid rect {0:?}; origin and/or size is less than or equal to 0, and/or is not contained in the render target {1:?}")]
+    #[error("Viewport has invalid rect {0:?}; origin and/or size is NaN or less than or equal to 0, and/or is not contained in the render target {1:?}")]
     InvalidViewportRect(Rect<f32>, wgt::Extent3d),
-    #[error("Viewport minDepth {0} and/or maxDepth {1} are not in [0, 1]")]
+    #[error("Viewport minDepth {0} and/or maxDepth {1} are not in [0, 1], or minDepth is greater than maxDepth")]
     InvalidViewportDepth(f32, f32),
     #[error("Scissor {0:?} is not contained in the render target {1:?}")]
     InvalidScissorRect(Rect<u32>, wgt::Extent3d),
@@ -121,6 +121,8 @@ pub struct CommandBufferMutable<A: HalApi> {
     buffer_memory_init_actions: Vec<BufferInitTrackerAction<A>>,
     texture_memory_actions: CommandBufferTextureMemoryActions<A>,
     pub(crate) pending_query_resets: QueryResetMap<A>,
+    /// Number of debug groups pushed on the encoder itself and not popped yet.
+    debug_scope_depth: u32,
     #[cfg(feature = "trace")]
     pub(crate) commands: Option<Vec<TraceCommand>>,
 }
@@ -190,6 +192,7 @@ impl<A: HalApi> CommandBuffer<A> {
                 buffer_memory_init_actions: Default::default(),
                 texture_memory_actions: Default::default(),
                 pending_query_resets: QueryResetMap::new(),
+                debug_scope_depth: 0,
                 #[cfg(feature = "trace")]
                 commands: if enable_tracing {
                     Some(Vec::new())
@@ -411,6 +414,10 @@ pub enum CommandEncoderError {
     Invalid,
     #[error("Command encoder must be active")]
     NotRecording,
+    #[error("Cannot pop debug group, because number of pushed debug groups is zero")]
+    InvalidPopDebugGroup,
+    #[error("{0} debug groups were pushed, but not popped before the encoder was finished")]
+    MissingPopDebugGroup(u32),
     #[error(transparent)]
     Device(#[from] DeviceError),
 }
@@ -430,6 +437,13 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                 let mut cmd_buf_data = cmd_buf.data.lock();
                 let cmd_buf_data = cmd_buf_data.as_mut().unwrap();
                 match cmd_buf_data.status {
+                    CommandEncoderStatus::Recording if cmd_buf_data.debug_scope_depth > 0 => {
+                        cmd_buf_data.status = CommandEncoderStatus::Error;
+                        cmd_buf_data.encoder.discard();
+                        Some(CommandEncoderError::MissingPopDebugGroup(
+                            cmd_buf_data.debug_scope_depth,
+                        ))
+                    }
                     CommandEncoderStatus::Recording => {
                         if let Err(e) = cmd_buf_data.encoder.close() {
                             Some(e.into())
@@ -472,6 +486,7 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
             list.push(TraceCommand::PushDebugGroup(label.to_string()));
         }
 
+        cmd_buf_data.debug_scope_depth += 1;
         let cmd_buf_raw = cmd_buf_data.encoder.open()?;
         if !self
             .instance
@@ -535,6 +550,10 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
             list.push(TraceCommand::PopDebugGroup);
         }
 
+        if cmd_buf_data.debug_scope_depth == 0 {
+            return Err(CommandEncoderError::InvalidPopDebugGroup);
+        }
+        cmd_buf_data.debug_scope_depth -= 1;
         let cmd_buf_raw = cmd_buf_data.encoder.open()?;
         if !self
             .instance
@@ -597,12 +616,15 @@ impl<T: Copy + PartialEq> Default for StateChange<T> {
 #[derive(Debug)]
 struct BindGroupStateChange {
     last_states: [StateChange<id::BindGroupId>; hal::MAX_BIND_GROUPS],
+    /// The dynamic offsets each bind group in `last_states` was set with.
+    last_dynamic_offsets: [Vec<wgt::DynamicOffset>; hal::MAX_BIND_GROUPS],
 }
 
 impl BindGroupStateChange {
     fn new() -> Self {
         Self {
             last_states: [StateChange::new(); hal::MAX_BIND_GROUPS],
+            last_dynamic_offsets: Default::default(),
         }
     }
 
@@ -614,26 +636,28 @@ impl BindGroupStateChange {
         offsets: *const wgt::DynamicOffset,
         offset_length: usize,
     ) -> bool {
-        // For now never deduplicate bind groups with dynamic offsets.
-        if offset_length == 0 {
-            // If this get returns None, that means we're well over the limit,
-            // so let the call through to get a proper error
-            if let Some(current_bind_group) = self.last_states.get_mut(index as usize) {
-                // Bail out if we're binding the same bind group.
-                if current_bind_group.set_and_check_redundant(bind_group_id) {
-                    return true;
-                }
-            }
+        let offsets = if offset_length == 0 {
+            &[]
         } else {
-            // We intentionally remove the memory of this bind group if we have dynamic offsets,
-            // such that if you try to bind this bind group later with _no_ dynamic offsets it
-            // tries to bind it again and gives a proper validation error.
-            if let Some(current_bind_group) = self.last_states.get_mut(index as usize) {
-                current_bind_group.reset();
+            unsafe { slice::from_raw_parts(offsets, offset_length) }
+        };
+
+        // If this get returns None, that means we're well over the limit,
+        // so let the call through to get a proper error
+        if let Some(current_bind_group) = self.last_states.get_mut(index as usize) {
+            let last_offsets = &mut self.last_dynamic_offsets[index as usize];
+            // Bail out if we're binding the same bind group with the same offsets.
+            // Offsets are compared by value, so binding the group again with
+            // different (or missing) offsets still goes through validation.
+            if current_bind_group.set_and_check_redundant(bind_group_id)
+                && last_offsets.as_slice() == offsets
+            {
+                return true;
             }
-            dynamic_offsets
-                .extend_from_slice(unsafe { slice::from_raw_parts(offsets, offset_length) });
+            last_offsets.clear();
+            last_offsets.extend_from_slice(offsets);
         }
+        dynamic_offsets.extend_from_slice(offsets);
         false
     }
     fn reset(&mut self) {
@@ -4,7 +4,7 @@ use crate::{
     binding_model::BindError,
     command::{
         self,
-        bind::Binder,
+        bind::{Binder, MergedBindGroups},
         end_occlusion_query, end_pipeline_statistics_query,
         memory_init::{fixup_discarded_surfaces, SurfacesInDiscardState},
         BasePass, BasePassRef, BindGroupStateChange, CommandBuffer, CommandEncoderError,
@@ -237,6 +237,12 @@ pub struct RenderPass {
     current_bind_groups: BindGroupStateChange,
     #[cfg_attr(feature = "serial-pass", serde(skip))]
     current_pipeline: StateChange<id::RenderPipelineId>,
+    #[cfg_attr(feature = "serial-pass", serde(skip))]
+    current_vertex_buffers:
+        [StateChange<(id::BufferId, BufferAddress, Option<BufferSize>)>; hal::MAX_VERTEX_BUFFERS],
+    #[cfg_attr(feature = "serial-pass", serde(skip))]
+    current_index_buffer:
+        StateChange<(id::BufferId, IndexFormat, BufferAddress, Option<BufferSize>)>,
 }
 
 impl RenderPass {
@@ -251,6 +257,8 @@ impl RenderPass {
 
             current_bind_groups: BindGroupStateChange::new(),
             current_pipeline: StateChange::new(),
+            current_vertex_buffers: Default::default(),
+            current_index_buffer: StateChange::new(),
         }
     }
 
@@ -276,6 +284,15 @@ impl RenderPass {
         offset: BufferAddress,
         size: Option<BufferSize>,
     ) {
+        if self.current_index_buffer.set_and_check_redundant((
+            buffer_id,
+            index_format,
+            offset,
+            size,
+        )) {
+            return;
+        }
+
         self.base.commands.push(RenderCommand::SetIndexBuffer {
             buffer_id,
             index_format,
@@ -439,8 +456,13 @@ impl<A: HalApi> State<A> {
         let bind_mask = self.binder.invalid_mask();
         if bind_mask != 0 {
             //let (expected, provided) = self.binder.entries[index as usize].info();
+            let index = bind_mask.trailing_zeros();
+
+            if !self.binder.is_group_set(index) {
+                return Err(DrawError::MissingBindGroup { index });
+            }
             return Err(DrawError::IncompatibleBindGroup {
-                index: bind_mask.trailing_zeros(),
+                index,
                 diff: self.binder.bgl_diff(),
             });
         }
@@ -588,14 +610,24 @@ pub enum RenderPassErrorInner {
     MissingFeatures(#[from] MissingFeatures),
     #[error(transparent)]
     MissingDownlevelFlags(#[from] MissingDownlevelFlags),
-    #[error("Indirect draw uses bytes {offset}..{end_offset} {} which overruns indirect buffer of size {buffer_size}",
+    #[error("Indirect draw uses bytes {offset}..{end_offset} {} which overruns indirect buffer {buffer:?} of size {buffer_size}; at most {max_count} draws fit",
         count.map_or_else(String::new, |v| format!("(using count {v})")))]
     IndirectBufferOverrun {
+        buffer: id::BufferId,
         count: Option<NonZeroU32>,
         offset: u64,
         end_offset: u64,
         buffer_size: u64,
+        max_count: u64,
     },
+    #[error("Indirect draw offset {offset} into indirect buffer {buffer:?} of size {buffer_size} is not a multiple of 4")]
+    UnalignedIndirectBufferOffset {
+        buffer: id::BufferId,
+        offset: BufferAddress,
+        buffer_size: BufferAddress,
+    },
+    #[error("Indirect draw count offset {0} is not a multiple of 4")]
+    UnalignedIndirectCountBufferOffset(BufferAddress),
     #[error("Indirect draw uses bytes {begin_count_offset}..{end_count_offset} which overruns indirect buffer of size {count_buffer_size}")]
     IndirectCountBufferOverrun {
         begin_count_offset: u64,
@@ -604,6 +636,8 @@ pub enum RenderPassErrorInner {
     },
     #[error("Cannot pop debug group, because number of pushed debug groups is zero")]
     InvalidPopDebugGroup,
+    #[error("{0} debug groups were pushed, but not popped before the end of the pass")]
+    MissingPopDebugGroup(u32),
     #[error(transparent)]
     ResourceUsageConflict(#[from] UsageConflict),
     #[error("Render bundle has incompatible targets, {0}")]
@@ -646,6 +680,11 @@ impl PrettyError for RenderPassErrorInner {
         if let Self::InvalidAttachment(id) = *self {
             fmt.texture_view_label_with_key(&id, "attachment");
         };
+        if let Self::IndirectBufferOverrun { buffer, .. }
+        | Self::UnalignedIndirectBufferOffset { buffer, .. } = *self
+        {
+            fmt.buffer_label_with_key(&buffer, "indirect buffer");
+        };
         if let Self::Draw(DrawError::IncompatibleBindGroup { diff, .. }) = self {
             for d in diff {
                 fmt.note(&d);
@@ -1413,6 +1452,7 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
             let mut dynamic_offset_count = 0;
             let mut string_offset = 0;
             let mut active_query = None;
+            let mut merged_bind_groups = MergedBindGroups::with_capacity(bind_group_guard.len());
 
             for command in base.commands {
                 match *command {
@@ -1454,27 +1494,31 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                             .validate_dynamic_bindings(index, &temp_offsets, &cmd_buf.limits)
                             .map_pass_err(scope)?;
 
-                        // merge the resource tracker in
-                        unsafe {
-                            info.usage_scope
-                                .merge_bind_group(&bind_group.used)
-                                .map_pass_err(scope)?;
-                        }
-                        //Note: stateless trackers are not merged: the lifetime reference
-                        // is held to the bind group itself.
+                        // Setting the same bind group again doesn't change what the pass
+                        // uses, so its usage and init actions are only merged once.
+                        if merged_bind_groups.insert(bind_group_id) {
+                            // merge the resource tracker in
+                            unsafe {
+                                info.usage_scope
+                                    .merge_bind_group(&bind_group.used)
+                                    .map_pass_err(scope)?;
+                            }
+                            //Note: stateless trackers are not merged: the lifetime reference
+                            // is held to the bind group itself.
 
-                        buffer_memory_init_actions.extend(
-                            bind_group.used_buffer_ranges.iter().filter_map(|action| {
-                                action
-                                    .buffer
-                                    .initialization_status
-                                    .read()
-                                    .check_action(action)
-                            }),
-                        );
-                        for action in bind_group.used_texture_ranges.iter() {
-                            info.pending_discard_init_fixups
-                                .extend(texture_memory_actions.register_init_action(action));
+                            buffer_memory_init_actions.extend(
+                                bind_group.used_buffer_ranges.iter().filter_map(|action| {
+                                    action
+                                        .buffer
+                                        .initialization_status
+                                        .read()
+                                        .check_action(action)
+                                }),
+                            );
+                            for action in bind_group.used_texture_ranges.iter() {
+                                info.pending_discard_init_fixups
+                                    .extend(texture_memory_actions.register_init_action(action));
+                            }
                         }
 
                         let pipeline_layout = state.binder.pipeline_layout.clone();
@@ -1784,7 +1828,8 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                         api_log!("RenderPass::set_viewport {rect:?}");
 
                         let scope = PassErrorScope::SetViewport;
-                        if rect.x < 0.0
+                        if [rect.x, rect.y, rect.w, rect.h].iter().any(|v| v.is_nan())
+                            || rect.x < 0.0
                             || rect.y < 0.0
                             || rect.w <= 0.0
                             || rect.h <= 0.0
@@ -1797,7 +1842,10 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                             ))
                             .map_pass_err(scope);
                         }
-                        if !(0.0..=1.0).contains(&depth_min) || !(0.0..=1.0).contains(&depth_max) {
+                        if !(0.0..=1.0).contains(&depth_min)
+                            || !(0.0..=1.0).contains(&depth_max)
+                            || depth_min > depth_max
+                        {
                             return Err(RenderCommandError::InvalidViewportDepth(
                                 depth_min, depth_max,
                             ))
@@ -1933,6 +1981,12 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                         };
                         state.is_ready(indexed).map_pass_err(scope)?;
 
+                        if base_vertex != 0 {
+                            device
+                                .require_downlevel_flags(wgt::DownlevelFlags::BASE_VERTEX)
+                                .map_pass_err(scope)?;
+                        }
+
                         //TODO: validate that base_vertex + max_index() is
                         // within the provided range
                         let last_index = first_index + index_count;
@@ -1985,11 +2039,6 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                             true => mem::size_of::<wgt::DrawIndexedIndirectArgs>(),
                         };
 
-                        if count.is_some() {
-                            device
-                                .require_features(wgt::Features::MULTI_DRAW_INDIRECT)
-                                .map_pass_err(scope)?;
-                        }
                         device
                             .require_downlevel_flags(wgt::DownlevelFlags::INDIRECT_EXECUTION)
                             .map_pass_err(scope)?;
@@ -2001,6 +2050,14 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                             .map_pass_err(scope)?;
                         check_buffer_usage(indirect_buffer.usage, BufferUsages::INDIRECT)
                             .map_pass_err(scope)?;
+                        if offset % 4 != 0 {
+                            return Err(RenderPassErrorInner::UnalignedIndirectBufferOffset {
+                                buffer: buffer_id,
+                                offset,
+                                buffer_size: indirect_buffer.size,
+                            })
+                            .map_pass_err(scope);
+                        }
                         let indirect_raw = indirect_buffer
                             .raw
                             .get(&snatch_guard)
@@ -2012,10 +2069,13 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                         let end_offset = offset + stride as u64 * actual_count as u64;
                         if end_offset > indirect_buffer.size {
                             return Err(RenderPassErrorInner::IndirectBufferOverrun {
+                                buffer: buffer_id,
                                 count,
                                 offset,
                                 end_offset,
                                 buffer_size: indirect_buffer.size,
+                                max_count: indirect_buffer.size.saturating_sub(offset)
+                                    / stride as u64,
                             })
                             .map_pass_err(scope);
                         }
@@ -2028,13 +2088,24 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                             ),
                         );
 
-                        match indexed {
-                            false => unsafe {
-                                raw.draw_indirect(indirect_raw, offset, actual_count);
-                            },
-                            true => unsafe {
-                                raw.draw_indexed_indirect(indirect_raw, offset, actual_count);
-                            },
+                        // Without native multi-draw support, issue one indirect draw
+                        // per record, stepping through the buffer by `stride`.
+                        let (calls, draws_per_call) =
+                            if device.features.contains(wgt::Features::MULTI_DRAW_INDIRECT) {
+                                (1, actual_count)
+                            } else {
+                                (actual_count, 1)
+                            };
+                        for call in 0..calls {
+                            let offset = offset + call as u64 * stride as u64;
+                            match indexed {
+                                false => unsafe {
+                                    raw.draw_indirect(indirect_raw, offset, draws_per_call);
+                                },
+                                true => unsafe {
+                                    raw.draw_indexed_indirect(indirect_raw, offset, draws_per_call);
+                                },
+                            }
                         }
                     }
                     RenderCommand::MultiDrawIndirectCount {
@@ -2073,6 +2144,14 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                             .map_pass_err(scope)?;
                         check_buffer_usage(indirect_buffer.usage, BufferUsages::INDIRECT)
                             .map_pass_err(scope)?;
+                        if offset % 4 != 0 {
+                            return Err(RenderPassErrorInner::UnalignedIndirectBufferOffset {
+                                buffer: buffer_id,
+                                offset,
+                                buffer_size: indirect_buffer.size,
+                            })
+                            .map_pass_err(scope);
+                        }
                         let indirect_raw = indirect_buffer
                             .raw
                             .get(&snatch_guard)
@@ -2090,6 +2169,12 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                             .map_pass_err(scope)?;
                         check_buffer_usage(count_buffer.usage, BufferUsages::INDIRECT)
                             .map_pass_err(scope)?;
+                        if count_buffer_offset % 4 != 0 {
+                            return Err(RenderPassErrorInner::UnalignedIndirectCountBufferOffset(
+                                count_buffer_offset,
+                            ))
+                            .map_pass_err(scope);
+                        }
                         let count_raw = count_buffer
                             .raw
                             .get(&snatch_guard)
@@ -2099,10 +2184,12 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                         let end_offset = offset + stride * max_count as u64;
                         if end_offset > indirect_buffer.size {
                             return Err(RenderPassErrorInner::IndirectBufferOverrun {
+                                buffer: buffer_id,
                                 count: None,
                                 offset,
                                 end_offset,
                                 buffer_size: indirect_buffer.size,
+                                max_count: indirect_buffer.size.saturating_sub(offset) / stride,
                             })
                             .map_pass_err(scope);
                         }
@@ -2362,6 +2449,13 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                 }
             }
 
+            if state.debug_scope_depth > 0 {
+                return Err(RenderPassErrorInner::MissingPopDebugGroup(
+                    state.debug_scope_depth,
+                ))
+                .map_pass_err(pass_scope);
+            }
+
             log::trace!("Merging renderpass into cmd_buf {:?}", encoder_id);
             let (trackers, pending_discard_init_fixups) =
                 info.finish(raw).map_pass_err(pass_scope)?;
@@ -2479,6 +2573,14 @@ pub mod render_ffi {
         offset: BufferAddress,
         size: Option<BufferSize>,
     ) {
+        // If this get returns None, the slot is over the limit, so let the
+        // call through to get a proper error.
+        if let Some(current_vertex_buffer) = pass.current_vertex_buffers.get_mut(slot as usize) {
+            if current_vertex_buffer.set_and_check_redundant((buffer_id, offset, size)) {
+                return;
+            }
+        }
+
         pass.base.commands.push(RenderCommand::SetVertexBuffer {
             slot,
             buffer_id,
@@ -2827,5 +2929,9 @@ pub mod render_ffi {
         }
         pass.current_pipeline.reset();
         pass.current_bind_groups.reset();
+        for current_vertex_buffer in pass.current_vertex_buffers.iter_mut() {
+            current_vertex_buffer.reset();
+        }
+        pass.current_index_buffer.reset();
     }
 }
@@ -48,7 +48,9 @@ pub enum TransferError {
     #[error("Texture {0:?} is invalid or destroyed")]
     InvalidTexture(TextureId),
     #[error("Source and destination cannot be the same buffer")]
-    SameSourceDestinationBuffer,
+    SameSourceDestinationBuffer(BufferId),
+    #[error("Source and destination subresources of texture {0:?} overlap")]
+    SameSourceDestinationTextureOverlap(TextureId),
     #[error("Source buffer/texture is missing the `COPY_SRC` usage flag")]
     MissingCopySrcUsageFlag,
     #[error("Destination buffer/texture is missing the `COPY_DST` usage flag")]
@@ -81,8 +83,20 @@ pub enum TransferError {
     InvalidDimensionExternal(TextureId),
     #[error("Buffer offset {0} is not aligned to block size or `COPY_BUFFER_ALIGNMENT`")]
     UnalignedBufferOffset(BufferAddress),
-    #[error("Copy size {0} does not respect `COPY_BUFFER_ALIGNMENT`")]
+    #[error(
+        "Copy size {0} is not a multiple of `COPY_BUFFER_ALIGNMENT` ({})",
+        wgt::COPY_BUFFER_ALIGNMENT
+    )]
     UnalignedCopySize(BufferAddress),
+    #[error(
+        "{side:?} buffer offset {offset} is not a multiple of `COPY_BUFFER_ALIGNMENT` ({})",
+        wgt::COPY_BUFFER_ALIGNMENT
+    )]
+    UnalignedCopyBufferOffset {
+        buffer: BufferId,
+        offset: BufferAddress,
+        side: CopySide,
+    },
     #[error("Copy width is not a multiple of block width")]
     UnalignedCopyWidth,
     #[error("Copy height is not a multiple of block height")]
@@ -154,6 +168,19 @@ impl PrettyError for TransferError {
             Self::InvalidTexture(id) => {
                 fmt.texture_label(&id);
             }
+            Self::SameSourceDestinationBuffer(id) => {
+                fmt.buffer_label(&id);
+            }
+            Self::SameSourceDestinationTextureOverlap(id) => {
+                fmt.texture_label(&id);
+            }
+            Self::UnalignedCopyBufferOffset { buffer, side, .. } => {
+                let key = match side {
+                    CopySide::Source => "source",
+                    CopySide::Destination => "destination",
+                };
+                fmt.buffer_label_with_key(&buffer, key);
+            }
             // Self::MissingCopySrcUsageFlag(buf_opt, tex_opt) => {
             //     if let Some(buf) = buf_opt {
             //         let name = crate::gfx_select!(buf => global.buffer_label(buf));
@@ -570,7 +597,7 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
         );
 
         if source == destination {
-            return Err(TransferError::SameSourceDestinationBuffer.into());
+            return Err(TransferError::SameSourceDestinationBuffer(source).into());
         }
         let hub = A::hub(self);
 
@@ -641,10 +668,20 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
             return Err(TransferError::UnalignedCopySize(size).into());
         }
         if source_offset % wgt::COPY_BUFFER_ALIGNMENT != 0 {
-            return Err(TransferError::UnalignedBufferOffset(source_offset).into());
+            return Err(TransferError::UnalignedCopyBufferOffset {
+                buffer: source,
+                offset: source_offset,
+                side: CopySide::Source,
+            }
+            .into());
         }
         if destination_offset % wgt::COPY_BUFFER_ALIGNMENT != 0 {
-            return Err(TransferError::UnalignedBufferOffset(destination_offset).into());
+            return Err(TransferError::UnalignedCopyBufferOffset {
+                buffer: destination,
+                offset: destination_offset,
+                side: CopySide::Destination,
+            }
+            .into());
         }
         if !device
             .downlevel
@@ -1133,6 +1170,17 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
         let (src_range, src_tex_base) = extract_texture_selector(source, copy_size, &src_texture)?;
         let (dst_range, dst_tex_base) =
             extract_texture_selector(destination, copy_size, &dst_texture)?;
+        // Copies within a texture are allowed between different mip levels or
+        // array layers. Volume slices aren't tracked separately, so copies
+        // within a mip level of a 3D texture always overlap.
+        if source.texture == destination.texture
+            && src_range.mips.start < dst_range.mips.end
+            && dst_range.mips.start < src_range.mips.end
+            && src_range.layers.start < dst_range.layers.end
+            && dst_range.layers.start < src_range.layers.end
+        {
+            return Err(TransferError::SameSourceDestinationTextureOverlap(source.texture).into());
+        }
         let src_texture_aspects = hal::FormatAspects::from(src_texture.desc.format);
         let dst_texture_aspects = hal::FormatAspects::from(dst_texture.desc.format);
         if src_tex_base.aspect != src_texture_aspects {
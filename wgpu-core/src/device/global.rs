@@ -492,7 +492,7 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
             .get(buffer_id)
             .map_err(|_| resource::DestroyError::Invalid)?;
 
-        let _ = buffer.unmap();
+        let _ = buffer.unmap_for_destroy();
 
         buffer.destroy()
     }
@@ -1189,6 +1189,30 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                     pipeline::ShaderModuleSource::Wgsl(ref code) => {
                         trace.make_binary("wgsl", code.as_bytes())
                     }
+                    // The trace has no room for the defines, so record the parsed module
+                    // when there is one. Source that fails to parse is recorded with its
+                    // stage in the file name, for the player to fail on it the same way.
+                    #[cfg(feature = "glsl")]
+                    pipeline::ShaderModuleSource::Glsl(ref code, ref options) => {
+                        match naga::front::glsl::Frontend::default().parse(options, code) {
+                            Ok(module) => {
+                                let string = ron::ser::to_string_pretty(
+                                    &module,
+                                    ron::ser::PrettyConfig::default(),
+                                )
+                                .unwrap();
+                                trace.make_binary("ron", string.as_bytes())
+                            }
+                            Err(_) => {
+                                let kind = match options.stage {
+                                    naga::ShaderStage::Vertex => "vert.glsl",
+                                    naga::ShaderStage::Fragment => "frag.glsl",
+                                    naga::ShaderStage::Compute => "comp.glsl",
+                                };
+                                trace.make_binary(kind, code.as_bytes())
+                            }
+                        }
+                    }
                     pipeline::ShaderModuleSource::Naga(ref module) => {
                         let string =
                             ron::ser::to_string_pretty(module, ron::ser::PrettyConfig::default())
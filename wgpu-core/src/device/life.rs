@@ -348,6 +348,7 @@ impl<A: HalApi> LifetimeTracker<A> {
     pub fn triage_submissions(
         &mut self,
         last_done: SubmissionIndex,
+        device: &A::Device,
         command_allocator: &mut super::CommandAllocator<A>,
     ) -> SmallVec<[SubmittedWorkDoneClosure; 1]> {
         profiling::scope!("triage_submissions");
@@ -366,7 +367,7 @@ impl<A: HalApi> LifetimeTracker<A> {
             self.ready_to_map.extend(a.mapped);
             for encoder in a.encoders {
                 let raw = unsafe { encoder.land() };
-                command_allocator.release_encoder(raw);
+                command_allocator.release_encoder(device, raw);
             }
             work_done_closures.extend(a.work_done_closures);
         }
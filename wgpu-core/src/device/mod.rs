@@ -398,6 +398,13 @@ fn map_buffer<A: HalApi>(
     Ok(mapping.ptr)
 }
 
+/// Maximum number of idle command encoders a [`CommandAllocator`] keeps for reuse.
+///
+/// Encoders released beyond this are destroyed, so a burst of command buffers
+/// doesn't keep their memory alive for the rest of the device's life. The
+/// pooled ones can be released with [`CommandAllocator::trim`].
+const MAX_FREE_COMMAND_ENCODERS: usize = 64;
+
 pub(crate) struct CommandAllocator<A: HalApi> {
     free_encoders: Vec<A::CommandEncoder>,
 }
@@ -417,8 +424,28 @@ impl<A: HalApi> CommandAllocator<A> {
         }
     }
 
-    fn release_encoder(&mut self, encoder: A::CommandEncoder) {
-        self.free_encoders.push(encoder);
+    fn release_encoder(&mut self, device: &A::Device, encoder: A::CommandEncoder) {
+        if self.free_encoders.len() < MAX_FREE_COMMAND_ENCODERS {
+            self.free_encoders.push(encoder);
+        } else {
+            unsafe {
+                device.destroy_command_encoder(encoder);
+            }
+        }
+    }
+
+    /// Destroy all idle encoders, leaving the pool empty.
+    fn trim(&mut self, device: &A::Device) {
+        resource_log!(
+            "CommandAllocator::trim encoders {}",
+            self.free_encoders.len()
+        );
+        for cmd_encoder in self.free_encoders.drain(..) {
+            unsafe {
+                device.destroy_command_encoder(cmd_encoder);
+            }
+        }
+        self.free_encoders.shrink_to_fit();
     }
 
     fn dispose(self, device: &A::Device) {
@@ -501,3 +528,66 @@ impl<G: GlobalIdentityHandlerFactory> ImplicitPipelineIds<'_, G> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandAllocator, MAX_FREE_COMMAND_ENCODERS};
+
+    #[test]
+    fn command_allocator_pool_is_bounded() {
+        let device = hal::empty::Context;
+        let mut allocator = CommandAllocator::<hal::api::Empty> {
+            free_encoders: Vec::new(),
+        };
+
+        // Release more encoders than the pool holds, as a large submission would
+        // once it completes.
+        for _ in 0..MAX_FREE_COMMAND_ENCODERS + 16 {
+            allocator.release_encoder(&device, hal::empty::Encoder);
+        }
+        assert_eq!(allocator.free_encoders.len(), MAX_FREE_COMMAND_ENCODERS);
+
+        // Pooled encoders are handed out again before new ones are created.
+        let queue = hal::empty::Context;
+        allocator.acquire_encoder(&device, &queue).unwrap();
+        assert_eq!(allocator.free_encoders.len(), MAX_FREE_COMMAND_ENCODERS - 1);
+    }
+
+    #[test]
+    fn command_allocator_trim_empties_pool() {
+        let device = hal::empty::Context;
+        let mut allocator = CommandAllocator::<hal::api::Empty> {
+            free_encoders: Vec::new(),
+        };
+
+        for _ in 0..MAX_FREE_COMMAND_ENCODERS {
+            allocator.release_encoder(&device, hal::empty::Encoder);
+        }
+        allocator.trim(&device);
+        assert!(allocator.free_encoders.is_empty());
+        assert_eq!(allocator.free_encoders.capacity(), 0);
+
+        // The pool keeps working after a trim.
+        allocator.release_encoder(&device, hal::empty::Encoder);
+        assert_eq!(allocator.free_encoders.len(), 1);
+    }
+
+    #[test]
+    fn hub_shrink_to_fit_trims_command_allocators() {
+        use crate::{hub::tests::empty_device, hub::Hub, identity::IdentityManagerFactory};
+
+        let hub = Hub::<hal::api::Empty>::new(&IdentityManagerFactory);
+        let (_adapter, device) = empty_device(&hub);
+        {
+            let mut allocator = device.command_allocator.lock();
+            let allocator = allocator.as_mut().unwrap();
+            for _ in 0..4 {
+                allocator.release_encoder(device.raw(), hal::empty::Encoder);
+            }
+        }
+
+        hub.shrink_to_fit();
+        let allocator = device.command_allocator.lock();
+        assert!(allocator.as_ref().unwrap().free_encoders.is_empty());
+    }
+}
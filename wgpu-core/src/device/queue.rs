@@ -417,6 +417,10 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
             return Ok(());
         }
 
+        // Backends can't allocate a staging buffer of an unaligned size, so
+        // validate the write before creating one.
+        self.queue_validate_write_buffer::<A>(queue_id, buffer_id, buffer_offset, data_size)?;
+
         // Platform validation requires that the staging buffer always be
         // freed, even if an error occurs. All paths from here must call
         // `device.pending_writes.consume`.
@@ -468,6 +472,11 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
         let (staging_buffer, staging_buffer_ptr) =
             prepare_staging_buffer(device, buffer_size.get(), device.instance_flags)?;
 
+        // The mapping is handed to the user as a `&mut [u8]`, and is copied into the
+        // destination even if they don't write all of it, so it must not be left
+        // uninitialized.
+        unsafe { ptr::write_bytes(staging_buffer_ptr, 0, buffer_size.get() as usize) };
+
         let fid = hub.staging_buffers.prepare::<G>(id_in);
         let (id, _) = fid.assign(staging_buffer);
         resource_log!("Queue::create_staging_buffer {id:?}");
@@ -560,7 +569,11 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
             return Err(TransferError::UnalignedCopySize(buffer_size));
         }
         if buffer_offset % wgt::COPY_BUFFER_ALIGNMENT != 0 {
-            return Err(TransferError::UnalignedBufferOffset(buffer_offset));
+            return Err(TransferError::UnalignedCopyBufferOffset {
+                buffer: buffer_id,
+                offset: buffer_offset,
+                side: CopySide::Destination,
+            });
         }
         if buffer_offset + buffer_size > buffer.size {
             return Err(TransferError::BufferOverrun {
@@ -754,9 +767,10 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
         let stage_bytes_per_row =
             wgt::math::align_to(block_size * width_blocks, bytes_per_row_alignment);
 
-        let block_rows_in_copy =
-            (size.depth_or_array_layers - 1) * block_rows_per_image + height_blocks;
-        let stage_size = stage_bytes_per_row as u64 * block_rows_in_copy as u64;
+        // The staging buffer packs the images tightly, whatever padding the
+        // caller's `rows_per_image` leaves between them.
+        let stage_bytes_per_image = stage_bytes_per_row as u64 * height_blocks as u64;
+        let stage_size = stage_bytes_per_image * size.depth_or_array_layers as u64;
 
         let mut pending_writes = device.pending_writes.lock();
         let pending_writes = pending_writes.as_mut().unwrap();
@@ -830,7 +844,7 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
         let stage_fid = hub.staging_buffers.request();
         let staging_buffer = stage_fid.init(staging_buffer);
 
-        if stage_bytes_per_row == bytes_per_row {
+        if stage_bytes_per_row == bytes_per_row && block_rows_per_image == height_blocks {
             profiling::scope!("copy aligned");
             // Fast path if the data is already being aligned optimally.
             unsafe {
@@ -845,16 +859,17 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
             // Copy row by row into the optimal alignment.
             let copy_bytes_per_row = stage_bytes_per_row.min(bytes_per_row) as usize;
             for layer in 0..size.depth_or_array_layers {
-                let rows_offset = layer * block_rows_per_image;
+                let src_rows_offset = layer * block_rows_per_image;
+                let dst_rows_offset = layer * height_blocks;
                 for row in 0..height_blocks {
                     unsafe {
                         ptr::copy_nonoverlapping(
                             data.as_ptr().offset(
                                 data_layout.offset as isize
-                                    + (rows_offset + row) as isize * bytes_per_row as isize,
+                                    + (src_rows_offset + row) as isize * bytes_per_row as isize,
                             ),
                             staging_buffer_ptr.offset(
-                                (rows_offset + row) as isize * stage_bytes_per_row as isize,
+                                (dst_rows_offset + row) as isize * stage_bytes_per_row as isize,
                             ),
                             copy_bytes_per_row,
                         );
@@ -873,11 +888,9 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
             texture_base.array_layer += rel_array_layer;
             hal::BufferTextureCopy {
                 buffer_layout: wgt::ImageDataLayout {
-                    offset: rel_array_layer as u64
-                        * block_rows_per_image as u64
-                        * stage_bytes_per_row as u64,
+                    offset: rel_array_layer as u64 * stage_bytes_per_image,
                     bytes_per_row: Some(stage_bytes_per_row),
-                    rows_per_image: Some(block_rows_per_image),
+                    rows_per_image: Some(height_blocks),
                 },
                 texture_base,
                 size: hal_copy_size,
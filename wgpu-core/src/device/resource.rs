@@ -13,6 +13,7 @@ use crate::{
     hal_label,
     hub::Hub,
     id::{self, DeviceId, QueueId},
+    indirect_validation::IndirectValidation,
     init_tracker::{
         BufferInitTracker, BufferInitTrackerAction, MemoryInitKind, TextureInitRange,
         TextureInitTracker, TextureInitTrackerAction,
@@ -90,6 +91,8 @@ pub struct Device<A: HalApi> {
     pub(crate) queue_id: RwLock<Option<QueueId>>,
     queue_to_drop: RwLock<Option<A::Queue>>,
     pub(crate) zero_buffer: Option<A::Buffer>,
+    /// Clamps indirect dispatches, if [`wgt::InstanceFlags::VALIDATION_INDIRECT_CALL`] is set.
+    pub(crate) indirect_validation: Option<IndirectValidation<A>>,
     pub(crate) info: ResourceInfo<DeviceId>,
 
     pub(crate) command_allocator: Mutex<Option<CommandAllocator<A>>>,
@@ -151,6 +154,9 @@ impl<A: HalApi> Drop for Device<A> {
         let pending_writes = self.pending_writes.lock().take().unwrap();
         pending_writes.dispose(&raw);
         self.command_allocator.lock().take().unwrap().dispose(&raw);
+        if let Some(indirect_validation) = self.indirect_validation.take() {
+            indirect_validation.dispose(&raw);
+        }
         unsafe {
             raw.destroy_buffer(self.zero_buffer.take().unwrap());
             raw.destroy_fence(self.fence.write().take().unwrap());
@@ -166,12 +172,22 @@ pub enum CreateDeviceError {
     OutOfMemory,
     #[error("Failed to create internal buffer for initializing textures")]
     FailedToCreateZeroBuffer(#[from] DeviceError),
+    #[error("Failed to create internal pipeline for clamping indirect dispatches")]
+    FailedToCreateIndirectValidation(#[source] DeviceError),
 }
 
 impl<A: HalApi> Device<A> {
     pub(crate) fn raw(&self) -> &A::Device {
         self.raw.as_ref().unwrap()
     }
+
+    /// Destroy the idle command encoders this device keeps for reuse.
+    pub(crate) fn trim_command_allocator(&self) {
+        if let Some(allocator) = self.command_allocator.lock().as_mut() {
+            allocator.trim(self.raw());
+        }
+    }
+
     pub(crate) fn require_features(&self, feature: wgt::Features) -> Result<(), MissingFeatures> {
         if self.features.contains(feature) {
             Ok(())
@@ -246,6 +262,20 @@ impl<A: HalApi> Device<A> {
                 }));
         }
 
+        let indirect_validation =
+            if instance_flags.contains(wgt::InstanceFlags::VALIDATION_INDIRECT_CALL) {
+                IndirectValidation::new(
+                    &raw_device,
+                    &mut pending_writes.command_encoder,
+                    &adapter.raw.capabilities,
+                    &desc.required_limits,
+                    instance_flags,
+                )
+                .map_err(CreateDeviceError::FailedToCreateIndirectValidation)?
+            } else {
+                None
+            };
+
         let alignments = adapter.raw.capabilities.alignments.clone();
         let downlevel = adapter.raw.capabilities.downlevel.clone();
 
@@ -255,6 +285,7 @@ impl<A: HalApi> Device<A> {
             queue_id: RwLock::new(None),
             queue_to_drop: RwLock::new(None),
             zero_buffer: Some(zero_buffer),
+            indirect_validation,
             info: ResourceInfo::new("<device>"),
             command_allocator: Mutex::new(Some(com_alloc)),
             active_submission_index: AtomicU64::new(0),
@@ -368,6 +399,7 @@ impl<A: HalApi> Device<A> {
         let mut life_tracker = self.lock_life();
         let submission_closures = life_tracker.triage_submissions(
             last_done_index,
+            self.raw(),
             self.command_allocator.lock().as_mut().unwrap(),
         );
         let mapping_closures = life_tracker.handle_mapping(self.raw(), &self.trackers);
@@ -497,6 +529,10 @@ impl<A: HalApi> Device<A> {
         }
 
         let mut usage = conv::map_buffer_usage(desc.usage);
+        if desc.usage.contains(wgt::BufferUsages::INDIRECT) && self.indirect_validation.is_some() {
+            // Indirect dispatches are clamped by reading the arguments in a shader.
+            usage |= hal::BufferUses::STORAGE_READ;
+        }
 
         if desc.usage.is_empty() || desc.usage.contains_invalid_bits() {
             return Err(resource::CreateBufferError::InvalidUsage(desc.usage));
@@ -518,7 +554,7 @@ impl<A: HalApi> Device<A> {
 
         if desc.mapped_at_creation {
             if desc.size % wgt::COPY_BUFFER_ALIGNMENT != 0 {
-                return Err(resource::CreateBufferError::UnalignedSize);
+                return Err(resource::CreateBufferError::UnalignedSize(desc.size));
             }
             if !desc.usage.contains(wgt::BufferUsages::MAP_WRITE) {
                 // we are going to be copying into it, internally
@@ -557,8 +593,27 @@ impl<A: HalApi> Device<A> {
         };
         let buffer = unsafe { self.raw().create_buffer(&hal_desc) }.map_err(DeviceError::from)?;
 
+        let indirect_validation_bind_group = match self.indirect_validation {
+            Some(ref indirect_validation) if desc.usage.contains(wgt::BufferUsages::INDIRECT) => {
+                match indirect_validation.create_src_bind_group(
+                    self.raw(),
+                    &buffer,
+                    desc.size,
+                    self.instance_flags,
+                ) {
+                    Ok(bind_group) => bind_group,
+                    Err(error) => {
+                        unsafe { self.raw().destroy_buffer(buffer) };
+                        return Err(error.into());
+                    }
+                }
+            }
+            _ => None,
+        };
+
         Ok(Buffer {
             raw: Snatchable::new(buffer),
+            indirect_validation_bind_group,
             device: self.clone(),
             usage: desc.usage,
             size: desc.size,
@@ -607,6 +662,7 @@ impl<A: HalApi> Device<A> {
 
         Buffer {
             raw: Snatchable::new(hal_buffer),
+            indirect_validation_bind_group: None,
             device: self.clone(),
             usage: desc.usage,
             size: desc.size,
@@ -972,9 +1028,22 @@ impl<A: HalApi> Device<A> {
                     .aspect_specific_format(desc.range.aspect)
         };
         if !format_is_good {
+            let allowed = if desc.range.aspect == wgt::TextureAspect::All {
+                iter::once(texture.desc.format)
+                    .chain(texture.desc.view_formats.iter().copied())
+                    .collect()
+            } else {
+                texture
+                    .desc
+                    .format
+                    .aspect_specific_format(desc.range.aspect)
+                    .into_iter()
+                    .collect()
+            };
             return Err(resource::CreateTextureViewError::FormatReinterpretation {
                 texture: texture.desc.format,
                 view: resolved_format,
+                allowed,
             });
         }
 
@@ -1203,7 +1272,10 @@ impl<A: HalApi> Device<A> {
             .iter()
             .any(|am| am == &wgt::AddressMode::ClampToBorder)
         {
-            self.require_features(wgt::Features::ADDRESS_MODE_CLAMP_TO_BORDER)?;
+            // A zero border only needs its own feature.
+            if desc.border_color != Some(wgt::SamplerBorderColor::Zero) {
+                self.require_features(wgt::Features::ADDRESS_MODE_CLAMP_TO_BORDER)?;
+            }
         }
 
         if desc.border_color == Some(wgt::SamplerBorderColor::Zero) {
@@ -1319,6 +1391,19 @@ impl<A: HalApi> Device<A> {
                 })?;
                 (Cow::Owned(module), code.into_owned())
             }
+            #[cfg(feature = "glsl")]
+            pipeline::ShaderModuleSource::Glsl(code, options) => {
+                profiling::scope!("naga::glsl::parse");
+                let mut frontend = naga::front::glsl::Frontend::default();
+                let module = frontend.parse(&options, &code).map_err(|errors| {
+                    pipeline::CreateShaderModuleError::ParsingGlsl(pipeline::ShaderError {
+                        source: code.to_string(),
+                        label: desc.label.as_ref().map(|l| l.to_string()),
+                        inner: Box::new(pipeline::GlslParseErrors(errors)),
+                    })
+                })?;
+                (Cow::Owned(module), code.into_owned())
+            }
             pipeline::ShaderModuleSource::Naga(module) => (module, String::new()),
             pipeline::ShaderModuleSource::Dummy(_) => panic!("found `ShaderModuleSource::Dummy`"),
         };
@@ -1461,6 +1546,7 @@ impl<A: HalApi> Device<A> {
             raw: Some(raw),
             device: self.clone(),
             interface: Some(interface),
+            spirv_entry_points: Vec::new(),
             info: ResourceInfo::new(desc.label.borrow_or_default()),
             label: desc.label.borrow_or_default().to_string(),
         })
@@ -1502,6 +1588,7 @@ impl<A: HalApi> Device<A> {
             raw: Some(raw),
             device: self.clone(),
             interface: None,
+            spirv_entry_points: validation::spirv_entry_points(source),
             info: ResourceInfo::new(desc.label.borrow_or_default()),
             label: desc.label.borrow_or_default().to_string(),
         })
@@ -1662,13 +1749,22 @@ impl<A: HalApi> Device<A> {
             };
 
             // Validate the count parameter
-            if entry.count.is_some() {
+            if let Some(count) = entry.count {
                 required_features |= array_feature
                     .ok_or(BindGroupLayoutEntryError::ArrayUnsupported)
                     .map_err(|error| binding_model::CreateBindGroupLayoutError::Entry {
                         binding: entry.binding,
                         error,
                     })?;
+                if count.get() > self.limits.max_binding_array_elements {
+                    return Err(binding_model::CreateBindGroupLayoutError::Entry {
+                        binding: entry.binding,
+                        error: BindGroupLayoutEntryError::ArrayTooLarge {
+                            count: count.get(),
+                            limit: self.limits.max_binding_array_elements,
+                        },
+                    });
+                }
             }
 
             if entry.visibility.contains_invalid_bits() {
@@ -1861,6 +1957,7 @@ impl<A: HalApi> Device<A> {
             if min_size > bind_size {
                 return Err(Error::BindingSizeTooSmall {
                     buffer: bb.buffer_id,
+                    binding,
                     actual: bind_size,
                     min: min_size,
                 });
@@ -1947,7 +2044,6 @@ impl<A: HalApi> Device<A> {
             }
         }
 
-        // TODO: arrayvec/smallvec, or re-use allocations
         // Record binding info for dynamic offset validation
         let mut dynamic_binding_info = Vec::new();
         // Map of binding -> shader reflected size
@@ -1963,10 +2059,12 @@ impl<A: HalApi> Device<A> {
 
         let mut used_buffer_ranges = Vec::new();
         let mut used_texture_ranges = Vec::new();
-        let mut hal_entries = Vec::with_capacity(desc.entries.len());
-        let mut hal_buffers = Vec::new();
-        let mut hal_samplers = Vec::new();
-        let mut hal_textures = Vec::new();
+        // The hal descriptor only lives for the duration of this call, so keep
+        // its arrays on the stack for typical bind groups.
+        let mut hal_entries = SmallVec::<[_; 8]>::with_capacity(desc.entries.len());
+        let mut hal_buffers = SmallVec::<[_; 4]>::new();
+        let mut hal_samplers = SmallVec::<[_; 4]>::new();
+        let mut hal_textures = SmallVec::<[_; 4]>::new();
         let snatch_guard = self.snatchable_lock.read();
         for entry in desc.entries.iter() {
             let binding = entry.binding;
@@ -2570,6 +2668,14 @@ impl<A: HalApi> Device<A> {
                     io,
                     None,
                 )?;
+            } else {
+                shader_module.check_passthrough_entry_point(
+                    &desc.stage.entry_point,
+                    naga::ShaderStage::Compute,
+                )?;
+                if desc.layout.is_none() {
+                    return Err(pipeline::ImplicitLayoutError::ReflectionError(stage).into());
+                }
             }
         }
 
@@ -2974,6 +3080,13 @@ impl<A: HalApi> Device<A> {
                     )
                     .map_err(|error| pipeline::CreateRenderPipelineError::Stage { stage, error })?;
                 validated_stages |= stage;
+            } else {
+                vertex_shader_module
+                    .check_passthrough_entry_point(
+                        &stage_desc.entry_point,
+                        naga::ShaderStage::Vertex,
+                    )
+                    .map_err(|error| pipeline::CreateRenderPipelineError::Stage { stage, error })?;
             }
 
             hal::ProgrammableStage {
@@ -3022,6 +3135,16 @@ impl<A: HalApi> Device<A> {
                             stage,
                             error,
                         })?;
+                } else {
+                    shader_module
+                        .check_passthrough_entry_point(
+                            &fragment_state.stage.entry_point,
+                            naga::ShaderStage::Fragment,
+                        )
+                        .map_err(|error| pipeline::CreateRenderPipelineError::Stage {
+                            stage,
+                            error,
+                        })?;
                 }
 
                 Some(hal::ProgrammableStage {
@@ -3271,6 +3394,7 @@ impl<A: HalApi> Device<A> {
             drop(guard);
             let closures = self.lock_life().triage_submissions(
                 submission_index,
+                self.raw(),
                 self.command_allocator.lock().as_mut().unwrap(),
             );
             assert!(
@@ -3378,6 +3502,7 @@ impl<A: HalApi> Device<A> {
         let mut life_tracker = self.lock_life();
         let _ = life_tracker.triage_submissions(
             current_index,
+            self.raw(),
             self.command_allocator.lock().as_mut().unwrap(),
         );
         #[cfg(feature = "trace")]
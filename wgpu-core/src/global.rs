@@ -9,6 +9,7 @@ use crate::{
     identity::GlobalIdentityHandlerFactory,
     instance::{Instance, Surface},
     registry::{Registry, RegistryReport},
+    resource::Resource,
     resource_log,
     storage::Element,
 };
@@ -136,6 +137,29 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
             },
         }
     }
+
+    /// Log every live buffer and texture of backend `A`, with its label and size.
+    ///
+    /// This is meant for tracking down leaks. Each registry is read-locked
+    /// while it is being logged.
+    pub fn log_live_resources<A: HalApi>(&self) {
+        let hub = A::hub(self);
+        hub.buffers.for_each(|id, buffer| {
+            log::info!(
+                "Live buffer {id:?} {:?}: {} bytes",
+                buffer.label(),
+                buffer.size
+            );
+        });
+        hub.textures.for_each(|id, texture| {
+            log::info!(
+                "Live texture {id:?} {:?}: {:?} {:?}",
+                texture.label(),
+                texture.desc.size,
+                texture.desc.format
+            );
+        });
+    }
 }
 
 impl<G: GlobalIdentityHandlerFactory> Drop for Global<G> {
@@ -163,7 +187,7 @@ impl<G: GlobalIdentityHandlerFactory> Drop for Global<G> {
         }
 
         // destroy surfaces
-        for element in surfaces_locked.map.drain(..) {
+        for element in surfaces_locked.drain() {
             if let Element::Occupied(arc_surface, _) = element {
                 if let Some(surface) = Arc::into_inner(arc_surface) {
                     self.instance.destroy_surface(surface);
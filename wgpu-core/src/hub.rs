@@ -230,26 +230,26 @@ impl<A: HalApi> Hub<A> {
         use hal::Surface;
 
         let mut devices = self.devices.write();
-        for element in devices.map.iter() {
+        for element in devices.elements() {
             if let Element::Occupied(ref device, _) = *element {
                 device.prepare_to_die();
             }
         }
 
-        self.command_buffers.write().map.clear();
-        self.samplers.write().map.clear();
-        self.texture_views.write().map.clear();
-        self.textures.write().map.clear();
-        self.buffers.write().map.clear();
-        self.bind_groups.write().map.clear();
-        self.shader_modules.write().map.clear();
-        self.bind_group_layouts.write().map.clear();
-        self.pipeline_layouts.write().map.clear();
-        self.compute_pipelines.write().map.clear();
-        self.render_pipelines.write().map.clear();
-        self.query_sets.write().map.clear();
-
-        for element in surface_guard.map.iter() {
+        self.command_buffers.write().clear();
+        self.samplers.write().clear();
+        self.texture_views.write().clear();
+        self.textures.write().clear();
+        self.buffers.write().clear();
+        self.bind_groups.write().clear();
+        self.shader_modules.write().clear();
+        self.bind_group_layouts.write().clear();
+        self.pipeline_layouts.write().clear();
+        self.compute_pipelines.write().clear();
+        self.render_pipelines.write().clear();
+        self.query_sets.write().clear();
+
+        for element in surface_guard.elements() {
             if let Element::Occupied(ref surface, _epoch) = *element {
                 if let Some(ref mut present) = surface.presentation.lock().take() {
                     if let Some(device) = present.device.downcast_ref::<A>() {
@@ -263,12 +263,12 @@ impl<A: HalApi> Hub<A> {
             }
         }
 
-        self.queues.write().map.clear();
-        devices.map.clear();
+        self.queues.write().clear();
+        devices.clear();
 
         if with_adapters {
             drop(devices);
-            self.adapters.write().map.clear();
+            self.adapters.write().clear();
         }
     }
 
@@ -279,6 +279,71 @@ impl<A: HalApi> Hub<A> {
         }
     }
 
+    /// Free memory that the hub's registries keep around to make creating
+    /// resources cheap, along with the idle command encoders each device
+    /// pools for reuse.
+    ///
+    /// Useful at a quiet moment after a burst of short-lived resources.
+    pub fn shrink_to_fit(&self) {
+        for (_, device) in self.devices.read().iter(A::VARIANT) {
+            device.trim_command_allocator();
+        }
+        self.adapters.shrink_to_fit();
+        self.devices.shrink_to_fit();
+        self.queues.shrink_to_fit();
+        self.pipeline_layouts.shrink_to_fit();
+        self.shader_modules.shrink_to_fit();
+        self.bind_group_layouts.shrink_to_fit();
+        self.bind_groups.shrink_to_fit();
+        self.command_buffers.shrink_to_fit();
+        self.render_bundles.shrink_to_fit();
+        self.render_pipelines.shrink_to_fit();
+        self.compute_pipelines.shrink_to_fit();
+        self.query_sets.shrink_to_fit();
+        self.buffers.shrink_to_fit();
+        self.staging_buffers.shrink_to_fit();
+        self.textures.shrink_to_fit();
+        self.texture_views.shrink_to_fit();
+        self.samplers.shrink_to_fit();
+    }
+
+    /// Log every resource that is still registered in this hub.
+    ///
+    /// This is meant to be called once an application believes it has
+    /// released everything, to find leaked resources. If any are left, the
+    /// returned error summarizes them, like `"buffers: 3, bind_groups: 1"`.
+    pub fn check_empty(&self) -> Result<(), String> {
+        let live = [
+            ("adapters", self.adapters.log_live()),
+            ("devices", self.devices.log_live()),
+            ("queues", self.queues.log_live()),
+            ("pipeline_layouts", self.pipeline_layouts.log_live()),
+            ("shader_modules", self.shader_modules.log_live()),
+            ("bind_group_layouts", self.bind_group_layouts.log_live()),
+            ("bind_groups", self.bind_groups.log_live()),
+            ("command_buffers", self.command_buffers.log_live()),
+            ("render_bundles", self.render_bundles.log_live()),
+            ("render_pipelines", self.render_pipelines.log_live()),
+            ("compute_pipelines", self.compute_pipelines.log_live()),
+            ("query_sets", self.query_sets.log_live()),
+            ("buffers", self.buffers.log_live()),
+            ("staging_buffers", self.staging_buffers.log_live()),
+            ("textures", self.textures.log_live()),
+            ("texture_views", self.texture_views.log_live()),
+            ("samplers", self.samplers.log_live()),
+        ];
+        let leaks: Vec<_> = live
+            .iter()
+            .filter(|&&(_, count)| count != 0)
+            .map(|(name, count)| format!("{name}: {count}"))
+            .collect();
+        if leaks.is_empty() {
+            Ok(())
+        } else {
+            Err(leaks.join(", "))
+        }
+    }
+
     pub fn generate_report(&self) -> HubReport {
         HubReport {
             adapters: self.adapters.generate_report(),
@@ -340,3 +405,97 @@ impl Hubs {
         }
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::Arc;
+
+    use super::Hub;
+    use crate::{
+        device::Device, identity::IdentityManagerFactory, instance::Adapter, resource::Resource,
+    };
+
+    type Empty = hal::api::Empty;
+
+    /// Register an adapter and a device of the empty backend in `hub`.
+    pub(crate) fn empty_device(hub: &Hub<Empty>) -> (Arc<Adapter<Empty>>, Arc<Device<Empty>>) {
+        let exposed = hal::ExposedAdapter {
+            adapter: hal::empty::Context,
+            info: wgt::AdapterInfo {
+                name: String::from("empty"),
+                vendor: 0,
+                device: 0,
+                device_type: wgt::DeviceType::Cpu,
+                driver: String::new(),
+                driver_info: String::new(),
+                backend: wgt::Backend::Empty,
+            },
+            features: wgt::Features::empty(),
+            capabilities: hal::Capabilities {
+                limits: wgt::Limits::default(),
+                alignments: hal::Alignments {
+                    buffer_copy_offset: wgt::BufferSize::new(4).unwrap(),
+                    buffer_copy_pitch: wgt::BufferSize::new(256).unwrap(),
+                },
+                downlevel: wgt::DownlevelCapabilities::default(),
+            },
+        };
+        let (_, adapter) = hub
+            .adapters
+            .prepare::<IdentityManagerFactory>(())
+            .assign(Adapter::new(exposed));
+        let device = Device::new(
+            hal::empty::Context,
+            &hal::empty::Context,
+            &adapter,
+            &wgt::DeviceDescriptor::default(),
+            None,
+            wgt::InstanceFlags::empty(),
+        )
+        .unwrap();
+        let (_, device) = hub
+            .devices
+            .prepare::<IdentityManagerFactory>(())
+            .assign(device);
+        (adapter, device)
+    }
+
+    #[test]
+    fn check_empty_reports_leaked_texture() {
+        let hub = Hub::<Empty>::new(&IdentityManagerFactory);
+        assert_eq!(hub.check_empty(), Ok(()));
+
+        let (adapter, device) = empty_device(&hub);
+        let texture = device
+            .create_texture(
+                &adapter,
+                &wgt::TextureDescriptor {
+                    label: Some("leaked".into()),
+                    size: wgt::Extent3d {
+                        width: 4,
+                        height: 4,
+                        depth_or_array_layers: 1,
+                    },
+                    mip_level_count: 1,
+                    sample_count: 1,
+                    dimension: wgt::TextureDimension::D2,
+                    format: wgt::TextureFormat::Rgba8Unorm,
+                    usage: wgt::TextureUsages::TEXTURE_BINDING,
+                    view_formats: Vec::new(),
+                },
+            )
+            .unwrap();
+        let (texture_id, _) = hub
+            .textures
+            .prepare::<IdentityManagerFactory>(())
+            .assign(texture);
+
+        // Release everything but the texture.
+        hub.devices.unregister(device.as_info().id());
+        hub.adapters.unregister(adapter.as_info().id());
+        assert_eq!(hub.check_empty(), Err(String::from("textures: 1")));
+
+        hub.textures.unregister(texture_id);
+        assert_eq!(hub.check_empty(), Ok(()));
+    }
+}
@@ -153,11 +153,23 @@ where
             Backend::Gl => "gl",
             Backend::BrowserWebGpu => "webgpu",
         };
-        write!(formatter, "Id({index},{epoch},{backend})")?;
+        write!(
+            formatter,
+            "{}({index},{epoch},{backend})",
+            resource_type_name::<T>()
+        )?;
         Ok(())
     }
 }
 
+/// The name of the resource type `T`, without its module path or generic
+/// parameters, for use in `Id`'s `Debug` output.
+fn resource_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
 impl<T> Hash for Id<T>
 where
     T: 'static + WasmNotSendSync,
@@ -302,3 +314,13 @@ fn test_id() {
         }
     }
 }
+
+#[test]
+fn test_id_debug() {
+    let buffer = BufferId::zip(5, 2, Backend::Vulkan);
+    assert_eq!(format!("{buffer:?}"), "Buffer(5,2,vk)");
+    let texture = TextureId::zip(0, 1, Backend::Empty);
+    assert_eq!(format!("{texture:?}"), "Texture(0,1,_)");
+    let surface = SurfaceId::zip(3, 1, Backend::Gl);
+    assert_eq!(format!("{surface:?}"), "Surface(3,1,gl)");
+}
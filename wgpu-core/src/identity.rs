@@ -3,7 +3,7 @@ use wgt::Backend;
 
 use crate::{
     id::{self},
-    Epoch, FastHashMap, Index,
+    Epoch, Index,
 };
 use std::{fmt::Debug, marker::PhantomData, sync::Arc};
 
@@ -37,8 +37,8 @@ use std::{fmt::Debug, marker::PhantomData, sync::Arc};
 #[derive(Debug, Default)]
 pub(super) struct IdentityValues {
     free: Vec<(Index, Epoch)>,
-    //sorted by Index
-    used: FastHashMap<Epoch, Vec<Index>>,
+    /// One past the highest index ever handed out or marked as used.
+    next_index: Index,
     count: usize,
 }
 
@@ -52,31 +52,32 @@ impl IdentityValues {
         match self.free.pop() {
             Some((index, epoch)) => I::zip(index, epoch + 1, backend),
             None => {
-                let epoch = 1;
-                let used = self.used.entry(epoch).or_insert_with(Default::default);
-                let index = if let Some(i) = used.iter().max_by_key(|v| *v) {
-                    i + 1
-                } else {
-                    0
-                };
-                used.push(index);
-                I::zip(index, epoch, backend)
+                let index = self.next_index;
+                self.next_index += 1;
+                I::zip(index, 1, backend)
             }
         }
     }
 
     pub fn mark_as_used<I: id::TypedId>(&mut self, id: I) -> I {
         self.count += 1;
-        let (index, epoch, _backend) = id.unzip();
-        let used = self.used.entry(epoch).or_insert_with(Default::default);
-        used.push(index);
+        let (index, _epoch, _backend) = id.unzip();
+        self.next_index = self.next_index.max(index + 1);
         id
     }
 
     /// Free `id`. It will never be returned from `alloc` again.
+    ///
+    /// If `id`'s epoch is the largest one an id can hold, its index is
+    /// retired instead of being reused, since bumping the epoch again would
+    /// wrap around and make stale ids look alive.
     pub fn release<I: id::TypedId>(&mut self, id: I) {
         let (index, epoch, _backend) = id.unzip();
-        self.free.push((index, epoch));
+        if epoch < id::EPOCH_MASK {
+            self.free.push((index, epoch));
+        } else {
+            log::warn!("Index {index} has run out of epochs and will not be reused");
+        }
         self.count -= 1;
     }
 
@@ -190,3 +191,53 @@ fn test_epoch_end_of_life() {
     assert_eq!(id2.unzip().0, 1);
     assert_eq!(id2.unzip().1, 2);
 }
+
+#[test]
+fn test_epoch_overflow_retires_index() {
+    use id::TypedId as _;
+    let man = IdentityManager::<id::BufferId>::new();
+    let id1 = man.process(Backend::Empty);
+    assert_eq!(id1.unzip().0, 0);
+    man.free(id1);
+
+    // Pretend index 0 has been recycled until one epoch before the last.
+    man.values.lock().free = vec![(0, id::EPOCH_MASK - 1)];
+    let id2 = man.process(Backend::Empty);
+    assert_eq!(id2.unzip(), (0, id::EPOCH_MASK, Backend::Empty));
+    man.free(id2);
+
+    // Index 0 is out of epochs, so it must not be handed out again.
+    let id3 = man.process(Backend::Empty);
+    assert_eq!(id3.unzip().0, 1);
+    assert_eq!(id3.unzip().1, 1);
+}
+
+#[test]
+fn test_concurrent_alloc_free() {
+    use id::TypedId as _;
+    use std::collections::HashSet;
+
+    let man = IdentityManager::<id::BufferId>::new();
+    let live = Mutex::new(HashSet::new());
+    std::thread::scope(|scope| {
+        for _ in 0..8 {
+            scope.spawn(|| {
+                let mut ids = Vec::new();
+                for round in 0..1000 {
+                    let id = man.process(Backend::Empty);
+                    assert!(
+                        live.lock().insert(id.unzip()),
+                        "{id:?} was handed out twice"
+                    );
+                    ids.push(id);
+                    if round % 3 != 0 {
+                        let id = ids.swap_remove(round % ids.len());
+                        live.lock().remove(&id.unzip());
+                        man.free(id);
+                    }
+                }
+            });
+        }
+    });
+    assert_eq!(man.values.lock().count(), live.lock().len());
+}
@@ -0,0 +1,437 @@
+//! Clamping of indirect dispatch arguments to the device limits.
+//!
+//! The arguments of an indirect dispatch live in GPU memory, so they can't be
+//! checked against `max_compute_workgroups_per_dimension` when the pass is
+//! recorded. With [`wgt::InstanceFlags::VALIDATION_INDIRECT_CALL`], every indirect
+//! dispatch is preceded by an internal single-invocation dispatch that copies
+//! the arguments into a buffer owned by the device, clamping each dimension to
+//! the limit on the way. The user's dispatch then reads its arguments from that
+//! buffer instead.
+//!
+//! The internal dispatch reads the indirect buffer through a storage binding.
+//! Every buffer created with [`wgt::BufferUsages::INDIRECT`] gets a bind group
+//! for that when it is created. The binding covers a small window of the buffer,
+//! moved around with a dynamic offset, so it stays within the binding size
+//! limits no matter how large the buffer is.
+
+use std::{iter, num::NonZeroU64};
+
+use hal::{CommandEncoder as _, Device as _};
+
+use crate::{device::DeviceError, hal_api::HalApi, hal_label};
+
+/// Size of the dispatch arguments, three `u32` workgroup counts.
+const ARGS_SIZE: u64 = 3 * 4;
+
+/// Size of the push constant holding the index of the first argument in the
+/// source binding.
+const SRC_INDEX_PUSH_CONSTANT_SIZE: u32 = 4;
+
+/// Internal pipeline and buffers used to clamp indirect dispatches.
+pub(crate) struct IndirectValidation<A: HalApi> {
+    module: A::ShaderModule,
+    src_bind_group_layout: A::BindGroupLayout,
+    dst_bind_group_layout: A::BindGroupLayout,
+    pipeline_layout: A::PipelineLayout,
+    pipeline: A::ComputePipeline,
+    /// The clamped arguments of the last validated dispatch.
+    ///
+    /// Between dispatches, this is in the `INDIRECT` state.
+    dst_buffer: A::Buffer,
+    dst_bind_group: A::BindGroup,
+    min_storage_buffer_offset_alignment: u64,
+}
+
+impl<A: HalApi> IndirectValidation<A> {
+    /// Create the internal pipeline, or return `None` if the device can't run it.
+    ///
+    /// `encoder` must be open. It's used to put the destination buffer in the
+    /// state dispatches expect it in.
+    pub(crate) fn new(
+        device: &A::Device,
+        encoder: &mut A::CommandEncoder,
+        capabilities: &hal::Capabilities,
+        limits: &wgt::Limits,
+        instance_flags: wgt::InstanceFlags,
+    ) -> Result<Option<Self>, DeviceError> {
+        let required_downlevel_flags =
+            wgt::DownlevelFlags::COMPUTE_SHADERS | wgt::DownlevelFlags::INDIRECT_EXECUTION;
+        if !capabilities
+            .downlevel
+            .flags
+            .contains(required_downlevel_flags)
+            || capabilities.limits.max_storage_buffers_per_shader_stage < 2
+            || capabilities
+                .limits
+                .max_dynamic_storage_buffers_per_pipeline_layout
+                < 1
+            || capabilities.limits.max_push_constant_size < SRC_INDEX_PUSH_CONSTANT_SIZE
+        {
+            log::warn!("Indirect dispatches can't be clamped on this device");
+            return Ok(None);
+        }
+
+        let Some(shader) = parse_shader(limits.max_compute_workgroups_per_dimension) else {
+            return Ok(None);
+        };
+
+        let module = unsafe {
+            device.create_shader_module(
+                &hal::ShaderModuleDescriptor {
+                    label: hal_label(
+                        Some("(wgpu internal) Indirect validation shader"),
+                        instance_flags,
+                    ),
+                    runtime_checks: true,
+                },
+                hal::ShaderInput::Naga(shader),
+            )
+        }
+        .map_err(|error| match error {
+            hal::ShaderError::Device(error) => DeviceError::from(error),
+            hal::ShaderError::Compilation(message) => {
+                log::error!("Indirect validation shader failed to compile: {message}");
+                DeviceError::ResourceCreationFailed
+            }
+        })?;
+
+        let src_bind_group_layout = unsafe {
+            device.create_bind_group_layout(&hal::BindGroupLayoutDescriptor {
+                label: hal_label(
+                    Some("(wgpu internal) Indirect validation source bind group layout"),
+                    instance_flags,
+                ),
+                flags: hal::BindGroupLayoutFlags::empty(),
+                entries: &[storage_buffer_entry(true, true)],
+            })
+        }
+        .map_err(DeviceError::from)?;
+
+        let dst_bind_group_layout = unsafe {
+            device.create_bind_group_layout(&hal::BindGroupLayoutDescriptor {
+                label: hal_label(
+                    Some("(wgpu internal) Indirect validation destination bind group layout"),
+                    instance_flags,
+                ),
+                flags: hal::BindGroupLayoutFlags::empty(),
+                entries: &[storage_buffer_entry(false, false)],
+            })
+        }
+        .map_err(DeviceError::from)?;
+
+        let pipeline_layout = unsafe {
+            device.create_pipeline_layout(&hal::PipelineLayoutDescriptor {
+                label: hal_label(
+                    Some("(wgpu internal) Indirect validation pipeline layout"),
+                    instance_flags,
+                ),
+                flags: hal::PipelineLayoutFlags::empty(),
+                bind_group_layouts: &[&src_bind_group_layout, &dst_bind_group_layout],
+                push_constant_ranges: &[wgt::PushConstantRange {
+                    stages: wgt::ShaderStages::COMPUTE,
+                    range: 0..SRC_INDEX_PUSH_CONSTANT_SIZE,
+                }],
+            })
+        }
+        .map_err(DeviceError::from)?;
+
+        let pipeline = unsafe {
+            device.create_compute_pipeline(&hal::ComputePipelineDescriptor {
+                label: hal_label(
+                    Some("(wgpu internal) Indirect validation pipeline"),
+                    instance_flags,
+                ),
+                layout: &pipeline_layout,
+                stage: hal::ProgrammableStage {
+                    module: &module,
+                    entry_point: "main",
+                },
+            })
+        }
+        .map_err(|error| match error {
+            hal::PipelineError::Device(error) => DeviceError::from(error),
+            error => {
+                log::error!("Indirect validation pipeline failed to link: {error}");
+                DeviceError::ResourceCreationFailed
+            }
+        })?;
+
+        let dst_buffer = unsafe {
+            device.create_buffer(&hal::BufferDescriptor {
+                label: hal_label(
+                    Some("(wgpu internal) Indirect validation destination buffer"),
+                    instance_flags,
+                ),
+                size: ARGS_SIZE,
+                usage: hal::BufferUses::STORAGE_READ_WRITE | hal::BufferUses::INDIRECT,
+                memory_flags: hal::MemoryFlags::empty(),
+            })
+        }
+        .map_err(DeviceError::from)?;
+
+        let dst_bind_group = unsafe {
+            device.create_bind_group(&hal::BindGroupDescriptor {
+                label: hal_label(
+                    Some("(wgpu internal) Indirect validation destination bind group"),
+                    instance_flags,
+                ),
+                layout: &dst_bind_group_layout,
+                buffers: &[hal::BufferBinding {
+                    buffer: &dst_buffer,
+                    offset: 0,
+                    size: NonZeroU64::new(ARGS_SIZE),
+                }],
+                samplers: &[],
+                textures: &[],
+                entries: &[hal::BindGroupEntry {
+                    binding: 0,
+                    resource_index: 0,
+                    count: 1,
+                }],
+                acceleration_structures: &[],
+            })
+        }
+        .map_err(DeviceError::from)?;
+
+        unsafe {
+            encoder.transition_buffers(iter::once(hal::BufferBarrier {
+                buffer: &dst_buffer,
+                usage: hal::BufferUses::empty()..hal::BufferUses::INDIRECT,
+            }));
+        }
+
+        Ok(Some(Self {
+            module,
+            src_bind_group_layout,
+            dst_bind_group_layout,
+            pipeline_layout,
+            pipeline,
+            dst_buffer,
+            dst_bind_group,
+            min_storage_buffer_offset_alignment: limits.min_storage_buffer_offset_alignment as u64,
+        }))
+    }
+
+    /// Create the bind group the internal dispatch reads the arguments of
+    /// indirect dispatches from `buffer` with.
+    ///
+    /// Returns `None` if `size` is too small to hold any arguments.
+    pub(crate) fn create_src_bind_group(
+        &self,
+        device: &A::Device,
+        buffer: &A::Buffer,
+        size: wgt::BufferAddress,
+        instance_flags: wgt::InstanceFlags,
+    ) -> Result<Option<A::BindGroup>, DeviceError> {
+        if size < ARGS_SIZE {
+            return Ok(None);
+        }
+
+        let binding_size = src_binding_size(size, self.min_storage_buffer_offset_alignment);
+        let bind_group = unsafe {
+            device.create_bind_group(&hal::BindGroupDescriptor {
+                label: hal_label(
+                    Some("(wgpu internal) Indirect validation source bind group"),
+                    instance_flags,
+                ),
+                layout: &self.src_bind_group_layout,
+                buffers: &[hal::BufferBinding {
+                    buffer,
+                    offset: 0,
+                    size: NonZeroU64::new(binding_size),
+                }],
+                samplers: &[],
+                textures: &[],
+                entries: &[hal::BindGroupEntry {
+                    binding: 0,
+                    resource_index: 0,
+                    count: 1,
+                }],
+                acceleration_structures: &[],
+            })
+        }
+        .map_err(DeviceError::from)?;
+
+        Ok(Some(bind_group))
+    }
+
+    /// The buffer holding the clamped arguments after [`Self::encode`].
+    pub(crate) fn dst_buffer(&self) -> &A::Buffer {
+        &self.dst_buffer
+    }
+
+    /// Find the dynamic offset and source index [`Self::encode`] needs to read
+    /// the arguments at `offset` in a buffer of `size` bytes.
+    ///
+    /// Returns `None` if `offset` can't be reached with a dynamic offset, in
+    /// which case the dispatch can't be clamped and has to be rejected.
+    pub(crate) fn src_offsets(
+        &self,
+        size: wgt::BufferAddress,
+        offset: wgt::BufferAddress,
+    ) -> Option<(u32, u32)> {
+        let (dynamic_offset, src_index) =
+            src_offsets(size, offset, self.min_storage_buffer_offset_alignment);
+        Some((u32::try_from(dynamic_offset).ok()?, src_index))
+    }
+
+    /// Encode the internal dispatch that clamps the arguments found with
+    /// [`Self::src_offsets`], read through `src_bind_group`.
+    ///
+    /// This replaces the current compute pipeline, bind groups and push
+    /// constants, so the caller has to restore them before the user's dispatch.
+    ///
+    /// # Safety
+    ///
+    /// `encoder` must be in a compute pass, and `src_bind_group` must have been
+    /// created by [`Self::create_src_bind_group`] for a buffer that is in the
+    /// `STORAGE_READ` state.
+    pub(crate) unsafe fn encode(
+        &self,
+        encoder: &mut A::CommandEncoder,
+        src_bind_group: &A::BindGroup,
+        (dynamic_offset, src_index): (u32, u32),
+    ) {
+        unsafe {
+            encoder.transition_buffers(iter::once(hal::BufferBarrier {
+                buffer: &self.dst_buffer,
+                usage: hal::BufferUses::INDIRECT..hal::BufferUses::STORAGE_READ_WRITE,
+            }));
+            encoder.set_compute_pipeline(&self.pipeline);
+            encoder.set_push_constants(
+                &self.pipeline_layout,
+                wgt::ShaderStages::COMPUTE,
+                0,
+                &[src_index],
+            );
+            encoder.set_bind_group(&self.pipeline_layout, 0, src_bind_group, &[dynamic_offset]);
+            encoder.set_bind_group(&self.pipeline_layout, 1, &self.dst_bind_group, &[]);
+            encoder.dispatch([1, 1, 1]);
+            encoder.transition_buffers(iter::once(hal::BufferBarrier {
+                buffer: &self.dst_buffer,
+                usage: hal::BufferUses::STORAGE_READ_WRITE..hal::BufferUses::INDIRECT,
+            }));
+        }
+    }
+
+    pub(crate) fn dispose(self, device: &A::Device) {
+        unsafe {
+            device.destroy_bind_group(self.dst_bind_group);
+            device.destroy_buffer(self.dst_buffer);
+            device.destroy_compute_pipeline(self.pipeline);
+            device.destroy_pipeline_layout(self.pipeline_layout);
+            device.destroy_bind_group_layout(self.dst_bind_group_layout);
+            device.destroy_bind_group_layout(self.src_bind_group_layout);
+            device.destroy_shader_module(self.module);
+        }
+    }
+}
+
+fn storage_buffer_entry(read_only: bool, has_dynamic_offset: bool) -> wgt::BindGroupLayoutEntry {
+    wgt::BindGroupLayoutEntry {
+        binding: 0,
+        visibility: wgt::ShaderStages::COMPUTE,
+        ty: wgt::BindingType::Buffer {
+            ty: wgt::BufferBindingType::Storage { read_only },
+            has_dynamic_offset,
+            min_binding_size: NonZeroU64::new(ARGS_SIZE),
+        },
+        count: None,
+    }
+}
+
+#[cfg(feature = "wgsl")]
+fn parse_shader(max_compute_workgroups_per_dimension: u32) -> Option<hal::NagaShader> {
+    let source = format!(
+        "
+struct SrcIndex {{
+    value: u32,
+}}
+
+var<push_constant> src_index: SrcIndex;
+
+@group(0) @binding(0)
+var<storage, read> src: array<u32>;
+
+@group(1) @binding(0)
+var<storage, read_write> dst: array<u32, 3>;
+
+@compute @workgroup_size(1)
+fn main() {{
+    let limit = {max_compute_workgroups_per_dimension}u;
+    let index = src_index.value;
+    dst[0] = min(src[index], limit);
+    dst[1] = min(src[index + 1u], limit);
+    dst[2] = min(src[index + 2u], limit);
+}}
+"
+    );
+
+    let module =
+        naga::front::wgsl::parse_str(&source).expect("indirect validation shader should parse");
+    let info = naga::valid::Validator::new(
+        naga::valid::ValidationFlags::all(),
+        naga::valid::Capabilities::PUSH_CONSTANT,
+    )
+    .validate(&module)
+    .expect("indirect validation shader should be valid");
+
+    Some(hal::NagaShader {
+        module: std::borrow::Cow::Owned(module),
+        info,
+        debug_source: None,
+    })
+}
+
+#[cfg(not(feature = "wgsl"))]
+fn parse_shader(_max_compute_workgroups_per_dimension: u32) -> Option<hal::NagaShader> {
+    log::warn!("Indirect dispatches can't be clamped without the `wgsl` feature");
+    None
+}
+
+/// Size of the window of a buffer of `size` bytes the internal dispatch binds.
+///
+/// The window is at least `alignment + 8` bytes, so that a window starting at
+/// the aligned offset just before any 4-byte aligned arguments holds all of them.
+/// It's grown so that the last window ends exactly at the end of the buffer,
+/// which lets it cover arguments at the very end as well.
+fn src_binding_size(size: wgt::BufferAddress, alignment: u64) -> u64 {
+    let size = size & !3;
+    let alignment = alignment.max(4);
+    let min_binding_size = alignment + ARGS_SIZE - 4;
+    if size <= min_binding_size {
+        size
+    } else {
+        min_binding_size + (size - min_binding_size) % alignment
+    }
+}
+
+/// The dynamic offset of the window holding the arguments at `offset` in a
+/// buffer of `size` bytes, and the index of the first argument in that window.
+fn src_offsets(size: wgt::BufferAddress, offset: wgt::BufferAddress, alignment: u64) -> (u64, u32) {
+    let alignment = alignment.max(4);
+    let last_window_offset = (size & !3) - src_binding_size(size, alignment);
+    let window_offset = (offset / alignment * alignment).min(last_window_offset);
+    (window_offset, ((offset - window_offset) / 4) as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{src_binding_size, src_offsets, ARGS_SIZE};
+
+    #[test]
+    fn src_window_holds_every_argument_offset() {
+        for alignment in [4, 16, 32, 256] {
+            for size in ARGS_SIZE..3 * alignment + 24 {
+                let binding_size = src_binding_size(size, alignment);
+                for offset in (0..=size - ARGS_SIZE).step_by(4) {
+                    let (window_offset, index) = src_offsets(size, offset, alignment);
+                    assert_eq!(window_offset % alignment, 0);
+                    assert!(window_offset + binding_size <= size);
+                    assert_eq!(window_offset + index as u64 * 4, offset);
+                    assert!(index as u64 * 4 + ARGS_SIZE <= binding_size);
+                }
+            }
+        }
+    }
+}
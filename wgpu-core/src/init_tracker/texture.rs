@@ -18,7 +18,12 @@ pub(crate) fn has_copy_partial_init_tracker_coverage(
     mip_level: u32,
     desc: &wgt::TextureDescriptor<(), Vec<wgt::TextureFormat>>,
 ) -> bool {
-    let target_size = desc.mip_level_size(mip_level).unwrap();
+    // Copies of block compressed formats cover whole blocks, so at the edge of
+    // small mips they extend past the virtual size up to the physical size.
+    let target_size = desc
+        .mip_level_size(mip_level)
+        .unwrap()
+        .physical_size(desc.format);
     copy_size.width != target_size.width
         || copy_size.height != target_size.height
         || (desc.dimension == wgt::TextureDimension::D3
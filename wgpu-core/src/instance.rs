@@ -194,7 +194,7 @@ pub struct Adapter<A: HalApi> {
 }
 
 impl<A: HalApi> Adapter<A> {
-    fn new(mut raw: hal::ExposedAdapter<A>) -> Self {
+    pub(crate) fn new(mut raw: hal::ExposedAdapter<A>) -> Self {
         // WebGPU requires this offset alignment as lower bound on all adapters.
         const MIN_BUFFER_OFFSET_ALIGNMENT_LOWER_BOUND: u32 = 32;
 
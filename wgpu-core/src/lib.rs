@@ -17,6 +17,7 @@
 //! - **`replay`** --- Enable API replaying
 //! - **`serial-pass`** --- Enable serializable compute/render passes, and bundle encoders.
 //! - **`wgsl`** --- Enable `ShaderModuleSource::Wgsl`
+//! - **`glsl`** --- Enable `ShaderModuleSource::Glsl`
 //! - **`fragile-send-sync-non-atomic-wasm`** --- Implement `Send` and `Sync` on Wasm, but only if
 //!   atomics are not enabled.
 //!
@@ -88,6 +89,7 @@ mod hash_utils;
 pub mod hub;
 pub mod id;
 pub mod identity;
+mod indirect_validation;
 mod init_tracker;
 pub mod instance;
 pub mod pipeline;
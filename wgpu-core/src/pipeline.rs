@@ -26,6 +26,9 @@ pub(crate) struct LateSizedBufferGroup {
 pub enum ShaderModuleSource<'a> {
     #[cfg(feature = "wgsl")]
     Wgsl(Cow<'a, str>),
+    /// GLSL source for a single stage, with its preprocessor defines.
+    #[cfg(feature = "glsl")]
+    Glsl(Cow<'a, str>, naga::front::glsl::Options),
     Naga(Cow<'static, naga::Module>),
     /// Dummy variant because `Naga` doesn't have a lifetime and without enough active features it
     /// could be the last one active.
@@ -47,6 +50,8 @@ pub struct ShaderModule<A: HalApi> {
     pub(crate) raw: Option<A::ShaderModule>,
     pub(crate) device: Arc<Device<A>>,
     pub(crate) interface: Option<validation::Interface>,
+    /// Entry points of a passthrough SPIR-V module, which has no `interface`.
+    pub(crate) spirv_entry_points: Vec<(naga::ShaderStage, String)>,
     pub(crate) info: ResourceInfo<ShaderModuleId>,
     pub(crate) label: String,
 }
@@ -87,6 +92,25 @@ impl<A: HalApi> ShaderModule<A> {
     pub(crate) fn raw(&self) -> &A::ShaderModule {
         self.raw.as_ref().unwrap()
     }
+
+    /// Check that a module that naga didn't reflect declares `entry_point` for `stage`.
+    pub(crate) fn check_passthrough_entry_point(
+        &self,
+        entry_point: &str,
+        stage: naga::ShaderStage,
+    ) -> Result<(), validation::StageError> {
+        if self
+            .spirv_entry_points
+            .iter()
+            .any(|(ep_stage, name)| *ep_stage == stage && name == entry_point)
+        {
+            Ok(())
+        } else {
+            Err(validation::StageError::MissingEntryPoint(
+                entry_point.to_string(),
+            ))
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -95,15 +119,42 @@ pub struct ShaderError<E> {
     pub label: Option<String>,
     pub inner: Box<E>,
 }
+impl<E> ShaderError<E> {
+    /// The name of the source in the snippets quoted by error messages.
+    fn snippet_path(&self) -> &str {
+        self.label.as_deref().unwrap_or("shader")
+    }
+}
 #[cfg(feature = "wgsl")]
 impl fmt::Display for ShaderError<naga::front::wgsl::ParseError> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let label = self.label.as_deref().unwrap_or_default();
-        let string = self.inner.emit_to_string(&self.source);
+        let string = self
+            .inner
+            .emit_to_string_with_path(&self.source, self.snippet_path());
         write!(f, "\nShader '{label}' parsing {string}")
     }
 }
-impl fmt::Display for ShaderError<naga::WithSpan<naga::valid::ValidationError>> {
+/// The errors naga's GLSL frontend reports for one shader.
+#[cfg(feature = "glsl")]
+#[derive(Debug)]
+pub struct GlslParseErrors(pub Vec<naga::front::glsl::Error>);
+#[cfg(feature = "glsl")]
+impl fmt::Display for GlslParseErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, error) in self.0.iter().enumerate() {
+            if index != 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+#[cfg(feature = "glsl")]
+impl Error for GlslParseErrors {}
+#[cfg(feature = "glsl")]
+impl fmt::Display for ShaderError<GlslParseErrors> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use codespan_reporting::{
             diagnostic::{Diagnostic, Label},
@@ -112,28 +163,50 @@ impl fmt::Display for ShaderError<naga::WithSpan<naga::valid::ValidationError>>
         };
 
         let label = self.label.as_deref().unwrap_or_default();
-        let files = SimpleFile::new(label, &self.source);
+        let files = SimpleFile::new(self.snippet_path(), &self.source);
         let config = term::Config::default();
         let mut writer = term::termcolor::NoColor::new(Vec::new());
 
-        let diagnostic = Diagnostic::error().with_labels(
-            self.inner
-                .spans()
-                .map(|&(span, ref desc)| {
-                    Label::primary((), span.to_range().unwrap()).with_message(desc.to_owned())
-                })
-                .collect(),
-        );
-
-        term::emit(&mut writer, &config, &files, &diagnostic).expect("cannot write error");
+        for error in self.inner.0.iter() {
+            let mut diagnostic = Diagnostic::error().with_message(error.kind.to_string());
+            if let Some(range) = error.meta.to_range() {
+                diagnostic = diagnostic.with_labels(vec![Label::primary((), range)]);
+            }
+            term::emit(&mut writer, &config, &files, &diagnostic).expect("cannot write error");
+        }
 
         write!(
             f,
-            "\nShader validation {}",
+            "\nShader '{label}' parsing {}",
             String::from_utf8_lossy(&writer.into_inner())
         )
     }
 }
+impl fmt::Display for ShaderError<naga::WithSpan<naga::valid::ValidationError>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = self.label.as_deref().unwrap_or_default();
+        let source_is_known = self.inner.spans().all(|&(span, _)| {
+            span.to_range()
+                .map_or(false, |range| self.source.get(range).is_some())
+        });
+        if !source_is_known {
+            // Modules passed in as a `naga::Module` carry spans, but no source for them to
+            // point into.
+            let mut error: &dyn Error = self.inner.as_inner();
+            write!(f, "\nShader '{label}' validation error: {error}")?;
+            while let Some(source) = error.source() {
+                write!(f, "\n  {source}")?;
+                error = source;
+            }
+            return Ok(());
+        }
+
+        let string = self
+            .inner
+            .emit_to_string_with_path(&self.source, self.snippet_path());
+        write!(f, "\nShader '{label}' validation {string}")
+    }
+}
 impl<E> Error for ShaderError<E>
 where
     ShaderError<E>: fmt::Display,
@@ -151,6 +224,9 @@ pub enum CreateShaderModuleError {
     #[cfg(feature = "wgsl")]
     #[error(transparent)]
     Parsing(#[from] ShaderError<naga::front::wgsl::ParseError>),
+    #[cfg(feature = "glsl")]
+    #[error(transparent)]
+    ParsingGlsl(#[from] ShaderError<GlslParseErrors>),
     #[error("Failed to generate the backend-specific code")]
     Generation,
     #[error(transparent)]
@@ -174,6 +250,10 @@ impl CreateShaderModuleError {
         match *self {
             #[cfg(feature = "wgsl")]
             CreateShaderModuleError::Parsing(ref err) => err.inner.location(source),
+            #[cfg(feature = "glsl")]
+            CreateShaderModuleError::ParsingGlsl(ref err) => {
+                err.inner.0.first().map(|error| error.meta.location(source))
+            }
             CreateShaderModuleError::Validation(ref err) => err.inner.location(source),
             _ => None,
         }
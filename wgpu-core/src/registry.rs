@@ -1,9 +1,11 @@
 use std::sync::Arc;
 
 use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use thiserror::Error;
 use wgt::Backend;
 
 use crate::{
+    hash_utils::FastHashSet,
     id,
     identity::{IdentityHandlerFactory, IdentityManager},
     resource::Resource,
@@ -16,9 +18,19 @@ pub struct RegistryReport {
     pub num_kept_from_user: usize,
     pub num_released_from_user: usize,
     pub num_error: usize,
+    /// Ids that refer to the same resource as another id, such as deduplicated
+    /// bind group layouts.
+    pub num_shared: usize,
     pub element_size: usize,
+    /// Estimated memory used by the registry's storage table, in bytes.
+    pub storage_bytes: usize,
 }
 
+/// An id passed in from outside wgpu-core is already used by a live resource.
+#[derive(Clone, Debug, Error)]
+#[error("Id is already in use")]
+pub struct DuplicateId;
+
 impl RegistryReport {
     pub fn is_empty(&self) -> bool {
         self.num_allocated + self.num_kept_from_user == 0
@@ -88,6 +100,22 @@ impl<I: id::TypedId + Copy, T: Resource<I>> FutureId<'_, I, T> {
         (self.id, data.get(self.id).unwrap().clone())
     }
 
+    /// Like [`FutureId::assign`], but fails if the ID is already in use.
+    ///
+    /// Only IDs passed in from outside wgpu-core can collide. On failure the
+    /// resource already registered under the ID is left untouched.
+    #[allow(dead_code)]
+    pub fn try_assign(self, value: T) -> Result<(I, Arc<T>), DuplicateId> {
+        let mut data = self.data.write();
+        // Check before `init`: dropping an initialized `value` would free the
+        // ID that the registered resource still owns.
+        if data.contains(self.id) {
+            return Err(DuplicateId);
+        }
+        data.insert(self.id, self.init(value));
+        Ok((self.id, data.get(self.id).unwrap().clone()))
+    }
+
     /// Assign an existing resource to a new ID.
     ///
     /// Registers it with the registry.
@@ -181,6 +209,33 @@ impl<I: id::TypedId, T: Resource<I>> Registry<I, T> {
         }
     }
 
+    /// Free the memory the registry's storage keeps around for reuse.
+    pub(crate) fn shrink_to_fit(&self) {
+        self.storage.write().shrink_to_fit();
+    }
+
+    /// Call `f` with every live resource in the registry.
+    ///
+    /// The storage's read lock is held for the whole iteration, so `f` must
+    /// not try to register or unregister resources of this type.
+    pub(crate) fn for_each(&self, mut f: impl FnMut(I, &Arc<T>)) {
+        let storage = self.storage.read();
+        for (id, value) in storage.iter(self.backend) {
+            f(id, value);
+        }
+    }
+
+    /// Log the id and label of every resource still in the registry, and
+    /// return how many there are.
+    pub(crate) fn log_live(&self) -> usize {
+        let mut count = 0;
+        self.for_each(|id, value| {
+            log::warn!("{id:?} {:?} is still alive", value.label());
+            count += 1;
+        });
+        count
+    }
+
     pub(crate) fn generate_report(&self) -> RegistryReport {
         let storage = self.storage.read();
         let mut report = RegistryReport {
@@ -188,9 +243,16 @@ impl<I: id::TypedId, T: Resource<I>> Registry<I, T> {
             ..Default::default()
         };
         report.num_allocated = self.identity.values.lock().count();
-        for element in storage.map.iter() {
+        report.storage_bytes = storage.allocated_bytes();
+        let mut resources = FastHashSet::default();
+        for element in storage.elements() {
             match *element {
-                Element::Occupied(..) => report.num_kept_from_user += 1,
+                Element::Occupied(ref value, _) => {
+                    report.num_kept_from_user += 1;
+                    if !resources.insert(Arc::as_ptr(value)) {
+                        report.num_shared += 1;
+                    }
+                }
                 Element::Vacant => report.num_released_from_user += 1,
                 Element::Error(..) => report.num_error += 1,
             }
@@ -198,3 +260,91 @@ impl<I: id::TypedId, T: Resource<I>> Registry<I, T> {
         report
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        identity::{IdentityHandlerFactory, IdentityManagerFactory},
+        storage::tests::{TestId, TestResource},
+    };
+
+    /// Takes ids from the caller, the way an embedder that allocates its own
+    /// ids does.
+    struct ExternalIds;
+
+    impl IdentityHandlerFactory<TestId> for ExternalIds {
+        type Input = TestId;
+
+        fn autogenerate_ids() -> bool {
+            false
+        }
+
+        fn input_to_id(id_in: TestId) -> TestId {
+            id_in
+        }
+    }
+
+    #[test]
+    fn log_live() {
+        let registry = Registry::<TestId, TestResource>::without_backend(&IdentityManagerFactory);
+        assert_eq!(registry.log_live(), 0);
+
+        let (kept, _) = registry
+            .prepare::<IdentityManagerFactory>(())
+            .assign(TestResource::new("kept"));
+        let (released, _) = registry
+            .prepare::<IdentityManagerFactory>(())
+            .assign(TestResource::new("released"));
+        assert_eq!(registry.log_live(), 2);
+
+        registry.unregister(released);
+        assert_eq!(registry.log_live(), 1);
+        registry.unregister(kept);
+        assert_eq!(registry.log_live(), 0);
+    }
+
+    #[test]
+    fn report_shared() {
+        let registry = Registry::<TestId, TestResource>::without_backend(&IdentityManagerFactory);
+
+        let (_, value) = registry
+            .prepare::<IdentityManagerFactory>(())
+            .assign(TestResource::new("shared"));
+        let alias = registry
+            .prepare::<IdentityManagerFactory>(())
+            .assign_existing(&value);
+
+        let report = registry.generate_report();
+        assert_eq!(report.num_kept_from_user, 2);
+        assert_eq!(report.num_shared, 1);
+
+        registry.unregister(alias);
+        assert_eq!(registry.generate_report().num_shared, 0);
+    }
+
+    #[test]
+    fn try_assign_duplicate_id() {
+        use id::TypedId as _;
+
+        let registry = Registry::<TestId, TestResource>::without_backend(&ExternalIds);
+        let id = TestId::zip(0, 1, Backend::Empty);
+
+        let (first, _) = registry
+            .prepare::<ExternalIds>(id)
+            .try_assign(TestResource::new("first"))
+            .unwrap();
+        assert_eq!(first, id);
+        assert!(registry
+            .prepare::<ExternalIds>(id)
+            .try_assign(TestResource::new("second"))
+            .is_err());
+
+        // The first resource is still registered and usable.
+        let value = registry.get(id).unwrap();
+        assert_eq!(value.label(), "first");
+        assert_eq!(registry.log_live(), 1);
+        registry.unregister(id);
+        assert_eq!(registry.log_live(), 0);
+    }
+}
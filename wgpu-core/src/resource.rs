@@ -323,6 +323,7 @@ impl BufferMapCallback {
                     | Err(BufferAccessError::NegativeRange { .. }) => {
                         BufferMapAsyncStatus::InvalidRange
                     }
+                    Err(BufferAccessError::MapAborted) => BufferMapAsyncStatus::Aborted,
                     Err(_) => BufferMapAsyncStatus::Error,
                 };
 
@@ -401,6 +402,11 @@ pub type BufferDescriptor<'a> = wgt::BufferDescriptor<Label<'a>>;
 #[derive(Debug)]
 pub struct Buffer<A: HalApi> {
     pub(crate) raw: Snatchable<A::Buffer>,
+    /// Binds the buffer for clamping indirect dispatches, see
+    /// [`crate::indirect_validation`].
+    ///
+    /// This outlives `raw` if the buffer is destroyed. It isn't used after that.
+    pub(crate) indirect_validation_bind_group: Option<A::BindGroup>,
     pub(crate) device: Arc<Device<A>>,
     pub(crate) usage: wgt::BufferUsages,
     pub(crate) size: wgt::BufferAddress,
@@ -412,6 +418,13 @@ pub struct Buffer<A: HalApi> {
 
 impl<A: HalApi> Drop for Buffer<A> {
     fn drop(&mut self) {
+        if let Some(bind_group) = self.indirect_validation_bind_group.take() {
+            unsafe {
+                use hal::Device;
+                self.device.raw().destroy_bind_group(bind_group);
+            }
+        }
+
         if let Some(raw) = self.raw.take() {
             resource_log!("Destroy raw Buffer (dropped) {:?}", self.info.label());
 
@@ -448,6 +461,19 @@ impl<A: HalApi> Buffer<A> {
         Ok(())
     }
 
+    /// Unmap the buffer before it is destroyed, cancelling a pending mapping
+    /// with [`BufferAccessError::Destroyed`] rather than `MapAborted`.
+    // Note: This must not be called while holding a lock.
+    pub(crate) fn unmap_for_destroy(self: &Arc<Self>) -> Result<(), BufferAccessError> {
+        if let Some((mut operation, _)) = self.unmap_inner()? {
+            if let Some(callback) = operation.callback.take() {
+                callback.call(Err(BufferAccessError::Destroyed));
+            }
+        }
+
+        Ok(())
+    }
+
     fn unmap_inner(self: &Arc<Self>) -> Result<Option<BufferMapPendingClosure>, BufferAccessError> {
         use hal::Device;
 
@@ -602,11 +628,14 @@ pub enum CreateBufferError {
     Device(#[from] DeviceError),
     #[error("Failed to map buffer while creating: {0}")]
     AccessError(#[from] BufferAccessError),
-    #[error("Buffers that are mapped at creation have to be aligned to `COPY_BUFFER_ALIGNMENT`")]
-    UnalignedSize,
+    #[error(
+        "Buffers that are mapped at creation must have a size that is a multiple of `COPY_BUFFER_ALIGNMENT` ({}), but the size is {0}",
+        wgt::COPY_BUFFER_ALIGNMENT
+    )]
+    UnalignedSize(wgt::BufferAddress),
     #[error("Invalid usage flags {0:?}")]
     InvalidUsage(wgt::BufferUsages),
-    #[error("`MAP` usage can only be combined with the opposite `COPY`, requested {0:?}")]
+    #[error("`MAP` usage can only be combined with the opposite `COPY` unless `Features::MAPPABLE_PRIMARY_BUFFERS` is enabled, requested {0:?}")]
     UsageMismatch(wgt::BufferUsages),
     #[error("Buffer size {requested} is greater than the maximum buffer size ({maximum})")]
     MaxBufferSize { requested: u64, maximum: u64 },
@@ -1278,10 +1307,13 @@ pub enum CreateTextureViewError {
         texture_format: wgt::TextureFormat,
         requested_aspect: wgt::TextureAspect,
     },
-    #[error("Unable to view texture {texture:?} as {view:?}")]
+    #[error(
+        "Unable to view texture {texture:?} as {view:?}, the allowed view formats are {allowed:?}"
+    )]
     FormatReinterpretation {
         texture: wgt::TextureFormat,
         view: wgt::TextureFormat,
+        allowed: Vec<wgt::TextureFormat>,
     },
 }
 
@@ -1396,7 +1428,7 @@ pub enum CreateSamplerError {
     },
     #[error("Invalid anisotropic clamp: {0}. Must be at least 1.")]
     InvalidAnisotropy(u16),
-    #[error("Invalid filter mode for {filter_type:?}: {filter_mode:?}. When anistropic clamp is not 1 (it is {anisotropic_clamp}), all filter modes must be linear.")]
+    #[error("Invalid filter mode for {filter_type:?}: {filter_mode:?}. When anisotropic clamp is not 1 (it is {anisotropic_clamp}), all filter modes must be linear.")]
     InvalidFilterModeWithAnisotropy {
         filter_type: SamplerFilterErrorType,
         filter_mode: wgt::FilterMode,
@@ -1404,7 +1436,8 @@ pub enum CreateSamplerError {
     },
     #[error("Cannot create any more samplers")]
     TooManyObjects,
-    /// AddressMode::ClampToBorder requires feature ADDRESS_MODE_CLAMP_TO_BORDER.
+    /// AddressMode::ClampToBorder requires feature ADDRESS_MODE_CLAMP_TO_BORDER, or
+    /// ADDRESS_MODE_CLAMP_TO_ZERO with a zero border color.
     #[error(transparent)]
     MissingFeatures(#[from] MissingFeatures),
 }
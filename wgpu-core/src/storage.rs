@@ -4,7 +4,7 @@ use wgt::Backend;
 
 use crate::{id, resource::Resource, Epoch, Index};
 
-/// An entry in a `Storage::map` table.
+/// An entry in a `Storage` table.
 #[derive(Debug)]
 pub(crate) enum Element<T> {
     /// There are no live ids with this index.
@@ -24,18 +24,57 @@ pub(crate) enum Element<T> {
 #[derive(Clone, Debug)]
 pub(crate) struct InvalidId;
 
+/// The number of elements in each [`Page`] of a `Storage` table.
+const PAGE_SIZE: usize = 64;
+
+/// A fixed-size run of `Storage` elements.
+///
+/// Pages are boxed, so growing the table never moves existing elements.
+#[derive(Debug)]
+struct Page<T> {
+    elements: [Element<T>; PAGE_SIZE],
+
+    /// Bit `i` is set if `elements[i]` is not [`Element::Vacant`].
+    occupied: u64,
+}
+
+impl<T> Page<T> {
+    fn new() -> Box<Self> {
+        Box::new(Self {
+            elements: std::array::from_fn(|_| Element::Vacant),
+            occupied: 0,
+        })
+    }
+}
+
+/// Split a `Storage` index into a page index and an offset within that page.
+fn split_index(index: usize) -> (usize, usize) {
+    (index / PAGE_SIZE, index % PAGE_SIZE)
+}
+
 /// A table of `T` values indexed by the id type `I`.
 ///
-/// The table is represented as a vector indexed by the ids' index
-/// values, so you should use an id allocator like `IdentityManager`
-/// that keeps the index values dense and close to zero.
+/// The table is represented as a vector of fixed-size pages indexed by
+/// the ids' index values, so you should use an id allocator like
+/// `IdentityManager` that keeps the index values dense and close to zero.
+/// A page is released as soon as all of its elements are vacant.
 #[derive(Debug)]
 pub struct Storage<T, I>
 where
     T: Resource<I>,
     I: id::TypedId,
 {
-    pub(crate) map: Vec<Element<T>>,
+    /// The table's pages. Pages whose elements are all vacant are `None`.
+    pages: Vec<Option<Box<Page<T>>>>,
+
+    /// One past the highest index ever inserted into the table.
+    len: usize,
+
+    /// The most recently released page, kept so that a single resource
+    /// being created and destroyed over and over doesn't allocate a page
+    /// every time.
+    spare: Option<Box<Page<T>>>,
+
     kind: &'static str,
     _phantom: PhantomData<I>,
 }
@@ -57,7 +96,9 @@ where
 {
     pub(crate) fn new() -> Self {
         Self {
-            map: Vec::new(),
+            pages: Vec::new(),
+            len: 0,
+            spare: None,
             kind: T::TYPE,
             _phantom: PhantomData,
         }
@@ -69,10 +110,63 @@ where
     T: Resource<I>,
     I: id::TypedId,
 {
+    /// Returns the element at `index`, or `None` if `index` is past the end
+    /// of the table.
+    fn element(&self, index: usize) -> Option<&Element<T>> {
+        if index >= self.len {
+            return None;
+        }
+        let (page_index, offset) = split_index(index);
+        Some(match self.pages.get(page_index) {
+            Some(Some(page)) => &page.elements[offset],
+            _ => &Element::Vacant,
+        })
+    }
+
+    /// Returns the element at `index` for the caller to fill, growing the
+    /// table and allocating its page if needed.
+    ///
+    /// The element is marked as occupied, so the caller must not store
+    /// [`Element::Vacant`] in it.
+    fn element_to_fill(&mut self, index: usize) -> &mut Element<T> {
+        let (page_index, offset) = split_index(index);
+        if page_index >= self.pages.len() {
+            self.pages.resize_with(page_index + 1, || None);
+        }
+        self.len = self.len.max(index + 1);
+        let spare = &mut self.spare;
+        let page =
+            self.pages[page_index].get_or_insert_with(|| spare.take().unwrap_or_else(Page::new));
+        page.occupied |= 1 << offset;
+        &mut page.elements[offset]
+    }
+
+    /// Iterate over every element of the table, including vacant ones.
+    pub(crate) fn elements(&self) -> impl Iterator<Item = &Element<T>> {
+        (0..self.len).filter_map(move |index| self.element(index))
+    }
+
+    /// Remove all elements from the table, returning the ones that weren't vacant.
+    pub(crate) fn drain(&mut self) -> impl Iterator<Item = Element<T>> {
+        self.len = 0;
+        self.spare = None;
+        std::mem::take(&mut self.pages)
+            .into_iter()
+            .flatten()
+            .flat_map(|page| page.elements)
+            .filter(|element| !matches!(*element, Element::Vacant))
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.pages.clear();
+        self.len = 0;
+        self.spare = None;
+    }
+
     #[allow(dead_code)]
     pub(crate) fn contains(&self, id: I) -> bool {
         let (index, epoch, _) = id.unzip();
-        match self.map.get(index as usize) {
+        match self.element(index as usize) {
             Some(&Element::Vacant) => false,
             Some(&Element::Occupied(_, storage_epoch) | &Element::Error(storage_epoch, _)) => {
                 storage_epoch == epoch
@@ -90,7 +184,7 @@ where
     /// be in a different hub.
     pub(crate) fn try_get(&self, id: I) -> Result<Option<&Arc<T>>, InvalidId> {
         let (index, epoch, _) = id.unzip();
-        let (result, storage_epoch) = match self.map.get(index as usize) {
+        let (result, storage_epoch) = match self.element(index as usize) {
             Some(&Element::Occupied(ref v, epoch)) => (Ok(Some(v)), epoch),
             Some(&Element::Vacant) => return Ok(None),
             Some(&Element::Error(epoch, ..)) => (Err(InvalidId), epoch),
@@ -108,7 +202,7 @@ where
     /// Panics if there is an epoch mismatch, or the entry is empty.
     pub(crate) fn get(&self, id: I) -> Result<&Arc<T>, InvalidId> {
         let (index, epoch, _) = id.unzip();
-        let (result, storage_epoch) = match self.map.get(index as usize) {
+        let (result, storage_epoch) = match self.element(index as usize) {
             Some(&Element::Occupied(ref v, epoch)) => (Ok(v), epoch),
             Some(&Element::Vacant) => panic!("{}[{:?}] does not exist", self.kind, id),
             Some(&Element::Error(epoch, ..)) => (Err(InvalidId), epoch),
@@ -130,17 +224,14 @@ where
 
     pub(crate) fn label_for_invalid_id(&self, id: I) -> &str {
         let (index, _, _) = id.unzip();
-        match self.map.get(index as usize) {
+        match self.element(index as usize) {
             Some(Element::Error(_, label)) => label,
             _ => "",
         }
     }
 
     fn insert_impl(&mut self, index: usize, epoch: Epoch, element: Element<T>) {
-        if index >= self.map.len() {
-            self.map.resize_with(index + 1, || Element::Vacant);
-        }
-        match std::mem::replace(&mut self.map[index], element) {
+        match std::mem::replace(self.element_to_fill(index), element) {
             Element::Vacant => {}
             Element::Occupied(_, storage_epoch) => {
                 assert_ne!(
@@ -179,8 +270,13 @@ where
 
     pub(crate) fn replace_with_error(&mut self, id: I) -> Result<Arc<T>, InvalidId> {
         let (index, epoch, _) = id.unzip();
+        let (page_index, offset) = split_index(index as usize);
+        let page = match self.pages.get_mut(page_index) {
+            Some(Some(page)) => page,
+            _ => panic!("Cannot access vacant resource"),
+        };
         match std::mem::replace(
-            &mut self.map[index as usize],
+            &mut page.elements[offset],
             Element::Error(epoch, String::new()),
         ) {
             Element::Vacant => panic!("Cannot access vacant resource"),
@@ -195,13 +291,23 @@ where
     pub(crate) fn force_replace(&mut self, id: I, value: T) {
         log::trace!("User is replacing {}{:?}", T::TYPE, id);
         let (index, epoch, _) = id.unzip();
-        self.map[index as usize] = Element::Occupied(Arc::new(value), epoch);
+        *self.element_to_fill(index as usize) = Element::Occupied(Arc::new(value), epoch);
     }
 
     pub(crate) fn remove(&mut self, id: I) -> Option<Arc<T>> {
         log::trace!("User is removing {}{:?}", T::TYPE, id);
         let (index, epoch, _) = id.unzip();
-        match std::mem::replace(&mut self.map[index as usize], Element::Vacant) {
+        let (page_index, offset) = split_index(index as usize);
+        let page = match self.pages.get_mut(page_index) {
+            Some(Some(page)) => page,
+            _ => panic!("Cannot remove a vacant resource"),
+        };
+        let element = std::mem::replace(&mut page.elements[offset], Element::Vacant);
+        page.occupied &= !(1 << offset);
+        if page.occupied == 0 {
+            self.spare = self.pages[page_index].take();
+        }
+        match element {
             Element::Occupied(value, storage_epoch) => {
                 assert_eq!(epoch, storage_epoch);
                 Some(value)
@@ -212,22 +318,207 @@ where
     }
 
     pub(crate) fn iter(&self, backend: Backend) -> impl Iterator<Item = (I, &Arc<T>)> {
-        self.map
+        self.pages
             .iter()
             .enumerate()
-            .filter_map(move |(index, x)| match *x {
-                Element::Occupied(ref value, storage_epoch) => {
-                    Some((I::zip(index as Index, storage_epoch, backend), value))
-                }
-                _ => None,
+            .filter_map(|(page_index, page)| Some((page_index, page.as_ref()?)))
+            .flat_map(move |(page_index, page)| {
+                page.elements
+                    .iter()
+                    .enumerate()
+                    .filter_map(move |(offset, x)| match *x {
+                        Element::Occupied(ref value, storage_epoch) => {
+                            let index = page_index * PAGE_SIZE + offset;
+                            Some((I::zip(index as Index, storage_epoch, backend), value))
+                        }
+                        _ => None,
+                    })
             })
     }
 
+    /// Free the memory kept around to make future insertions cheap.
+    ///
+    /// Pages are already freed as soon as they become empty; this also
+    /// drops the spare page and trims the page table itself.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.spare = None;
+        while let Some(None) = self.pages.last() {
+            self.pages.pop();
+        }
+        self.pages.shrink_to_fit();
+    }
+
+    /// The number of element slots backed by allocated pages.
+    pub(crate) fn capacity(&self) -> usize {
+        let pages = self.pages.iter().flatten().count() + usize::from(self.spare.is_some());
+        pages * PAGE_SIZE
+    }
+
+    /// An estimate of the memory used by the table, in bytes.
+    pub(crate) fn allocated_bytes(&self) -> usize {
+        self.capacity() / PAGE_SIZE * std::mem::size_of::<Page<T>>()
+            + self.pages.capacity() * std::mem::size_of::<Option<Box<Page<T>>>>()
+    }
+
     pub(crate) fn kind(&self) -> &str {
         self.kind
     }
 
+    /// One past the highest index in use, suitable for sizing per-index tables.
     pub(crate) fn len(&self) -> usize {
-        self.map.len()
+        self.len
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::resource::ResourceInfo;
+
+    /// A minimal resource for testing `Storage` and `Registry`.
+    #[derive(Debug)]
+    pub(crate) struct TestResource {
+        info: ResourceInfo<TestId>,
+    }
+
+    pub(crate) type TestId = id::Id<TestResource>;
+
+    impl Resource<TestId> for TestResource {
+        const TYPE: &'static str = "TestResource";
+
+        fn as_info(&self) -> &ResourceInfo<TestId> {
+            &self.info
+        }
+
+        fn as_info_mut(&mut self) -> &mut ResourceInfo<TestId> {
+            &mut self.info
+        }
+    }
+
+    impl TestResource {
+        pub(crate) fn new(label: &str) -> Self {
+            Self {
+                info: ResourceInfo::new(label),
+            }
+        }
+    }
+
+    fn test_id(index: usize, epoch: Epoch) -> TestId {
+        TestId::zip(index as Index, epoch, Backend::Empty)
+    }
+
+    fn test_resource() -> Arc<TestResource> {
+        Arc::new(TestResource::new(""))
+    }
+
+    #[test]
+    fn insert_get_remove() {
+        let mut storage = Storage::<TestResource, TestId>::new();
+        let first = test_id(0, 1);
+        let far = test_id(PAGE_SIZE * 3 + 5, 1);
+
+        storage.insert(first, test_resource());
+        storage.insert(far, test_resource());
+        storage.insert_error(test_id(1, 1), "broken");
+
+        assert_eq!(storage.len(), PAGE_SIZE * 3 + 6);
+        assert!(storage.contains(first));
+        assert!(storage.contains(far));
+        assert!(!storage.contains(test_id(0, 2)));
+        assert!(!storage.contains(test_id(2, 1)));
+        assert!(!storage.contains(test_id(PAGE_SIZE, 1)));
+        assert!(storage.get(test_id(1, 1)).is_err());
+        assert_eq!(storage.label_for_invalid_id(test_id(1, 1)), "broken");
+        assert!(storage.get(test_id(PAGE_SIZE * 4, 1)).is_err());
+        assert_eq!(
+            storage.try_get(test_id(PAGE_SIZE, 1)).unwrap().map(|_| ()),
+            None
+        );
+
+        let ids: Vec<_> = storage.iter(Backend::Empty).map(|(id, _)| id).collect();
+        assert_eq!(ids, [first, far]);
+        assert_eq!(storage.elements().count(), storage.len());
+
+        assert!(storage.remove(far).is_some());
+        assert!(!storage.contains(far));
+        assert_eq!(storage.len(), PAGE_SIZE * 3 + 6);
+
+        // Indices are reused with a new epoch.
+        let reused = test_id(PAGE_SIZE * 3 + 5, 2);
+        storage.insert(reused, test_resource());
+        assert!(storage.contains(reused));
+        assert!(!storage.contains(far));
+    }
+
+    #[test]
+    #[should_panic(expected = "does not exist")]
+    fn get_vacant_in_released_page() {
+        let mut storage = Storage::<TestResource, TestId>::new();
+        let id = test_id(PAGE_SIZE + 1, 1);
+        storage.insert(test_id(PAGE_SIZE * 2, 1), test_resource());
+        storage.insert(id, test_resource());
+        storage.remove(id);
+        let _ = storage.get(id);
+    }
+
+    #[test]
+    fn empty_pages_are_released() {
+        const COUNT: usize = PAGE_SIZE * 16;
+
+        let mut storage = Storage::<TestResource, TestId>::new();
+        for index in 0..COUNT {
+            storage.insert(test_id(index, 1), test_resource());
+        }
+        assert!(storage.pages.iter().all(Option::is_some));
+
+        for index in 0..COUNT {
+            storage.remove(test_id(index, 1));
+        }
+        assert!(storage.pages.iter().all(Option::is_none));
+        assert!(storage.spare.is_some());
+        assert_eq!(storage.iter(Backend::Empty).count(), 0);
+        assert_eq!(storage.elements().count(), COUNT);
+        assert!(storage
+            .elements()
+            .all(|element| matches!(*element, Element::Vacant)));
+
+        // Inserting into a released page reuses the spare page.
+        storage.insert(test_id(0, 2), test_resource());
+        assert!(storage.spare.is_none());
+        assert!(storage.contains(test_id(0, 2)));
+    }
+
+    #[test]
+    fn shrink_to_fit() {
+        const COUNT: usize = PAGE_SIZE * 16;
+
+        let mut storage = Storage::<TestResource, TestId>::new();
+        for index in 0..COUNT {
+            storage.insert(test_id(index, 1), test_resource());
+        }
+        assert_eq!(storage.capacity(), COUNT);
+        let full_bytes = storage.allocated_bytes();
+
+        // Keep one element alive in the first page.
+        for index in 1..COUNT {
+            storage.remove(test_id(index, 1));
+        }
+        assert_eq!(storage.capacity(), PAGE_SIZE * 2);
+
+        storage.shrink_to_fit();
+        assert_eq!(storage.capacity(), PAGE_SIZE);
+        assert_eq!(storage.pages.len(), 1);
+        assert!(storage.allocated_bytes() < full_bytes / 8);
+
+        // Lookups past the trimmed page table behave as before.
+        assert_eq!(storage.len(), COUNT);
+        assert!(storage.contains(test_id(0, 1)));
+        assert!(!storage.contains(test_id(COUNT - 1, 1)));
+        assert_eq!(
+            storage.try_get(test_id(COUNT - 1, 1)).unwrap().map(|_| ()),
+            None
+        );
+        storage.insert(test_id(COUNT - 1, 2), test_resource());
+        assert!(storage.contains(test_id(COUNT - 1, 2)));
     }
 }
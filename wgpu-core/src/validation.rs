@@ -219,6 +219,8 @@ pub enum FilteringError {
     Integer,
     #[error("Non-filterable float textures can't be sampled with a filtering sampler")]
     Float,
+    #[error("Depth textures can only be sampled with a comparison or non-filtering sampler")]
+    Depth,
 }
 
 #[derive(Clone, Debug, Error)]
@@ -775,6 +777,56 @@ pub fn check_texture_format(
     }
 }
 
+/// Scan the entry points declared by a SPIR-V module.
+///
+/// This is used for passthrough modules, which naga never parses. Entry points of stages wgpu
+/// doesn't support are skipped, and a malformed module yields the entry points found so far.
+pub(crate) fn spirv_entry_points(words: &[u32]) -> Vec<(naga::ShaderStage, String)> {
+    const MAGIC: u32 = 0x0723_0203;
+    const HEADER_LEN: usize = 5;
+    const OP_ENTRY_POINT: u32 = 15;
+    const OP_FUNCTION: u32 = 54;
+
+    let mut entry_points = Vec::new();
+    if words.first() != Some(&MAGIC) {
+        return entry_points;
+    }
+
+    let mut offset = HEADER_LEN;
+    while let Some(&first) = words.get(offset) {
+        let word_count = (first >> 16) as usize;
+        let opcode = first & 0xFFFF;
+        // Entry points are declared before any function.
+        if word_count == 0 || opcode == OP_FUNCTION {
+            break;
+        }
+        let Some(operands) = words.get(offset + 1..offset + word_count) else {
+            break;
+        };
+        offset += word_count;
+
+        if opcode != OP_ENTRY_POINT || operands.len() < 3 {
+            continue;
+        }
+        let stage = match operands[0] {
+            0 => naga::ShaderStage::Vertex,
+            4 => naga::ShaderStage::Fragment,
+            5 => naga::ShaderStage::Compute,
+            _ => continue,
+        };
+        // The name is a nul-terminated UTF-8 string packed little-endian into words.
+        let bytes: Vec<u8> = operands[2..]
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .take_while(|&byte| byte != 0)
+            .collect();
+        if let Ok(name) = String::from_utf8(bytes) {
+            entry_points.push((stage, name));
+        }
+    }
+    entry_points
+}
+
 pub enum BindingLayoutSource<'a> {
     /// The binding layout is derived from the pipeline layout.
     ///
@@ -1075,6 +1127,7 @@ impl Interface {
                     }
                     (true, wgt::TextureSampleType::Sint) => Some(FilteringError::Integer),
                     (true, wgt::TextureSampleType::Uint) => Some(FilteringError::Integer),
+                    (true, wgt::TextureSampleType::Depth) => Some(FilteringError::Depth),
                     _ => None,
                 };
 
@@ -1246,3 +1299,53 @@ impl Interface {
             .map(|ep| ep.dual_source_blending)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spirv_entry_points_scan() {
+        let words = [
+            0x0723_0203,
+            0x0001_0000,
+            0,
+            4,
+            0,
+            // OpCapability Shader
+            0x0002_0011,
+            1,
+            // OpMemoryModel Logical GLSL450
+            0x0003_000E,
+            0,
+            1,
+            // OpEntryPoint GLCompute %1 "main"
+            0x0005_000F,
+            5,
+            1,
+            u32::from_le_bytes(*b"main"),
+            0,
+            // OpEntryPoint Fragment %2 "fs"
+            0x0004_000F,
+            4,
+            2,
+            u32::from_le_bytes(*b"fs\0\0"),
+            // OpEntryPoint Geometry %3 "gs"
+            0x0004_000F,
+            3,
+            3,
+            u32::from_le_bytes(*b"gs\0\0"),
+        ];
+
+        assert_eq!(
+            spirv_entry_points(&words),
+            [
+                (naga::ShaderStage::Compute, "main".to_string()),
+                (naga::ShaderStage::Fragment, "fs".to_string()),
+            ]
+        );
+        // A truncated instruction stops the scan.
+        assert!(spirv_entry_points(&words[..14]).is_empty());
+        assert!(spirv_entry_points(&[]).is_empty());
+    }
+}
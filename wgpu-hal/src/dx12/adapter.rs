@@ -390,6 +390,10 @@ impl super::Adapter {
                     // store buffer sizes using 32 bit ints (a situation we have already encountered with vulkan).
                     max_buffer_size: i32::MAX as u64,
                     max_non_sampler_bindings: 1_000_000,
+                    max_binding_array_elements: match options.ResourceBindingTier {
+                        d3d12_ty::D3D12_RESOURCE_BINDING_TIER_1 => 128,
+                        _ => full_heap_count,
+                    },
                 },
                 alignments: crate::Alignments {
                     buffer_copy_offset: wgt::BufferSize::new(
@@ -753,6 +753,8 @@ impl super::Adapter {
             max_compute_workgroups_per_dimension,
             max_buffer_size: i32::MAX as u64,
             max_non_sampler_bindings: std::u32::MAX,
+            // Binding arrays aren't supported.
+            max_binding_array_elements: 0,
         };
 
         let mut workarounds = super::Workarounds::empty();
@@ -231,7 +231,10 @@ impl super::Device {
         use naga::proc::BoundsCheckPolicy;
         // The image bounds checks require the TEXTURE_LEVELS feature available in GL core 4.3+.
         let version = gl.version();
-        let image_check = if !version.is_embedded && (version.major, version.minor) >= (4, 3) {
+        let image_check = if stage.module.runtime_checks
+            && !version.is_embedded
+            && (version.major, version.minor) >= (4, 3)
+        {
             BoundsCheckPolicy::ReadZeroSkipWrite
         } else {
             BoundsCheckPolicy::Unchecked
@@ -1253,6 +1256,7 @@ impl crate::Device<super::Api> for super::Device {
             },
             label: desc.label.map(|str| str.to_string()),
             id: self.shared.next_shader_id.fetch_add(1, Ordering::Relaxed),
+            runtime_checks: desc.runtime_checks,
         })
     }
     unsafe fn destroy_shader_module(&self, _module: super::ShaderModule) {}
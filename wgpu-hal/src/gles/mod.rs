@@ -478,6 +478,7 @@ pub struct ShaderModule {
     naga: crate::NagaShader,
     label: Option<String>,
     id: ShaderId,
+    runtime_checks: bool,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -6,7 +6,10 @@ use parking_lot::Mutex;
 use std::{
     collections::BTreeMap,
     ffi::CStr,
-    sync::{atomic::AtomicIsize, Arc},
+    sync::{
+        atomic::{AtomicIsize, AtomicUsize},
+        Arc,
+    },
 };
 
 fn depth_stencil_required_flags() -> vk::FormatFeatureFlags {
@@ -827,6 +830,12 @@ impl PhysicalDeviceCapabilities {
                 u64::MAX
             };
 
+        // Binding arrays are mostly of sampled textures or storage buffers. Bind group layouts
+        // aren't created with `UPDATE_AFTER_BIND`, so the plain per-stage limits apply.
+        let max_binding_array_elements = limits
+            .max_per_stage_descriptor_sampled_images
+            .min(limits.max_per_stage_descriptor_storage_buffers);
+
         wgt::Limits {
             max_texture_dimension_1d: limits.max_image_dimension1_d,
             max_texture_dimension_2d: limits.max_image_dimension2_d,
@@ -870,6 +879,7 @@ impl PhysicalDeviceCapabilities {
             max_compute_workgroups_per_dimension,
             max_buffer_size,
             max_non_sampler_bindings: std::u32::MAX,
+            max_binding_array_elements,
         }
     }
 
@@ -1519,6 +1529,7 @@ impl super::Adapter {
             workarounds: self.workarounds,
             render_passes: Mutex::new(Default::default()),
             framebuffers: Mutex::new(Default::default()),
+            memory_allocations: AtomicUsize::new(0),
         });
         let mut relay_semaphores = [vk::Semaphore::null(); 2];
         for sem in relay_semaphores.iter_mut() {
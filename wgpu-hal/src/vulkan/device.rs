@@ -10,7 +10,7 @@ use std::{
     ffi::{CStr, CString},
     num::NonZeroU32,
     ptr,
-    sync::Arc,
+    sync::{atomic::Ordering, Arc},
 };
 
 impl super::DeviceShared {
@@ -325,7 +325,10 @@ impl gpu_alloc::MemoryDevice<vk::DeviceMemory> for super::DeviceShared {
         }
 
         match unsafe { self.raw.allocate_memory(&info, None) } {
-            Ok(memory) => Ok(memory),
+            Ok(memory) => {
+                self.memory_allocations.fetch_add(1, Ordering::Relaxed);
+                Ok(memory)
+            }
             Err(vk::Result::ERROR_OUT_OF_DEVICE_MEMORY) => {
                 Err(gpu_alloc::OutOfMemory::OutOfDeviceMemory)
             }
@@ -339,6 +342,7 @@ impl gpu_alloc::MemoryDevice<vk::DeviceMemory> for super::DeviceShared {
 
     unsafe fn deallocate_memory(&self, memory: vk::DeviceMemory) {
         unsafe { self.raw.free_memory(memory, None) };
+        self.memory_allocations.fetch_sub(1, Ordering::Relaxed);
     }
 
     unsafe fn map_memory(
@@ -819,6 +823,14 @@ impl super::Device {
     pub fn shared_instance(&self) -> &super::InstanceShared {
         &self.shared.instance
     }
+
+    /// Returns the number of live `VkDeviceMemory` allocations backing resources.
+    ///
+    /// Small buffers and textures are sub-allocated out of shared memory blocks,
+    /// so this is usually far below the number of resources.
+    pub fn memory_allocation_count(&self) -> usize {
+        self.shared.memory_allocations.load(Ordering::Relaxed)
+    }
 }
 
 impl crate::Device<super::Api> for super::Device {
@@ -857,6 +869,17 @@ impl crate::Device<super::Api> for super::Device {
                 gpu_alloc::UsageFlags::UPLOAD,
                 desc.usage.contains(crate::BufferUses::MAP_WRITE),
             );
+            // Buffers that are mapped and also used directly by the GPU (with
+            // `MAPPABLE_PRIMARY_BUFFERS`) prefer memory that is both host visible
+            // and device local, which UMA devices provide.
+            flags.set(
+                gpu_alloc::UsageFlags::FAST_DEVICE_ACCESS,
+                !(crate::BufferUses::MAP_READ
+                    | crate::BufferUses::MAP_WRITE
+                    | crate::BufferUses::COPY_SRC
+                    | crate::BufferUses::COPY_DST)
+                    .contains(desc.usage),
+            );
             flags
         } else {
             gpu_alloc::UsageFlags::FAST_DEVICE_ACCESS
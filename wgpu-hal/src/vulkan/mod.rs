@@ -37,7 +37,7 @@ use std::{
     fmt,
     num::NonZeroU32,
     sync::{
-        atomic::{AtomicIsize, Ordering},
+        atomic::{AtomicIsize, AtomicUsize, Ordering},
         Arc,
     },
 };
@@ -332,6 +332,8 @@ struct DeviceShared {
     workarounds: Workarounds,
     render_passes: Mutex<rustc_hash::FxHashMap<RenderPassKey, vk::RenderPass>>,
     framebuffers: Mutex<rustc_hash::FxHashMap<FramebufferKey, vk::Framebuffer>>,
+    /// Number of live `VkDeviceMemory` allocations made through `gpu_alloc`.
+    memory_allocations: AtomicUsize,
 }
 
 pub struct Device {
@@ -300,7 +300,8 @@ bitflags::bitflags! {
 
         /// Allows shaders to acquire the FP16 ability
         ///
-        /// Note: this is not supported in `naga` yet，only through `spirv-passthrough` right now.
+        /// Note: this is not supported in `naga` yet, only through `spirv-passthrough` right now.
+        /// WGSL shaders may contain `enable f16;`, but `f16` types are rejected.
         ///
         /// Supported Platforms:
         /// - Vulkan
@@ -360,6 +361,11 @@ bitflags::bitflags! {
 
         /// Allows for explicit creation of textures of format [`TextureFormat::Depth32FloatStencil8`]
         ///
+        /// The depth aspect copies to and from buffers as 4 byte floats and the stencil aspect as
+        /// single bytes, each with its own [`TextureAspect`]. Copies of [`TextureAspect::All`]
+        /// between buffers and textures are rejected. The depth aspect can be sampled through a
+        /// view with [`TextureAspect::DepthOnly`].
+        ///
         /// Supported platforms:
         /// - Vulkan (mostly)
         /// - DX12
@@ -619,11 +625,20 @@ bitflags::bitflags! {
         const UNIFORM_BUFFER_AND_STORAGE_TEXTURE_ARRAY_NON_UNIFORM_INDEXING = 1 << 39;
         /// Allows the user to create bind groups containing arrays with less bindings than the BindGroupLayout.
         ///
+        /// Shaders must not access the array elements that aren't bound.
+        ///
+        /// Supported platforms:
+        /// - Vulkan 1.2+ (or VK_EXT_descriptor_indexing)'s descriptorBindingPartiallyBound feature
+        ///
+        /// DX12 doesn't support it yet.
+        ///
         /// This is a native only feature.
         const PARTIALLY_BOUND_BINDING_ARRAY = 1 << 40;
-        /// Allows the user to call [`RenderPass::multi_draw_indirect`] and [`RenderPass::multi_draw_indexed_indirect`].
+        /// Enables native support for [`RenderPass::multi_draw_indirect`] and [`RenderPass::multi_draw_indexed_indirect`].
         ///
-        /// Allows multiple indirect calls to be dispatched from a single buffer.
+        /// Allows multiple indirect calls to be dispatched from a single buffer with one backend call.
+        /// Without this feature, those functions are still available, but are emulated with one
+        /// indirect draw per record.
         ///
         /// Supported platforms:
         /// - DX12
@@ -840,6 +855,12 @@ bitflags::bitflags! {
         /// Allows two outputs from a shader to be used for blending.
         /// Note that dual-source blending doesn't support multiple render targets.
         ///
+        /// In WGSL, both outputs use `@location(0)`, and the second one is marked with
+        /// `@blend_src(1)` (or naga's older `@second_blend_source`), which needs
+        /// `enable dual_source_blending;` at the start of the shader. The [`BlendFactor`]s
+        /// that read it can only be used on the first color target, and only with a fragment
+        /// shader that writes the second output.
+        ///
         /// For more info see the OpenGL ES extension GL_EXT_blend_func_extended.
         ///
         /// Supported platforms:
@@ -890,6 +911,22 @@ bitflags::bitflags! {
         /// This mainly applies to a Vulkan driver's compliance version. If the major compliance version
         /// is `0`, then the driver is ignored. This flag allows that driver to be enabled for testing.
         const ALLOW_UNDERLYING_NONCOMPLIANT_ADAPTER = 1 << 3;
+        /// Clamp the workgroup counts of indirect dispatches to
+        /// [`Limits::max_compute_workgroups_per_dimension`].
+        ///
+        /// The counts are read from GPU memory, so they can't be validated when the pass is
+        /// recorded. With this flag, each indirect dispatch is preceded by a small internal
+        /// dispatch that copies its counts, clamped to the limit, into a buffer the actual
+        /// dispatch reads instead. Without it, counts above the limit are passed to the driver
+        /// as is, which can hang or lose the device.
+        ///
+        /// Every buffer with [`BufferUsages::INDIRECT`] is then also created as a read-only
+        /// storage buffer, with a bind group for the internal dispatch. Indirect dispatches from
+        /// an offset that a dynamic offset can't reach are rejected.
+        ///
+        /// Requires a device that supports compute shaders, indirect execution and push
+        /// constants, and the `wgsl` feature of `wgpu-core`. It's ignored otherwise.
+        const VALIDATION_INDIRECT_CALL = 1 << 4;
     }
 }
 
@@ -945,6 +982,9 @@ impl InstanceFlags {
         if let Some(bit) = env("WGPU_ALLOW_UNDERLYING_NONCOMPLIANT_ADAPTER") {
             self.set(Self::ALLOW_UNDERLYING_NONCOMPLIANT_ADAPTER, bit);
         }
+        if let Some(bit) = env("WGPU_VALIDATION_INDIRECT_CALL") {
+            self.set(Self::VALIDATION_INDIRECT_CALL, bit);
+        }
 
         self
     }
@@ -1087,10 +1127,27 @@ pub struct Limits {
     /// This limit only affects the d3d12 backend. Using a large number will allow the device
     /// to create many bind groups at the cost of a large up-front allocation at device creation.
     pub max_non_sampler_bindings: u32,
+    /// Maximum number of elements in a binding array, that is the largest
+    /// [`BindGroupLayoutEntry::count`]. Defaults to 0. Higher is "better".
+    ///
+    /// Binding arrays require [`Features::TEXTURE_BINDING_ARRAY`] or
+    /// [`Features::BUFFER_BINDING_ARRAY`], so this has to be requested along with them.
+    /// The elements of an array still count towards the per-stage limits of their binding
+    /// type, such as `max_sampled_textures_per_shader_stage`.
+    pub max_binding_array_elements: u32,
 }
 
 impl Default for Limits {
     fn default() -> Self {
+        Self::defaults()
+    }
+}
+
+impl Limits {
+    /// The default limits, usable in `const` contexts.
+    ///
+    /// Same as [`Limits::default`]; these are the limits guaranteed by the WebGPU specification.
+    pub const fn defaults() -> Self {
         Self {
             max_texture_dimension_1d: 8192,
             max_texture_dimension_2d: 8192,
@@ -1122,11 +1179,10 @@ impl Default for Limits {
             max_compute_workgroups_per_dimension: 65535,
             max_push_constant_size: 0,
             max_non_sampler_bindings: 1_000_000,
+            max_binding_array_elements: 0,
         }
     }
-}
 
-impl Limits {
     /// These default limits are guaranteed to be compatible with GLES-3.1, and D3D11
     ///
     /// Those limits are as follows (different from default are marked with *):
@@ -1163,9 +1219,10 @@ impl Limits {
     ///     max_compute_workgroups_per_dimension: 65535,
     ///     max_buffer_size: 256 << 20, // (256 MiB)
     ///     max_non_sampler_bindings: 1_000_000,
+    ///     max_binding_array_elements: 0,
     /// });
     /// ```
-    pub fn downlevel_defaults() -> Self {
+    pub const fn downlevel_defaults() -> Self {
         Self {
             max_texture_dimension_1d: 2048,
             max_texture_dimension_2d: 2048,
@@ -1197,6 +1254,7 @@ impl Limits {
             max_compute_workgroups_per_dimension: 65535,
             max_buffer_size: 256 << 20,
             max_non_sampler_bindings: 1_000_000,
+            max_binding_array_elements: 0,
         }
     }
 
@@ -1237,9 +1295,10 @@ impl Limits {
     ///     max_compute_workgroups_per_dimension: 0, // +
     ///     max_buffer_size: 256 << 20, // (256 MiB),
     ///     max_non_sampler_bindings: 1_000_000,
+    ///     max_binding_array_elements: 0,
     /// });
     /// ```
-    pub fn downlevel_webgl2_defaults() -> Self {
+    pub const fn downlevel_webgl2_defaults() -> Self {
         Self {
             max_uniform_buffers_per_shader_stage: 11,
             max_storage_buffers_per_shader_stage: 0,
@@ -1357,6 +1416,7 @@ impl Limits {
         compare!(max_compute_workgroups_per_dimension, Less);
         compare!(max_buffer_size, Less);
         compare!(max_non_sampler_bindings, Less);
+        compare!(max_binding_array_elements, Less);
     }
 }
 
@@ -1976,6 +2036,12 @@ impl From<TextureFormat> for ColorTargetState {
     }
 }
 
+impl From<TextureFormat> for Option<ColorTargetState> {
+    fn from(format: TextureFormat) -> Self {
+        Some(format.into())
+    }
+}
+
 /// Primitive type the input mesh is composed of.
 ///
 /// Corresponds to [WebGPU `GPUPrimitiveTopology`](
@@ -2076,7 +2142,7 @@ pub enum PolygonMode {
 /// Corresponds to [WebGPU `GPUPrimitiveState`](
 /// https://gpuweb.github.io/gpuweb/#dictdef-gpuprimitivestate).
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "trace", derive(Serialize))]
 #[cfg_attr(feature = "replay", derive(Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
@@ -2112,6 +2178,27 @@ pub struct PrimitiveState {
     pub conservative: bool,
 }
 
+impl PrimitiveState {
+    /// The default primitive state, usable in `const` contexts.
+    ///
+    /// Same as [`PrimitiveState::default`].
+    pub const DEFAULT: Self = PrimitiveState {
+        topology: PrimitiveTopology::TriangleList,
+        strip_index_format: None,
+        front_face: FrontFace::Ccw,
+        cull_mode: None,
+        unclipped_depth: false,
+        polygon_mode: PolygonMode::Fill,
+        conservative: false,
+    };
+}
+
+impl Default for PrimitiveState {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 /// Describes the multi-sampling state of a render pipeline.
 ///
 /// Corresponds to [WebGPU `GPUMultisampleState`](
@@ -2137,13 +2224,20 @@ pub struct MultisampleState {
     pub alpha_to_coverage_enabled: bool,
 }
 
+impl MultisampleState {
+    /// The default multisample state, usable in `const` contexts.
+    ///
+    /// Same as [`MultisampleState::default`].
+    pub const DEFAULT: Self = MultisampleState {
+        count: 1,
+        mask: !0,
+        alpha_to_coverage_enabled: false,
+    };
+}
+
 impl Default for MultisampleState {
     fn default() -> Self {
-        MultisampleState {
-            count: 1,
-            mask: !0,
-            alpha_to_coverage_enabled: false,
-        }
+        Self::DEFAULT
     }
 }
 
@@ -4246,6 +4340,36 @@ fn texture_format_deserialize() {
     );
 }
 
+#[test]
+fn texture_format_etc2_block_info() {
+    use TextureFormat as Tf;
+
+    for (format, block_size) in [
+        (Tf::Etc2Rgb8Unorm, 8),
+        (Tf::Etc2Rgb8UnormSrgb, 8),
+        (Tf::Etc2Rgb8A1Unorm, 8),
+        (Tf::Etc2Rgb8A1UnormSrgb, 8),
+        (Tf::Etc2Rgba8Unorm, 16),
+        (Tf::Etc2Rgba8UnormSrgb, 16),
+        (Tf::EacR11Unorm, 8),
+        (Tf::EacR11Snorm, 8),
+        (Tf::EacRg11Unorm, 16),
+        (Tf::EacRg11Snorm, 16),
+    ] {
+        assert_eq!(format.block_dimensions(), (4, 4), "{format:?}");
+        assert_eq!(format.block_copy_size(None), Some(block_size), "{format:?}");
+        assert_eq!(
+            format.required_features(),
+            Features::TEXTURE_COMPRESSION_ETC2,
+            "{format:?}"
+        );
+        assert!(!format
+            .guaranteed_format_features(Features::empty())
+            .allowed_usages
+            .intersects(TextureUsages::RENDER_ATTACHMENT | TextureUsages::STORAGE_BINDING));
+    }
+}
+
 bitflags::bitflags! {
     /// Color write mask. Disabled color channels will not be written to.
     ///
@@ -4321,7 +4445,7 @@ impl<T> Maintain<T> {
 /// Corresponds to a portion of [WebGPU `GPUDepthStencilState`](
 /// https://gpuweb.github.io/gpuweb/#dictdef-gpudepthstencilstate).
 #[repr(C)]
-#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "trace", derive(Serialize))]
 #[cfg_attr(feature = "replay", derive(Deserialize))]
 pub struct StencilState {
@@ -4336,6 +4460,16 @@ pub struct StencilState {
 }
 
 impl StencilState {
+    /// The default stencil state, usable in `const` contexts.
+    ///
+    /// Same as [`StencilState::default`].
+    pub const DEFAULT: Self = StencilState {
+        front: StencilFaceState::IGNORE,
+        back: StencilFaceState::IGNORE,
+        read_mask: 0,
+        write_mask: 0,
+    };
+
     /// Returns true if the stencil test is enabled.
     pub fn is_enabled(&self) -> bool {
         (self.front != StencilFaceState::IGNORE || self.back != StencilFaceState::IGNORE)
@@ -4362,6 +4496,12 @@ impl StencilState {
     }
 }
 
+impl Default for StencilState {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 /// Describes the biasing setting for the depth target.
 ///
 /// For use in [`DepthStencilState`].
@@ -4369,7 +4509,7 @@ impl StencilState {
 /// Corresponds to a portion of [WebGPU `GPUDepthStencilState`](
 /// https://gpuweb.github.io/gpuweb/#dictdef-gpudepthstencilstate).
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "trace", derive(Serialize))]
 #[cfg_attr(feature = "replay", derive(Deserialize))]
 pub struct DepthBiasState {
@@ -4382,12 +4522,27 @@ pub struct DepthBiasState {
 }
 
 impl DepthBiasState {
+    /// The default depth bias state (no biasing), usable in `const` contexts.
+    ///
+    /// Same as [`DepthBiasState::default`].
+    pub const DEFAULT: Self = DepthBiasState {
+        constant: 0,
+        slope_scale: 0.0,
+        clamp: 0.0,
+    };
+
     /// Returns true if the depth biasing is enabled.
     pub fn is_enabled(&self) -> bool {
         self.constant != 0 || self.slope_scale != 0.0
     }
 }
 
+impl Default for DepthBiasState {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 impl Hash for DepthBiasState {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.constant.hash(state);
@@ -6309,6 +6464,52 @@ pub struct BindGroupLayoutEntry {
     pub count: Option<NonZeroU32>,
 }
 
+impl BindGroupLayoutEntry {
+    /// Creates a non-array entry for a buffer binding of type `ty`, without a dynamic offset
+    /// or minimum binding size.
+    pub const fn buffer(binding: u32, visibility: ShaderStages, ty: BufferBindingType) -> Self {
+        Self {
+            binding,
+            visibility,
+            ty: BindingType::Buffer {
+                ty,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        }
+    }
+
+    /// Creates a non-array entry for a non-multisampled texture binding.
+    pub const fn texture(
+        binding: u32,
+        visibility: ShaderStages,
+        sample_type: TextureSampleType,
+        view_dimension: TextureViewDimension,
+    ) -> Self {
+        Self {
+            binding,
+            visibility,
+            ty: BindingType::Texture {
+                sample_type,
+                view_dimension,
+                multisampled: false,
+            },
+            count: None,
+        }
+    }
+
+    /// Creates a non-array entry for a sampler binding of type `ty`.
+    pub const fn sampler(binding: u32, visibility: ShaderStages, ty: SamplerBindingType) -> Self {
+        Self {
+            binding,
+            visibility,
+            ty: BindingType::Sampler(ty),
+            count: None,
+        }
+    }
+}
+
 /// View of a buffer which can be used to copy to/from a texture.
 ///
 /// Corresponds to [WebGPU `GPUImageCopyBuffer`](
@@ -6728,6 +6929,10 @@ bitflags::bitflags! {
 impl_bitflags!(PipelineStatisticsTypes);
 
 /// Argument buffer layout for draw_indirect commands.
+///
+/// This is exactly the layout the GPU reads, so consecutive draws of a
+/// `multi_draw_indirect` call are `size_of::<DrawIndirectArgs>()` (16) bytes apart.
+/// Validation uses the same size to bounds check indirect buffers.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Default)]
 pub struct DrawIndirectArgs {
@@ -6756,6 +6961,13 @@ impl DrawIndirectArgs {
 }
 
 /// Argument buffer layout for draw_indexed_indirect commands.
+///
+/// This is exactly the layout the GPU reads, so consecutive draws of a
+/// `multi_draw_indexed_indirect` call are `size_of::<DrawIndexedIndirectArgs>()` (20)
+/// bytes apart. Validation uses the same size to bounds check indirect buffers.
+///
+/// Note that `first_index` comes before `base_vertex`, unlike the arguments of
+/// `draw_indexed`.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Default)]
 pub struct DrawIndexedIndirectArgs {
@@ -6786,6 +6998,9 @@ impl DrawIndexedIndirectArgs {
 }
 
 /// Argument buffer layout for dispatch_indirect commands.
+///
+/// This is exactly the layout the GPU reads, `size_of::<DispatchIndirectArgs>()` (12)
+/// bytes. Validation uses the same size to bounds check indirect buffers.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Default)]
 pub struct DispatchIndirectArgs {
@@ -7035,3 +7250,139 @@ pub enum DeviceLostReason {
     /// After Device::destroy
     Destroyed = 1,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_target_state_from_format() {
+        let verbose = ColorTargetState {
+            format: TextureFormat::Bgra8UnormSrgb,
+            blend: None,
+            write_mask: ColorWrites::ALL,
+        };
+
+        let state: ColorTargetState = TextureFormat::Bgra8UnormSrgb.into();
+        assert_eq!(state, verbose);
+
+        let state: Option<ColorTargetState> = TextureFormat::Bgra8UnormSrgb.into();
+        assert_eq!(state, Some(verbose));
+    }
+
+    #[test]
+    fn blend_state_constants() {
+        assert_eq!(
+            BlendState::ALPHA_BLENDING,
+            BlendState {
+                color: BlendComponent {
+                    src_factor: BlendFactor::SrcAlpha,
+                    dst_factor: BlendFactor::OneMinusSrcAlpha,
+                    operation: BlendOperation::Add,
+                },
+                alpha: BlendComponent::OVER,
+            }
+        );
+        assert_eq!(
+            BlendState::PREMULTIPLIED_ALPHA_BLENDING,
+            BlendState {
+                color: BlendComponent::OVER,
+                alpha: BlendComponent::OVER,
+            }
+        );
+        assert_eq!(
+            BlendState::REPLACE,
+            BlendState {
+                color: BlendComponent::REPLACE,
+                alpha: BlendComponent::REPLACE,
+            }
+        );
+    }
+
+    #[test]
+    fn const_defaults() {
+        const PRIMITIVE: PrimitiveState = PrimitiveState::DEFAULT;
+        const MULTISAMPLE: MultisampleState = MultisampleState::DEFAULT;
+        const DEPTH_BIAS: DepthBiasState = DepthBiasState::DEFAULT;
+        const STENCIL: StencilState = StencilState::DEFAULT;
+        const LIMITS: Limits = Limits::defaults();
+        const DOWNLEVEL_LIMITS: Limits = Limits::downlevel_defaults();
+        const WEBGL2_LIMITS: Limits = Limits::downlevel_webgl2_defaults();
+
+        assert_eq!(
+            PRIMITIVE,
+            PrimitiveState {
+                topology: PrimitiveTopology::default(),
+                strip_index_format: None,
+                front_face: FrontFace::default(),
+                cull_mode: None,
+                unclipped_depth: false,
+                polygon_mode: PolygonMode::default(),
+                conservative: false,
+            }
+        );
+        assert_eq!(PRIMITIVE, PrimitiveState::default());
+        assert_eq!(MULTISAMPLE, MultisampleState::default());
+        assert_eq!(DEPTH_BIAS, DepthBiasState::default());
+        assert!(!DEPTH_BIAS.is_enabled());
+        assert_eq!(
+            STENCIL,
+            StencilState {
+                front: StencilFaceState::default(),
+                back: StencilFaceState::default(),
+                read_mask: 0,
+                write_mask: 0,
+            }
+        );
+        assert_eq!(STENCIL, StencilState::default());
+        assert_eq!(LIMITS, Limits::default());
+        assert_eq!(DOWNLEVEL_LIMITS, Limits::downlevel_defaults());
+        assert_eq!(WEBGL2_LIMITS, Limits::downlevel_webgl2_defaults());
+    }
+
+    #[test]
+    fn bind_group_layout_entry_constructors() {
+        const ENTRIES: &[BindGroupLayoutEntry] = &[
+            BindGroupLayoutEntry::buffer(0, ShaderStages::VERTEX, BufferBindingType::Uniform),
+            BindGroupLayoutEntry::texture(
+                1,
+                ShaderStages::FRAGMENT,
+                TextureSampleType::Float { filterable: true },
+                TextureViewDimension::D2,
+            ),
+            BindGroupLayoutEntry::sampler(2, ShaderStages::FRAGMENT, SamplerBindingType::Filtering),
+        ];
+
+        assert_eq!(
+            ENTRIES,
+            &[
+                BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: ShaderStages::VERTEX,
+                    ty: BindingType::Buffer {
+                        ty: BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Texture {
+                        sample_type: TextureSampleType::Float { filterable: true },
+                        view_dimension: TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ]
+        );
+    }
+}
@@ -3,11 +3,13 @@
 use crate::{
     context::{ObjectId, Unused},
     AdapterInfo, BindGroupDescriptor, BindGroupLayoutDescriptor, BindingResource, BufferBinding,
-    BufferDescriptor, CommandEncoderDescriptor, ComputePassDescriptor, ComputePipelineDescriptor,
+    BufferDescriptor, CommandEncoderDescriptor, CompilationInfo, CompilationMessage,
+    CompilationMessageType, ComputePassDescriptor, ComputePipelineDescriptor,
     DownlevelCapabilities, Features, Label, Limits, LoadOp, MapMode, Operations,
     PipelineLayoutDescriptor, RenderBundleEncoderDescriptor, RenderPipelineDescriptor,
-    SamplerDescriptor, ShaderModuleDescriptor, ShaderModuleDescriptorSpirV, ShaderSource, StoreOp,
-    SurfaceStatus, TextureDescriptor, TextureViewDescriptor, UncapturedErrorHandler,
+    SamplerDescriptor, ShaderModuleDescriptor, ShaderModuleDescriptorSpirV, ShaderSource,
+    SourceLocation, StoreOp, SurfaceStatus, TextureDescriptor, TextureViewDescriptor,
+    UncapturedErrorHandler,
 };
 
 use arrayvec::ArrayVec;
@@ -378,6 +380,31 @@ impl Context {
     }
 }
 
+fn map_buffer_async_error(
+    error: wgc::resource::BufferAccessError,
+    requested: Range<wgt::BufferAddress>,
+) -> crate::BufferAsyncError {
+    use wgc::resource::BufferAccessError;
+    match error {
+        BufferAccessError::MissingBufferUsage(_) => crate::BufferAsyncError::InvalidUsage,
+        BufferAccessError::AlreadyMapped | BufferAccessError::MapAlreadyPending => {
+            crate::BufferAsyncError::AlreadyMapped
+        }
+        BufferAccessError::Destroyed => crate::BufferAsyncError::Destroyed,
+        BufferAccessError::Device(wgc::device::DeviceError::Lost) => {
+            crate::BufferAsyncError::DeviceLost
+        }
+        BufferAccessError::OutOfBoundsOverrun { max, .. } => {
+            crate::BufferAsyncError::RangeOutOfBounds {
+                requested,
+                size: max,
+            }
+        }
+        BufferAccessError::MapAborted => crate::BufferAsyncError::Aborted,
+        _ => crate::BufferAsyncError::Other,
+    }
+}
+
 fn map_buffer_copy_view(view: crate::ImageCopyBuffer<'_>) -> wgc::command::ImageCopyBuffer {
     wgc::command::ImageCopyBuffer {
         buffer: view.buffer.id.into(),
@@ -485,6 +512,11 @@ pub struct Buffer {
     error_sink: ErrorSink,
 }
 
+#[derive(Debug)]
+pub struct Pipeline {
+    error_sink: ErrorSink,
+}
+
 #[derive(Debug)]
 pub struct Texture {
     id: wgc::id::TextureId,
@@ -519,6 +551,65 @@ pub struct CommandEncoder {
     open: bool,
 }
 
+#[derive(Debug)]
+pub struct ShaderModule {
+    compilation_info: CompilationInfo,
+}
+
+impl ShaderModule {
+    fn new(error: Option<&wgc::pipeline::CreateShaderModuleError>) -> Self {
+        Self {
+            compilation_info: error.map(compilation_info).unwrap_or_default(),
+        }
+    }
+}
+
+/// Describe why a shader module could not be created, pointing into the source where naga
+/// recorded a span for the error.
+fn compilation_info(error: &wgc::pipeline::CreateShaderModuleError) -> CompilationInfo {
+    use wgc::pipeline::CreateShaderModuleError as Csme;
+
+    let error_message = |message: String, source: &str, span: Option<Range<usize>>| {
+        // Modules that weren't created from text have no source to point into.
+        let location = span
+            .filter(|span| source.get(span.clone()).is_some())
+            .map(|span| SourceLocation::from_span(source, span));
+        CompilationMessage {
+            message,
+            message_type: CompilationMessageType::Error,
+            location,
+        }
+    };
+    let messages = match *error {
+        #[cfg(feature = "wgsl")]
+        Csme::Parsing(ref err) => vec![error_message(
+            err.inner.message().to_string(),
+            &err.source,
+            err.inner
+                .labels()
+                .next()
+                .and_then(|(span, _)| span.to_range()),
+        )],
+        #[cfg(feature = "glsl")]
+        Csme::ParsingGlsl(ref err) => err
+            .inner
+            .0
+            .iter()
+            .map(|error| error_message(error.kind.to_string(), &err.source, error.meta.to_range()))
+            .collect(),
+        Csme::Validation(ref err) => vec![error_message(
+            err.inner.as_inner().to_string(),
+            &err.source,
+            err.inner
+                .spans()
+                .next()
+                .and_then(|(span, _)| span.to_range()),
+        )],
+        ref other => vec![error_message(other.to_string(), "", None)],
+    };
+    CompilationInfo { messages }
+}
+
 impl crate::Context for Context {
     type AdapterId = wgc::id::AdapterId;
     type AdapterData = ();
@@ -527,7 +618,7 @@ impl crate::Context for Context {
     type QueueId = wgc::id::QueueId;
     type QueueData = Queue;
     type ShaderModuleId = wgc::id::ShaderModuleId;
-    type ShaderModuleData = ();
+    type ShaderModuleData = ShaderModule;
     type BindGroupLayoutId = wgc::id::BindGroupLayoutId;
     type BindGroupLayoutData = ();
     type BindGroupId = wgc::id::BindGroupId;
@@ -545,9 +636,9 @@ impl crate::Context for Context {
     type PipelineLayoutId = wgc::id::PipelineLayoutId;
     type PipelineLayoutData = ();
     type RenderPipelineId = wgc::id::RenderPipelineId;
-    type RenderPipelineData = ();
+    type RenderPipelineData = Pipeline;
     type ComputePipelineId = wgc::id::ComputePipelineId;
-    type ComputePipelineData = ();
+    type ComputePipelineData = Pipeline;
     type CommandEncoderId = wgc::id::CommandEncoderId;
     type CommandEncoderData = CommandEncoder;
     type ComputePassId = Unused;
@@ -583,6 +674,7 @@ impl crate::Context for Context {
     >;
 
     type PopErrorScopeFuture = Ready<Option<crate::Error>>;
+    type CompilationInfoFuture = Ready<CompilationInfo>;
 
     fn init(instance_desc: wgt::InstanceDescriptor) -> Self {
         Self(wgc::global::Global::new(
@@ -921,15 +1013,11 @@ impl crate::Context for Context {
                 stage,
                 ref defines,
             } => {
-                // Parse the given shader code and store its representation.
                 let options = naga::front::glsl::Options {
                     stage,
                     defines: defines.clone(),
                 };
-                let mut parser = naga::front::glsl::Frontend::default();
-                let module = parser.parse(&options, shader).unwrap();
-
-                wgc::pipeline::ShaderModuleSource::Naga(Owned(module))
+                wgc::pipeline::ShaderModuleSource::Glsl(Borrowed(shader), options)
             }
             #[cfg(feature = "wgsl")]
             ShaderSource::Wgsl(ref code) => wgc::pipeline::ShaderModuleSource::Wgsl(Borrowed(code)),
@@ -940,6 +1028,7 @@ impl crate::Context for Context {
         let (id, error) = wgc::gfx_select!(
             device => global.device_create_shader_module(*device, &descriptor, source, ())
         );
+        let data = ShaderModule::new(error.as_ref());
         if let Some(cause) = error {
             self.handle_error(
                 &device_data.error_sink,
@@ -949,7 +1038,7 @@ impl crate::Context for Context {
                 "Device::create_shader_module",
             );
         }
-        (id, ())
+        (id, data)
     }
 
     unsafe fn device_create_shader_module_spirv(
@@ -968,6 +1057,7 @@ impl crate::Context for Context {
         let (id, error) = wgc::gfx_select!(
             device => global.device_create_shader_module_spirv(*device, &descriptor, Borrowed(&desc.source), ())
         );
+        let data = ShaderModule::new(error.as_ref());
         if let Some(cause) = error {
             self.handle_error(
                 &device_data.error_sink,
@@ -977,7 +1067,7 @@ impl crate::Context for Context {
                 "Device::create_shader_module_spirv",
             );
         }
-        (id, ())
+        (id, data)
     }
 
     fn device_create_bind_group_layout(
@@ -1232,7 +1322,12 @@ impl crate::Context for Context {
                 "Device::create_render_pipeline",
             );
         }
-        (id, ())
+        (
+            id,
+            Pipeline {
+                error_sink: Arc::clone(&device_data.error_sink),
+            },
+        )
     }
     fn device_create_compute_pipeline(
         &self,
@@ -1282,7 +1377,12 @@ impl crate::Context for Context {
                 "Device::create_compute_pipeline",
             );
         }
-        (id, ())
+        (
+            id,
+            Pipeline {
+                error_sink: Arc::clone(&device_data.error_sink),
+            },
+        )
     }
     fn device_create_buffer(
         &self,
@@ -1535,6 +1635,14 @@ impl crate::Context for Context {
         ready(scope.error)
     }
 
+    fn shader_get_compilation_info(
+        &self,
+        _shader: &Self::ShaderModuleId,
+        shader_data: &Self::ShaderModuleData,
+    ) -> Self::CompilationInfoFuture {
+        ready(shader_data.compilation_info.clone())
+    }
+
     fn buffer_map_async(
         &self,
         buffer: &Self::BufferId,
@@ -1548,12 +1656,13 @@ impl crate::Context for Context {
                 MapMode::Read => wgc::device::HostMap::Read,
                 MapMode::Write => wgc::device::HostMap::Write,
             },
-            callback: Some(wgc::resource::BufferMapCallback::from_rust(Box::new(
-                |status| {
-                    let res = status.map_err(|_| crate::BufferAsyncError);
+            callback: Some(wgc::resource::BufferMapCallback::from_rust(Box::new({
+                let range = range.clone();
+                move |status| {
+                    let res = status.map_err(|error| map_buffer_async_error(error, range));
                     callback(res);
-                },
-            ))),
+                }
+            }))),
         };
 
         let global = &self.0;
@@ -1763,13 +1872,17 @@ impl crate::Context for Context {
     fn compute_pipeline_get_bind_group_layout(
         &self,
         pipeline: &Self::ComputePipelineId,
-        _pipeline_data: &Self::ComputePipelineData,
+        pipeline_data: &Self::ComputePipelineData,
         index: u32,
     ) -> (Self::BindGroupLayoutId, Self::BindGroupLayoutData) {
         let global = &self.0;
         let (id, error) = wgc::gfx_select!(*pipeline => global.compute_pipeline_get_bind_group_layout(*pipeline, index, ()));
-        if let Some(err) = error {
-            panic!("Error reflecting bind group {index}: {err}");
+        if let Some(cause) = error {
+            self.handle_error_nolabel(
+                &pipeline_data.error_sink,
+                cause,
+                "ComputePipeline::get_bind_group_layout",
+            );
         }
         (id, ())
     }
@@ -1777,13 +1890,17 @@ impl crate::Context for Context {
     fn render_pipeline_get_bind_group_layout(
         &self,
         pipeline: &Self::RenderPipelineId,
-        _pipeline_data: &Self::RenderPipelineData,
+        pipeline_data: &Self::RenderPipelineData,
         index: u32,
     ) -> (Self::BindGroupLayoutId, Self::BindGroupLayoutData) {
         let global = &self.0;
         let (id, error) = wgc::gfx_select!(*pipeline => global.render_pipeline_get_bind_group_layout(*pipeline, index, ()));
-        if let Some(err) = error {
-            panic!("Error reflecting bind group {index}: {err}");
+        if let Some(cause) = error {
+            self.handle_error_nolabel(
+                &pipeline_data.error_sink,
+                cause,
+                "RenderPipeline::get_bind_group_layout",
+            );
         }
         (id, ())
     }
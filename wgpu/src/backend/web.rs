@@ -730,6 +730,7 @@ fn map_wgt_limits(limits: web_sys::GpuSupportedLimits) -> wgt::Limits {
         // The following are not part of WebGPU
         max_push_constant_size: wgt::Limits::default().max_push_constant_size,
         max_non_sampler_bindings: wgt::Limits::default().max_non_sampler_bindings,
+        max_binding_array_elements: wgt::Limits::default().max_binding_array_elements,
     }
 }
 
@@ -835,6 +836,52 @@ fn future_pop_error_scope(result: JsFutureResult) -> Option<crate::Error> {
     }
 }
 
+fn future_compilation_info(result: JsFutureResult, source: &str) -> crate::CompilationInfo {
+    let Ok(js_value) = result else {
+        return crate::CompilationInfo::default();
+    };
+    let messages = web_sys::GpuCompilationInfo::from(js_value)
+        .messages()
+        .iter()
+        .map(|js_message| {
+            let message = web_sys::GpuCompilationMessage::from(js_message);
+            let message_type = match message.type_() {
+                web_sys::GpuCompilationMessageType::Error => crate::CompilationMessageType::Error,
+                web_sys::GpuCompilationMessageType::Warning => {
+                    crate::CompilationMessageType::Warning
+                }
+                _ => crate::CompilationMessageType::Info,
+            };
+            // The browser reports positions in UTF-16 code units, and a line number of zero for
+            // messages that don't point into the source.
+            let location = (message.line_num() != 0.0).then(|| {
+                let start = utf16_to_utf8_offset(source, message.offset() as usize);
+                let end =
+                    utf16_to_utf8_offset(source, (message.offset() + message.length()) as usize);
+                crate::SourceLocation::from_span(source, start..end)
+            });
+            crate::CompilationMessage {
+                message: message.message(),
+                message_type,
+                location,
+            }
+        })
+        .collect();
+    crate::CompilationInfo { messages }
+}
+
+/// Converts an offset into `source` in UTF-16 code units into one in bytes.
+fn utf16_to_utf8_offset(source: &str, utf16_offset: usize) -> usize {
+    let mut utf16_position = 0;
+    for (utf8_position, c) in source.char_indices() {
+        if utf16_position >= utf16_offset {
+            return utf8_position;
+        }
+        utf16_position += c.len_utf16();
+    }
+    source.len()
+}
+
 /// Calls `callback(success_value)` when the promise completes successfully, calls `callback(failure_value)`
 /// when the promise completes unsuccessfully.
 fn register_then_closures<F, T>(promise: &Promise, callback: F, success_value: T, failure_value: T)
@@ -987,8 +1034,8 @@ impl crate::context::Context for Context {
     type DeviceData = Sendable<web_sys::GpuDevice>;
     type QueueId = Identified<web_sys::GpuQueue>;
     type QueueData = Sendable<web_sys::GpuQueue>;
-    type ShaderModuleId = Identified<web_sys::GpuShaderModule>;
-    type ShaderModuleData = Sendable<web_sys::GpuShaderModule>;
+    type ShaderModuleId = Identified<WebShaderModule>;
+    type ShaderModuleData = Sendable<WebShaderModule>;
     type BindGroupLayoutId = Identified<web_sys::GpuBindGroupLayout>;
     type BindGroupLayoutData = Sendable<web_sys::GpuBindGroupLayout>;
     type BindGroupId = Identified<web_sys::GpuBindGroup>;
@@ -1048,6 +1095,10 @@ impl crate::context::Context for Context {
     >;
     type PopErrorScopeFuture =
         MakeSendFuture<wasm_bindgen_futures::JsFuture, fn(JsFutureResult) -> Option<crate::Error>>;
+    type CompilationInfoFuture = MakeSendFuture<
+        wasm_bindgen_futures::JsFuture,
+        Box<dyn Fn(JsFutureResult) -> crate::CompilationInfo>,
+    >;
 
     fn init(_instance_desc: wgt::InstanceDescriptor) -> Self {
         let global: Global = js_sys::global().unchecked_into();
@@ -1397,7 +1448,7 @@ impl crate::context::Context for Context {
         desc: crate::ShaderModuleDescriptor<'_>,
         _shader_bound_checks: wgt::ShaderBoundChecks,
     ) -> (Self::ShaderModuleId, Self::ShaderModuleData) {
-        let mut descriptor = match desc.source {
+        let source = match desc.source {
             #[cfg(feature = "spirv")]
             crate::ShaderSource::SpirV(ref spv) => {
                 use naga::{back, front, valid};
@@ -1417,9 +1468,7 @@ impl crate::context::Context for Context {
                 let spv_module_info = validator.validate(&spv_module).unwrap();
 
                 let writer_flags = naga::back::wgsl::WriterFlags::empty();
-                let wgsl_text =
-                    back::wgsl::write_string(&spv_module, &spv_module_info, writer_flags).unwrap();
-                web_sys::GpuShaderModuleDescriptor::new(wgsl_text.as_str())
+                back::wgsl::write_string(&spv_module, &spv_module_info, writer_flags).unwrap()
             }
             #[cfg(feature = "glsl")]
             crate::ShaderSource::Glsl {
@@ -1444,13 +1493,10 @@ impl crate::context::Context for Context {
                 let glsl_module_info = validator.validate(&glsl_module).unwrap();
 
                 let writer_flags = naga::back::wgsl::WriterFlags::empty();
-                let wgsl_text =
-                    back::wgsl::write_string(&glsl_module, &glsl_module_info, writer_flags)
-                        .unwrap();
-                web_sys::GpuShaderModuleDescriptor::new(wgsl_text.as_str())
+                back::wgsl::write_string(&glsl_module, &glsl_module_info, writer_flags).unwrap()
             }
             #[cfg(feature = "wgsl")]
-            crate::ShaderSource::Wgsl(ref code) => web_sys::GpuShaderModuleDescriptor::new(code),
+            crate::ShaderSource::Wgsl(ref code) => code.to_string(),
             #[cfg(feature = "naga")]
             crate::ShaderSource::Naga(module) => {
                 use naga::{back, valid};
@@ -1462,18 +1508,20 @@ impl crate::context::Context for Context {
                 let module_info = validator.validate(&module).unwrap();
 
                 let writer_flags = naga::back::wgsl::WriterFlags::empty();
-                let wgsl_text =
-                    back::wgsl::write_string(&module, &module_info, writer_flags).unwrap();
-                web_sys::GpuShaderModuleDescriptor::new(wgsl_text.as_str())
+                back::wgsl::write_string(&module, &module_info, writer_flags).unwrap()
             }
             crate::ShaderSource::Dummy(_) => {
                 panic!("found `ShaderSource::Dummy`")
             }
         };
+        let mut descriptor = web_sys::GpuShaderModuleDescriptor::new(&source);
         if let Some(label) = desc.label {
             descriptor.label(label);
         }
-        create_identified(device_data.0.create_shader_module(&descriptor))
+        create_identified(WebShaderModule {
+            module: device_data.0.create_shader_module(&descriptor),
+            source,
+        })
     }
 
     unsafe fn device_create_shader_module_spirv(
@@ -1676,7 +1724,7 @@ impl crate::context::Context for Context {
         let module: &<Context as crate::Context>::ShaderModuleData =
             downcast_ref(desc.vertex.module.data.as_ref());
         let mut mapped_vertex_state =
-            web_sys::GpuVertexState::new(desc.vertex.entry_point, &module.0);
+            web_sys::GpuVertexState::new(desc.vertex.entry_point, &module.0.module);
 
         let buffers = desc
             .vertex
@@ -1751,7 +1799,7 @@ impl crate::context::Context for Context {
             let module: &<Context as crate::Context>::ShaderModuleData =
                 downcast_ref(frag.module.data.as_ref());
             let mapped_fragment_desc =
-                web_sys::GpuFragmentState::new(frag.entry_point, &module.0, &targets);
+                web_sys::GpuFragmentState::new(frag.entry_point, &module.0.module, &targets);
             mapped_desc.fragment(&mapped_fragment_desc);
         }
 
@@ -1776,7 +1824,7 @@ impl crate::context::Context for Context {
         let shader_module: &<Context as crate::Context>::ShaderModuleData =
             downcast_ref(desc.module.data.as_ref());
         let mapped_compute_stage =
-            web_sys::GpuProgrammableStage::new(desc.entry_point, &shader_module.0);
+            web_sys::GpuProgrammableStage::new(desc.entry_point, &shader_module.0.module);
         let auto_layout = wasm_bindgen::JsValue::from(web_sys::GpuAutoLayoutMode::Auto);
         let mut mapped_desc = web_sys::GpuComputePipelineDescriptor::new(
             &match desc.layout {
@@ -2010,6 +2058,19 @@ impl crate::context::Context for Context {
         )
     }
 
+    fn shader_get_compilation_info(
+        &self,
+        _shader: &Self::ShaderModuleId,
+        shader_data: &Self::ShaderModuleData,
+    ) -> Self::CompilationInfoFuture {
+        let compilation_info_promise = shader_data.0.module.compilation_info();
+        let source = shader_data.0.source.clone();
+        MakeSendFuture::new(
+            wasm_bindgen_futures::JsFuture::from(compilation_info_promise),
+            Box::new(move |result| future_compilation_info(result, &source)),
+        )
+    }
+
     fn buffer_map_async(
         &self,
         _buffer: &Self::BufferId,
@@ -2026,7 +2087,12 @@ impl crate::context::Context for Context {
 
         buffer_data.0.set_mapped_range(range);
 
-        register_then_closures(&map_promise, callback, Ok(()), Err(crate::BufferAsyncError));
+        register_then_closures(
+            &map_promise,
+            callback,
+            Ok(()),
+            Err(crate::BufferAsyncError::Other),
+        );
     }
 
     fn buffer_get_mapped_range(
@@ -2583,15 +2649,17 @@ impl crate::context::Context for Context {
         let write_size = u64::from(size);
         if write_size % wgt::COPY_BUFFER_ALIGNMENT != 0 {
             log::error!(
-                "Copy size {} does not respect `COPY_BUFFER_ALIGNMENT`",
-                size
+                "Copy size {} is not a multiple of `COPY_BUFFER_ALIGNMENT` ({})",
+                size,
+                wgt::COPY_BUFFER_ALIGNMENT
             );
             return None;
         }
         if offset % wgt::COPY_BUFFER_ALIGNMENT != 0 {
             log::error!(
-                "Buffer offset {} is not aligned to block size or `COPY_BUFFER_ALIGNMENT`",
-                offset
+                "Destination buffer offset {} is not a multiple of `COPY_BUFFER_ALIGNMENT` ({})",
+                offset,
+                wgt::COPY_BUFFER_ALIGNMENT
             );
             return None;
         }
@@ -3026,25 +3094,39 @@ impl crate::context::Context for Context {
     fn render_bundle_encoder_multi_draw_indirect(
         &self,
         _encoder: &mut Self::RenderBundleEncoderId,
-        _encoder_data: &mut Self::RenderBundleEncoderData,
+        encoder_data: &mut Self::RenderBundleEncoderData,
         _indirect_buffer: &Self::BufferId,
-        _indirect_buffer_data: &Self::BufferData,
-        _indirect_offset: wgt::BufferAddress,
-        _count: u32,
-    ) {
-        panic!("MULTI_DRAW_INDIRECT feature must be enabled to call multi_draw_indirect")
+        indirect_buffer_data: &Self::BufferData,
+        indirect_offset: wgt::BufferAddress,
+        count: u32,
+    ) {
+        // WebGPU has no multi-draw, so issue one indirect draw per record.
+        let stride = std::mem::size_of::<wgt::DrawIndirectArgs>() as wgt::BufferAddress;
+        for i in 0..count as wgt::BufferAddress {
+            encoder_data.0.draw_indirect_with_f64(
+                &indirect_buffer_data.0.buffer,
+                (indirect_offset + i * stride) as f64,
+            );
+        }
     }
 
     fn render_bundle_encoder_multi_draw_indexed_indirect(
         &self,
         _encoder: &mut Self::RenderBundleEncoderId,
-        _encoder_data: &mut Self::RenderBundleEncoderData,
+        encoder_data: &mut Self::RenderBundleEncoderData,
         _indirect_buffer: &Self::BufferId,
-        _indirect_buffer_data: &Self::BufferData,
-        _indirect_offset: wgt::BufferAddress,
-        _count: u32,
-    ) {
-        panic!("MULTI_DRAW_INDIRECT feature must be enabled to call multi_draw_indexed_indirect")
+        indirect_buffer_data: &Self::BufferData,
+        indirect_offset: wgt::BufferAddress,
+        count: u32,
+    ) {
+        // WebGPU has no multi-draw, so issue one indirect draw per record.
+        let stride = std::mem::size_of::<wgt::DrawIndexedIndirectArgs>() as wgt::BufferAddress;
+        for i in 0..count as wgt::BufferAddress {
+            encoder_data.0.draw_indexed_indirect_with_f64(
+                &indirect_buffer_data.0.buffer,
+                (indirect_offset + i * stride) as f64,
+            );
+        }
     }
 
     fn render_bundle_encoder_multi_draw_indirect_count(
@@ -3247,25 +3329,39 @@ impl crate::context::Context for Context {
     fn render_pass_multi_draw_indirect(
         &self,
         _pass: &mut Self::RenderPassId,
-        _pass_data: &mut Self::RenderPassData,
+        pass_data: &mut Self::RenderPassData,
         _indirect_buffer: &Self::BufferId,
-        _indirect_buffer_data: &Self::BufferData,
-        _indirect_offset: wgt::BufferAddress,
-        _count: u32,
-    ) {
-        panic!("MULTI_DRAW_INDIRECT feature must be enabled to call multi_draw_indirect")
+        indirect_buffer_data: &Self::BufferData,
+        indirect_offset: wgt::BufferAddress,
+        count: u32,
+    ) {
+        // WebGPU has no multi-draw, so issue one indirect draw per record.
+        let stride = std::mem::size_of::<wgt::DrawIndirectArgs>() as wgt::BufferAddress;
+        for i in 0..count as wgt::BufferAddress {
+            pass_data.0.draw_indirect_with_f64(
+                &indirect_buffer_data.0.buffer,
+                (indirect_offset + i * stride) as f64,
+            );
+        }
     }
 
     fn render_pass_multi_draw_indexed_indirect(
         &self,
         _pass: &mut Self::RenderPassId,
-        _pass_data: &mut Self::RenderPassData,
+        pass_data: &mut Self::RenderPassData,
         _indirect_buffer: &Self::BufferId,
-        _indirect_buffer_data: &Self::BufferData,
-        _indirect_offset: wgt::BufferAddress,
-        _count: u32,
-    ) {
-        panic!("MULTI_DRAW_INDIRECT feature must be enabled to call multi_draw_indexed_indirect")
+        indirect_buffer_data: &Self::BufferData,
+        indirect_offset: wgt::BufferAddress,
+        count: u32,
+    ) {
+        // WebGPU has no multi-draw, so issue one indirect draw per record.
+        let stride = std::mem::size_of::<wgt::DrawIndexedIndirectArgs>() as wgt::BufferAddress;
+        for i in 0..count as wgt::BufferAddress {
+            pass_data.0.draw_indexed_indirect_with_f64(
+                &indirect_buffer_data.0.buffer,
+                (indirect_offset + i * stride) as f64,
+            );
+        }
     }
 
     fn render_pass_multi_draw_indirect_count(
@@ -3512,6 +3608,16 @@ impl WebBuffer {
     }
 }
 
+/// A shader module, along with the WGSL it was compiled from.
+#[derive(Debug)]
+pub struct WebShaderModule {
+    /// The associated GPU shader module.
+    module: web_sys::GpuShaderModule,
+    /// The source handed to the browser, used to translate the UTF-16 positions in its
+    /// compilation messages into byte positions.
+    source: String,
+}
+
 /// Remembers which portion of a buffer has been mapped, along with a reference
 /// to the mapped portion.
 #[derive(Debug)]
@@ -227,11 +227,18 @@ impl MapContext {
             Some(s) => offset + s.get(),
             None => self.initial_range.end,
         };
-        assert!(self.initial_range.start <= offset && end <= self.initial_range.end);
+        assert!(
+            self.initial_range.start <= offset && end <= self.initial_range.end,
+            "Range {:?} is outside of the mapped range {:?}",
+            offset..end,
+            self.initial_range
+        );
+        // Any number of views may coexist, as long as they don't overlap.
         for sub in self.sub_ranges.iter() {
             assert!(
                 end <= sub.start || offset >= sub.end,
-                "Intersecting map range with {sub:?}"
+                "Range {:?} overlaps the mapped view {sub:?}",
+                offset..end
             );
         }
         self.sub_ranges.push(offset..end);
@@ -510,6 +517,9 @@ pub enum ShaderSource<'a> {
     /// GLSL module as a string slice.
     ///
     /// Note: GLSL is not yet fully supported and must be a specific ShaderStage.
+    ///
+    /// The source is parsed by naga's GLSL frontend. On native backends, parsing errors are
+    /// reported like WGSL ones, as a validation error from [`Device::create_shader_module`].
     #[cfg(feature = "glsl")]
     Glsl {
         /// The source code of the shader.
@@ -559,6 +569,87 @@ pub struct ShaderModuleDescriptorSpirV<'a> {
 }
 static_assertions::assert_impl_all!(ShaderModuleDescriptorSpirV<'_>: Send, Sync);
 
+/// Messages produced while compiling a shader module, returned by
+/// [`ShaderModule::get_compilation_info`].
+///
+/// Corresponds to [WebGPU `GPUCompilationInfo`](https://gpuweb.github.io/gpuweb/#gpucompilationinfo).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CompilationInfo {
+    /// The messages, in the order they were produced.
+    pub messages: Vec<CompilationMessage>,
+}
+
+/// A single diagnostic from the compilation of a shader module.
+///
+/// Corresponds to [WebGPU `GPUCompilationMessage`](https://gpuweb.github.io/gpuweb/#gpucompilationmessage).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompilationMessage {
+    /// The human-readable text of the message.
+    pub message: String,
+    /// The severity of the message.
+    pub message_type: CompilationMessageType,
+    /// Where in the shader source the message points to, if anywhere.
+    ///
+    /// This is `None` for messages that aren't tied to a piece of the source, and for
+    /// sources that aren't text, like SPIR-V and naga modules.
+    pub location: Option<SourceLocation>,
+}
+
+/// The severity of a [`CompilationMessage`].
+///
+/// Corresponds to [WebGPU `GPUCompilationMessageType`](https://gpuweb.github.io/gpuweb/#enumdef-gpucompilationmessagetype).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CompilationMessageType {
+    /// The shader module could not be compiled.
+    Error,
+    /// Something in the shader is suspicious, but it still compiled.
+    Warning,
+    /// Purely informational.
+    Info,
+}
+
+/// The position of a [`CompilationMessage`] in the shader source.
+///
+/// Positions are given both in bytes (UTF-8 code units), which is what indexing a Rust `str`
+/// uses, and in UTF-16 code units, which is what the WebGPU API and JavaScript strings use.
+/// The two only differ in the presence of non-ASCII text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SourceLocation {
+    /// 1-based line number.
+    pub line_number: u32,
+    /// 1-based column of the start of the span within its line, in bytes.
+    pub line_position: u32,
+    /// 0-based offset of the start of the span from the start of the source, in bytes.
+    pub offset: u32,
+    /// Length of the span, in bytes.
+    pub length: u32,
+    /// 1-based column of the start of the span within its line, in UTF-16 code units.
+    pub line_position_utf16: u32,
+    /// 0-based offset of the start of the span from the start of the source, in UTF-16 code units.
+    pub offset_utf16: u32,
+    /// Length of the span, in UTF-16 code units.
+    pub length_utf16: u32,
+}
+
+impl SourceLocation {
+    /// The location of the byte range `span` in `source`.
+    pub(crate) fn from_span(source: &str, span: Range<usize>) -> Self {
+        let prefix = &source[..span.start];
+        let line_start = prefix.rfind('\n').map_or(0, |pos| pos + 1);
+        let utf16_len = |text: &str| text.encode_utf16().count() as u32;
+
+        Self {
+            line_number: prefix.matches('\n').count() as u32 + 1,
+            line_position: (span.start - line_start) as u32 + 1,
+            offset: span.start as u32,
+            length: span.len() as u32,
+            line_position_utf16: utf16_len(&source[line_start..span.start]) + 1,
+            offset_utf16: utf16_len(prefix),
+            length_utf16: utf16_len(&source[span]),
+        }
+    }
+}
+
 /// Handle to a pipeline layout.
 ///
 /// A `PipelineLayout` object describes the available binding groups of a pipeline.
@@ -609,6 +700,10 @@ impl Drop for RenderPipeline {
 
 impl RenderPipeline {
     /// Get an object representing the bind group layout at a given index.
+    ///
+    /// For a pipeline created with `layout: None`, groups the shader skips below its highest
+    /// used group have empty layouts. This method raises a validation error if there is no
+    /// bind group layout at `index`.
     pub fn get_bind_group_layout(&self, index: u32) -> BindGroupLayout {
         let context = Arc::clone(&self.context);
         let (id, data) =
@@ -644,6 +739,10 @@ impl Drop for ComputePipeline {
 
 impl ComputePipeline {
     /// Get an object representing the bind group layout at a given index.
+    ///
+    /// For a pipeline created with `layout: None`, groups the shader skips below its highest
+    /// used group have empty layouts. This method raises a validation error if there is no
+    /// bind group layout at `index`.
     pub fn get_bind_group_layout(&self, index: u32) -> BindGroupLayout {
         let context = Arc::clone(&self.context);
         let (id, data) = self.context.compute_pipeline_get_bind_group_layout(
@@ -690,6 +789,11 @@ impl Drop for CommandBuffer {
 /// When finished recording, call [`CommandEncoder::finish`] to obtain a [`CommandBuffer`] which may
 /// be submitted for execution.
 ///
+/// On native, command encoders are `Send` and `Sync`: several encoders may be recorded in parallel
+/// on different threads, and an encoder may be finished on a different thread than the one it was
+/// created on. Recording only touches the encoder's own resource trackers; they are merged into the
+/// device's trackers once per command buffer, when it is submitted.
+///
 /// Corresponds to [WebGPU `GPUCommandEncoder`](https://gpuweb.github.io/gpuweb/#command-encoder).
 #[derive(Debug)]
 pub struct CommandEncoder {
@@ -1210,7 +1314,11 @@ pub struct SamplerDescriptor<'a> {
     pub lod_max_clamp: f32,
     /// If this is enabled, this is a comparison sampler using the given comparison function.
     pub compare: Option<CompareFunction>,
-    /// Must be at least 1. If this is not 1, all filter modes must be linear.
+    /// Maximum anisotropy of anisotropic filtering. 1 disables it.
+    ///
+    /// Must be at least 1. If this is not 1, all filter modes must be linear. Values above 16
+    /// are clamped to 16, and on adapters without
+    /// [`DownlevelFlags::ANISOTROPIC_FILTERING`] the value is ignored and filtering is isotropic.
     pub anisotropy_clamp: u16,
     /// Border color to use when address_mode is [`AddressMode::ClampToBorder`]
     pub border_color: Option<SamplerBorderColor>,
@@ -2268,6 +2376,21 @@ impl Device {
 
     /// Creates a shader module from either SPIR-V or WGSL source code without runtime checks.
     ///
+    /// [`create_shader_module`](Self::create_shader_module) has naga guard the memory accesses
+    /// of the shader: indices into arrays, vectors and matrices, accesses to buffers, and
+    /// texture loads are clamped or replaced with zero when they are out of bounds, unless
+    /// the backend already guarantees robust access. Those checks cost some performance in
+    /// hot loops. Modules created with this function are translated without them. Only the
+    /// pipelines that use such a module are affected, so trusted and untrusted shaders can be
+    /// mixed on one device.
+    ///
+    /// The checks that are skipped differ per backend:
+    /// - Vulkan and Metal: index, buffer and texture load checks.
+    /// - OpenGL: texture load checks. The other accesses aren't checked by naga's GLSL output
+    ///   either way.
+    /// - DX12 and WebGPU: nothing. Naga's HLSL output doesn't insert checks, and browsers
+    ///   always check.
+    ///
     /// # Safety
     /// In contrast with [`create_shader_module`](Self::create_shader_module) this function
     /// creates a shader module without runtime checks which allows shaders to perform
@@ -2301,6 +2424,10 @@ impl Device {
     /// This function passes binary data to the backend as-is and can potentially result in a
     /// driver crash or bogus behaviour. No attempt is made to ensure that data is valid SPIR-V.
     ///
+    /// Only the module's entry points are read, so pipelines can check that the requested entry
+    /// point exists. Its resource bindings aren't reflected, so pipelines using the module must
+    /// be created with an explicit [`PipelineLayout`].
+    ///
     /// See also [`include_spirv_raw!`] and [`util::make_spirv_raw`].
     pub unsafe fn create_shader_module_spirv(
         &self,
@@ -2780,13 +2907,52 @@ impl From<hal::InstanceError> for CreateSurfaceError {
 }
 
 /// Error occurred when trying to async map a buffer.
+///
+/// The WebGPU backend can't tell failures apart, and reports all of them as
+/// [`BufferAsyncError::Other`].
 #[derive(Clone, PartialEq, Eq, Debug)]
-pub struct BufferAsyncError;
+#[non_exhaustive]
+pub enum BufferAsyncError {
+    /// The buffer was not created with the `MAP_READ` or `MAP_WRITE` usage that the
+    /// [`MapMode`] requires.
+    InvalidUsage,
+    /// The buffer is already mapped, or a mapping of it is already pending.
+    AlreadyMapped,
+    /// The buffer has been destroyed.
+    Destroyed,
+    /// The device the buffer belongs to has been lost.
+    DeviceLost,
+    /// The requested range doesn't fit in the buffer.
+    RangeOutOfBounds {
+        /// The range that was requested.
+        requested: Range<BufferAddress>,
+        /// The size of the buffer.
+        size: BufferAddress,
+    },
+    /// The buffer was unmapped before the mapping completed.
+    Aborted,
+    /// Any other failure, such as an invalid buffer or a misaligned range.
+    Other,
+}
 static_assertions::assert_impl_all!(BufferAsyncError: Send, Sync);
 
 impl fmt::Display for BufferAsyncError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Error occurred when trying to async map a buffer")
+        match *self {
+            Self::InvalidUsage => write!(f, "Buffer usage doesn't allow the requested map mode"),
+            Self::AlreadyMapped => write!(f, "Buffer is already mapped or has a pending map"),
+            Self::Destroyed => write!(f, "Buffer is destroyed"),
+            Self::DeviceLost => write!(f, "Device is lost"),
+            Self::RangeOutOfBounds {
+                ref requested,
+                size,
+            } => write!(
+                f,
+                "Requested range {requested:?} doesn't fit in buffer of size {size}"
+            ),
+            Self::Aborted => write!(f, "Buffer was unmapped before the mapping completed"),
+            Self::Other => write!(f, "Error occurred when trying to async map a buffer"),
+        }
     }
 }
 
@@ -2932,7 +3098,18 @@ impl Buffer {
     }
 
     /// Destroy the associated native resources as soon as possible.
+    ///
+    /// The memory is released once the submissions that use the buffer have completed.
+    /// Using the buffer afterwards, in a bind group, a copy or a pass, is a validation error.
+    /// The buffer is unmapped, and a pending [`BufferSlice::map_async`] callback receives
+    /// [`BufferAsyncError::Destroyed`].
+    ///
+    /// # Panics
+    ///
+    /// Like [`Buffer::unmap`], panics if a [`BufferView`] or [`BufferViewMut`] of the buffer
+    /// is still alive, since unmapping would leave it pointing at freed memory.
     pub fn destroy(&self) {
+        self.map_context.lock().reset();
         DynContext::buffer_destroy(&*self.context, &self.id, self.data.as_ref());
     }
 
@@ -3079,6 +3256,10 @@ impl Texture {
     }
 
     /// Destroy the associated native resources as soon as possible.
+    ///
+    /// The memory is released once the submissions that use the texture have completed.
+    /// Using the texture afterwards, in a copy or a pass, or through one of its views, is a
+    /// validation error.
     pub fn destroy(&self) {
         DynContext::texture_destroy(&*self.context, &self.id, self.data.as_ref());
     }
@@ -3233,7 +3414,7 @@ impl CommandEncoder {
     ///
     /// - Buffer offsets or copy size not a multiple of [`COPY_BUFFER_ALIGNMENT`].
     /// - Copy would overrun buffer.
-    /// - Copy within the same buffer.
+    /// - Copy within the same buffer, even if the source and destination ranges don't overlap.
     pub fn copy_buffer_to_buffer(
         &mut self,
         source: &Buffer,
@@ -3336,12 +3517,12 @@ impl CommandEncoder {
         );
     }
 
-    /// Clears buffer to zero.
-    ///
-    /// # Panics
+    /// Clears `size` bytes of `buffer` starting at `offset` to zero, or everything from
+    /// `offset` to the end of the buffer if `size` is `None`.
     ///
-    /// - Buffer does not have `COPY_DST` usage.
-    /// - Range it out of bounds
+    /// The buffer must have the [`BufferUsages::COPY_DST`] usage, and `offset` and `size`
+    /// must be multiples of [`COPY_BUFFER_ALIGNMENT`]. The cleared range must lie within
+    /// the buffer. Otherwise a validation error is raised.
     pub fn clear_buffer(
         &mut self,
         buffer: &Buffer,
@@ -3370,6 +3551,8 @@ impl CommandEncoder {
     }
 
     /// Start record commands and group it into debug marker group.
+    ///
+    /// Each pushed group must be popped with `pop_debug_group` before the encoder is finished.
     pub fn push_debug_group(&mut self, label: &str) {
         let id = self.id.as_ref().unwrap();
         DynContext::command_encoder_push_debug_group(&*self.context, id, self.data.as_ref(), label);
@@ -3549,6 +3732,9 @@ impl<'a> RenderPass<'a> {
     /// Subsequent draw calls will only draw within this region.
     /// If this method has not been called, the viewport defaults to the entire bounds of the render
     /// targets.
+    ///
+    /// The rectangle must have a positive size and lie within the render targets, and
+    /// `min_depth` and `max_depth` must satisfy `0.0 <= min_depth <= max_depth <= 1.0`.
     pub fn set_viewport(&mut self, x: f32, y: f32, w: f32, h: f32, min_depth: f32, max_depth: f32) {
         DynContext::render_pass_set_viewport(
             &*self.parent.context,
@@ -3587,6 +3773,8 @@ impl<'a> RenderPass<'a> {
     }
 
     /// Start record commands and group it into debug marker group.
+    ///
+    /// Each pushed group must be popped with `pop_debug_group` before the render pass ends.
     pub fn push_debug_group(&mut self, label: &str) {
         DynContext::render_pass_push_debug_group(
             &*self.parent.context,
@@ -3675,6 +3863,7 @@ impl<'a> RenderPass<'a> {
     ///
     /// This is like calling [`RenderPass::draw`] but the contents of the call are specified in the `indirect_buffer`.
     /// The structure expected in `indirect_buffer` must conform to [`DrawIndirectArgs`](crate::util::DrawIndirectArgs).
+    /// `indirect_offset` must be a multiple of 4.
     ///
     /// Indirect drawing has some caveats depending on the features available. We are not currently able to validate
     /// these and issue an error.
@@ -3700,6 +3889,7 @@ impl<'a> RenderPass<'a> {
     ///
     /// This is like calling [`RenderPass::draw_indexed`] but the contents of the call are specified in the `indirect_buffer`.
     /// The structure expected in `indirect_buffer` must conform to [`DrawIndexedIndirectArgs`](crate::util::DrawIndexedIndirectArgs).
+    /// `indirect_offset` must be a multiple of 4.
     ///
     /// Indirect drawing has some caveats depending on the features available. We are not currently able to validate
     /// these and issue an error.
@@ -3743,7 +3933,8 @@ impl<'a> RenderPass<'a> {
     }
 }
 
-/// [`Features::MULTI_DRAW_INDIRECT`] must be enabled on the device in order to call these functions.
+/// These functions are available on every device that supports indirect execution. Without
+/// [`Features::MULTI_DRAW_INDIRECT`], they are emulated with one indirect draw per record.
 impl<'a> RenderPass<'a> {
     /// Dispatches multiple draw calls from the active vertex buffer(s) based on the contents of the `indirect_buffer`.
     /// `count` draw calls are issued.
@@ -3808,6 +3999,7 @@ impl<'a> RenderPass<'a> {
     ///
     /// The indirect buffer must be long enough to account for `max_count` draws, however only `count`
     /// draws will be read. If `count` is greater than `max_count`, `max_count` will be used.
+    /// `count_offset` must be a multiple of 4.
     ///
     /// The active vertex buffers can be set with [`RenderPass::set_vertex_buffer`].
     ///
@@ -3852,6 +4044,7 @@ impl<'a> RenderPass<'a> {
     ///
     /// The indirect buffer must be long enough to account for `max_count` draws, however only `count`
     /// draws will be read. If `count` is greater than `max_count`, `max_count` will be used.
+    /// `count_offset` must be a multiple of 4.
     ///
     /// The active index buffer can be set with [`RenderPass::set_index_buffer`], while the active
     /// vertex buffers can be set with [`RenderPass::set_vertex_buffer`].
@@ -4079,6 +4272,8 @@ impl<'a> ComputePass<'a> {
     }
 
     /// Start record commands and group it into debug marker group.
+    ///
+    /// Each pushed group must be popped with `pop_debug_group` before the compute pass ends.
     pub fn push_debug_group(&mut self, label: &str) {
         DynContext::compute_pass_push_debug_group(
             &*self.parent.context,
@@ -4114,6 +4309,7 @@ impl<'a> ComputePass<'a> {
     /// Dispatches compute work operations, based on the contents of the `indirect_buffer`.
     ///
     /// The structure expected in `indirect_buffer` must conform to [`DispatchIndirectArgs`](crate::util::DispatchIndirectArgs).
+    /// `indirect_offset` must be a multiple of 4.
     pub fn dispatch_workgroups_indirect(
         &mut self,
         indirect_buffer: &'a Buffer,
@@ -4516,7 +4712,13 @@ impl Queue {
     /// As such, the write is not immediately submitted, and instead enqueued
     /// internally to happen at the start of the next `submit()` call.
     ///
-    /// This method fails if `size` is greater than the size of `buffer` starting at `offset`.
+    /// The view starts out zeroed, so bytes that aren't written through it are
+    /// written to `buffer` as zero.
+    ///
+    /// This method raises a validation error and returns `None` if `buffer` doesn't have the
+    /// [`BufferUsages::COPY_DST`] usage, if `offset` or `size` aren't multiples of
+    /// [`COPY_BUFFER_ALIGNMENT`], or if `size` is greater than the size of `buffer` starting
+    /// at `offset`.
     #[must_use]
     pub fn write_buffer_with<'a>(
         &'a self,
@@ -4900,6 +5102,15 @@ impl ShaderModule {
     pub fn global_id(&self) -> Id<Self> {
         Id(self.id.global_id(), PhantomData)
     }
+
+    /// Get the messages produced while compiling this shader module.
+    ///
+    /// A module whose creation failed is still a valid handle, and its compilation info
+    /// describes why it failed, with positions in the source where naga could attribute them.
+    pub fn get_compilation_info(&self) -> impl Future<Output = CompilationInfo> + WasmNotSend {
+        self.context
+            .shader_get_compilation_info(&self.id, self.data.as_ref())
+    }
 }
 
 impl BindGroupLayout {
@@ -5132,4 +5343,39 @@ mod tests {
     fn range_to_offset_size_panics_for_unbounded_empty_range() {
         crate::range_to_offset_size(..0);
     }
+
+    fn mapped_context() -> crate::MapContext {
+        let mut mc = crate::MapContext::new(64);
+        mc.initial_range = 0..64;
+        mc
+    }
+
+    #[test]
+    fn map_context_allows_disjoint_views() {
+        let mut mc = mapped_context();
+        assert_eq!(mc.add(0, BufferSize::new(16)), 16);
+        assert_eq!(mc.add(48, None), 64);
+        assert_eq!(mc.add(16, BufferSize::new(32)), 48);
+
+        mc.remove(48, None);
+        mc.remove(0, BufferSize::new(16));
+        mc.remove(16, BufferSize::new(32));
+        mc.reset();
+    }
+
+    #[test]
+    #[should_panic(expected = "Range 8..24 overlaps the mapped view 0..16")]
+    fn map_context_panics_for_overlapping_views() {
+        let mut mc = mapped_context();
+        mc.add(0, BufferSize::new(16));
+        mc.add(8, BufferSize::new(16));
+    }
+
+    #[test]
+    #[should_panic(expected = "still has accessible mapped views")]
+    fn map_context_panics_for_unmap_with_views() {
+        let mut mc = mapped_context();
+        mc.add(0, BufferSize::new(16));
+        mc.reset();
+    }
 }
@@ -1,8 +1,12 @@
+#[cfg(send_sync)]
+use crate::Queue;
 use crate::{
     util::align_to, Buffer, BufferAddress, BufferDescriptor, BufferSize, BufferUsages,
     BufferViewMut, CommandEncoder, Device, MapMode,
 };
 use std::fmt;
+#[cfg(send_sync)]
+use std::sync::Weak;
 use std::sync::{mpsc, Arc};
 
 struct Chunk {
@@ -40,6 +44,8 @@ impl<T> Exclusive<T> {
 /// 3. Submit all command encoders that were used in step 1.
 /// 4. Call [`StagingBelt::recall()`].
 ///
+/// A belt created with [`StagingBelt::new_with_auto_recall()`] does step 4 by itself.
+///
 /// [`Queue::write_buffer()`]: crate::Queue::write_buffer
 pub struct StagingBelt {
     chunk_size: BufferAddress,
@@ -56,6 +62,12 @@ pub struct StagingBelt {
     sender: Exclusive<mpsc::Sender<Chunk>>,
     /// Free chunks are received here to be put on `self.free_chunks`.
     receiver: Exclusive<mpsc::Receiver<Chunk>>,
+    /// If set, closed chunks are recalled through this queue's
+    /// `on_submitted_work_done` instead of by [`StagingBelt::recall()`].
+    ///
+    /// This is a weak reference so the belt doesn't keep the queue alive.
+    #[cfg(send_sync)]
+    auto_recall: Option<Weak<Queue>>,
 }
 
 impl StagingBelt {
@@ -78,6 +90,29 @@ impl StagingBelt {
             free_chunks: Vec::new(),
             sender: Exclusive::new(sender),
             receiver: Exclusive::new(receiver),
+            #[cfg(send_sync)]
+            auto_recall: None,
+        }
+    }
+
+    /// Create a new staging belt which recalls its buffers automatically.
+    ///
+    /// The `chunk_size` has the same meaning as in [`StagingBelt::new()`].
+    ///
+    /// Chunks closed by [`StagingBelt::finish()`] are handed to
+    /// [`Queue::on_submitted_work_done()`] the next time the belt is used, and are
+    /// mapped again once the GPU is done with them. Calling [`StagingBelt::recall()`]
+    /// is not needed, but remains harmless. Because of this, the command encoder(s)
+    /// provided to [`StagingBelt::write_buffer()`] must be submitted to `queue`
+    /// before the belt is used again.
+    ///
+    /// Only a weak reference to `queue` is kept. If the queue is dropped, closed
+    /// chunks are freed instead of being reused.
+    #[cfg(send_sync)]
+    pub fn new_with_auto_recall(chunk_size: BufferAddress, queue: &Arc<Queue>) -> Self {
+        Self {
+            auto_recall: Some(Arc::downgrade(queue)),
+            ..Self::new(chunk_size)
         }
     }
 
@@ -99,6 +134,9 @@ impl StagingBelt {
         size: BufferSize,
         device: &Device,
     ) -> BufferViewMut<'_> {
+        #[cfg(send_sync)]
+        self.auto_recall();
+
         let mut chunk = if let Some(index) = self
             .active_chunks
             .iter()
@@ -151,6 +189,9 @@ impl StagingBelt {
     /// further writes) until after [`StagingBelt::recall()`] is called *and* the GPU is done
     /// copying the data from them.
     pub fn finish(&mut self) {
+        #[cfg(send_sync)]
+        self.auto_recall();
+
         for chunk in self.active_chunks.drain(..) {
             chunk.buffer.unmap();
             self.closed_chunks.push(chunk);
@@ -166,14 +207,33 @@ impl StagingBelt {
         self.receive_chunks();
 
         for chunk in self.closed_chunks.drain(..) {
+            map_chunk(chunk, self.sender.get_mut().clone());
+        }
+    }
+
+    /// In auto-recall mode, recall the chunks closed by the previous
+    /// [`StagingBelt::finish()`] once the GPU is done with them.
+    ///
+    /// The encoders using those chunks have been submitted by now, so the
+    /// work-done callback registered here fires after their copies complete.
+    #[cfg(send_sync)]
+    fn auto_recall(&mut self) {
+        let Some(queue) = self.auto_recall.as_ref() else {
+            return;
+        };
+        if self.closed_chunks.is_empty() {
+            return;
+        }
+
+        let chunks = std::mem::take(&mut self.closed_chunks);
+        // If the queue is gone nothing can be submitted anymore, so just let the chunks go.
+        if let Some(queue) = queue.upgrade() {
             let sender = self.sender.get_mut().clone();
-            chunk
-                .buffer
-                .clone()
-                .slice(..)
-                .map_async(MapMode::Write, move |_| {
-                    let _ = sender.send(chunk);
-                });
+            queue.on_submitted_work_done(move || {
+                for chunk in chunks {
+                    map_chunk(chunk, sender.clone());
+                }
+            });
         }
     }
 
@@ -187,6 +247,21 @@ impl StagingBelt {
     }
 }
 
+/// Map `chunk` for writing, sending it to `sender` once it is ready for reuse.
+fn map_chunk(chunk: Chunk, sender: mpsc::Sender<Chunk>) {
+    chunk
+        .buffer
+        .clone()
+        .slice(..)
+        .map_async(MapMode::Write, move |result| {
+            // If mapping failed (for example because the device was lost), the chunk
+            // can't be written to anymore, so drop it instead of reusing it.
+            if result.is_ok() {
+                let _ = sender.send(chunk);
+            }
+        });
+}
+
 impl fmt::Debug for StagingBelt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("StagingBelt")
@@ -0,0 +1,257 @@
+use std::collections::HashMap;
+
+use parking_lot::Mutex;
+
+use crate::{
+    BindGroupDescriptor, BindGroupEntry, BindGroupLayout, BindGroupLayoutDescriptor,
+    BindGroupLayoutEntry, BindingResource, BindingType, BlendState, ColorTargetState, ColorWrites,
+    CommandEncoder, Device, FilterMode, FragmentState, LoadOp, MultisampleState, Operations,
+    PipelineLayout, PipelineLayoutDescriptor, PrimitiveState, RenderPassColorAttachment,
+    RenderPassDescriptor, RenderPipeline, RenderPipelineDescriptor, Sampler, SamplerBindingType,
+    SamplerDescriptor, ShaderModule, ShaderModuleDescriptor, ShaderSource, ShaderStages, StoreOp,
+    TextureFormat, TextureSampleType, TextureView, TextureViewDimension, VertexState,
+};
+
+/// Builder for a [`TextureBlitter`] with non-default settings.
+pub struct TextureBlitterBuilder<'a> {
+    device: &'a Device,
+    format: TextureFormat,
+    filter: FilterMode,
+    blend_state: Option<BlendState>,
+}
+
+impl<'a> TextureBlitterBuilder<'a> {
+    /// Start building a [`TextureBlitter`] that renders into targets of `format`.
+    pub fn new(device: &'a Device, format: TextureFormat) -> Self {
+        Self {
+            device,
+            format,
+            filter: FilterMode::Nearest,
+            blend_state: None,
+        }
+    }
+
+    /// Filter used when sampling the source. Defaults to [`FilterMode::Nearest`].
+    ///
+    /// With [`FilterMode::Linear`], the source format must be filterable.
+    pub fn sample_filter(mut self, filter: FilterMode) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Blend state used when writing to the target. Defaults to no blending.
+    pub fn blend_state(mut self, blend_state: BlendState) -> Self {
+        self.blend_state = Some(blend_state);
+        self
+    }
+
+    /// Create the [`TextureBlitter`].
+    pub fn build(self) -> TextureBlitter {
+        let filterable = self.filter == FilterMode::Linear;
+
+        let sampler = self.device.create_sampler(&SamplerDescriptor {
+            label: Some("wgpu::util::TextureBlitter::sampler"),
+            mag_filter: self.filter,
+            min_filter: self.filter,
+            ..Default::default()
+        });
+
+        let bind_group_layout = self
+            .device
+            .create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("wgpu::util::TextureBlitter::bind_group_layout"),
+                entries: &[
+                    BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: ShaderStages::FRAGMENT,
+                        ty: BindingType::Texture {
+                            sample_type: TextureSampleType::Float { filterable },
+                            view_dimension: TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: ShaderStages::FRAGMENT,
+                        ty: BindingType::Sampler(if filterable {
+                            SamplerBindingType::Filtering
+                        } else {
+                            SamplerBindingType::NonFiltering
+                        }),
+                        count: None,
+                    },
+                ],
+            });
+
+        let pipeline_layout = self
+            .device
+            .create_pipeline_layout(&PipelineLayoutDescriptor {
+                label: Some("wgpu::util::TextureBlitter::pipeline_layout"),
+                bind_group_layouts: &[&bind_group_layout],
+                push_constant_ranges: &[],
+            });
+
+        let shader = self.device.create_shader_module(ShaderModuleDescriptor {
+            label: Some("wgpu::util::TextureBlitter::shader"),
+            source: ShaderSource::Wgsl(include_str!("blit.wgsl").into()),
+        });
+
+        let pipeline = create_pipeline(
+            self.device,
+            &pipeline_layout,
+            &shader,
+            self.format,
+            self.blend_state,
+        );
+
+        TextureBlitter {
+            format: self.format,
+            blend_state: self.blend_state,
+            shader,
+            pipeline_layout,
+            bind_group_layout,
+            sampler,
+            pipelines: Mutex::new(HashMap::from([(self.format, pipeline)])),
+        }
+    }
+}
+
+fn create_pipeline(
+    device: &Device,
+    layout: &PipelineLayout,
+    shader: &ShaderModule,
+    format: TextureFormat,
+    blend_state: Option<BlendState>,
+) -> RenderPipeline {
+    device.create_render_pipeline(&RenderPipelineDescriptor {
+        label: Some("wgpu::util::TextureBlitter::pipeline"),
+        layout: Some(layout),
+        vertex: VertexState {
+            module: shader,
+            entry_point: "vs_main",
+            buffers: &[],
+        },
+        primitive: PrimitiveState::default(),
+        depth_stencil: None,
+        multisample: MultisampleState::default(),
+        fragment: Some(FragmentState {
+            module: shader,
+            entry_point: "fs_main",
+            targets: &[Some(ColorTargetState {
+                format,
+                blend: blend_state,
+                write_mask: ColorWrites::ALL,
+            })],
+        }),
+        multiview: None,
+    })
+}
+
+/// Copies the contents of one texture view into another, converting between formats
+/// and scaling as needed.
+///
+/// Copy commands like [`CommandEncoder::copy_texture_to_texture`] require the source
+/// and destination to have the same size and compatible formats. This instead draws a
+/// full screen triangle that samples the source, so it can for example present an
+/// `Rgba16Float` HDR image to a `Bgra8UnormSrgb` surface. Values that don't fit the
+/// target format are clamped by the hardware as usual.
+///
+/// Render pipelines are cached per target format. The one for the format given on
+/// creation is created up front, others the first time they are blitted to with
+/// [`TextureBlitter::copy_to_format`].
+pub struct TextureBlitter {
+    format: TextureFormat,
+    blend_state: Option<BlendState>,
+    shader: ShaderModule,
+    pipeline_layout: PipelineLayout,
+    bind_group_layout: BindGroupLayout,
+    sampler: Sampler,
+    pipelines: Mutex<HashMap<TextureFormat, RenderPipeline>>,
+}
+
+impl TextureBlitter {
+    /// Create a [`TextureBlitter`] that renders into targets of `format`, using nearest
+    /// filtering and no blending.
+    ///
+    /// Use [`TextureBlitterBuilder`] to change these settings.
+    pub fn new(device: &Device, format: TextureFormat) -> Self {
+        TextureBlitterBuilder::new(device, format).build()
+    }
+
+    /// Record a render pass in `encoder` that copies `source` into `target`,
+    /// stretching it to cover the whole target.
+    ///
+    /// `source` must be a 2D view of a texture with the `TEXTURE_BINDING` usage and a
+    /// float sample type. `target` must be a view of a texture with the
+    /// `RENDER_ATTACHMENT` usage, in the format this blitter was created for.
+    /// Otherwise a validation error is raised, as with any other bind group or render
+    /// pass.
+    pub fn copy(
+        &self,
+        device: &Device,
+        encoder: &mut CommandEncoder,
+        source: &TextureView,
+        target: &TextureView,
+    ) {
+        self.copy_to_format(device, encoder, source, target, self.format);
+    }
+
+    /// Like [`TextureBlitter::copy`], but for a `target` of `target_format`, which
+    /// may differ from the format this blitter was created for.
+    ///
+    /// The render pipeline for `target_format` is created on first use and reused
+    /// afterwards.
+    pub fn copy_to_format(
+        &self,
+        device: &Device,
+        encoder: &mut CommandEncoder,
+        source: &TextureView,
+        target: &TextureView,
+        target_format: TextureFormat,
+    ) {
+        let mut pipelines = self.pipelines.lock();
+        let pipeline = pipelines.entry(target_format).or_insert_with(|| {
+            create_pipeline(
+                device,
+                &self.pipeline_layout,
+                &self.shader,
+                target_format,
+                self.blend_state,
+            )
+        });
+
+        let bind_group = device.create_bind_group(&BindGroupDescriptor {
+            label: Some("wgpu::util::TextureBlitter::bind_group"),
+            layout: &self.bind_group_layout,
+            entries: &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: BindingResource::TextureView(source),
+                },
+                BindGroupEntry {
+                    binding: 1,
+                    resource: BindingResource::Sampler(&self.sampler),
+                },
+            ],
+        });
+
+        let mut pass = encoder.begin_render_pass(&RenderPassDescriptor {
+            label: Some("wgpu::util::TextureBlitter::pass"),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: target,
+                resolve_target: None,
+                ops: Operations {
+                    load: LoadOp::Load,
+                    store: StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        pass.set_pipeline(pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.draw(0..3, 0..1);
+    }
+}
@@ -41,8 +41,25 @@ pub enum TextureDataOrder {
 /// Utility methods not meant to be in the main API.
 pub trait DeviceExt {
     /// Creates a [Buffer](crate::Buffer) with data to initialize it.
+    ///
+    /// The size of the buffer is `contents.len()` rounded up to a multiple of
+    /// [`COPY_BUFFER_ALIGNMENT`](crate::COPY_BUFFER_ALIGNMENT), with the padding
+    /// filled with zeros. If `contents` is empty, a zero sized buffer is created
+    /// without ever being mapped.
     fn create_buffer_init(&self, desc: &BufferInitDescriptor<'_>) -> crate::Buffer;
 
+    /// Creates a [Buffer](crate::Buffer) with data to initialize it, and leaves
+    /// it mapped.
+    ///
+    /// This is useful for `MAP_WRITE | COPY_SRC` staging buffers that are going to
+    /// be written to further before use. The caller is responsible for calling
+    /// [`Buffer::unmap`](crate::Buffer::unmap) before the buffer is used on the GPU.
+    ///
+    /// The buffer is sized the same way as in [`DeviceExt::create_buffer_init`].
+    /// If `contents` is empty, the zero sized buffer is returned unmapped and
+    /// must not be unmapped again.
+    fn create_buffer_init_mapped(&self, desc: &BufferInitDescriptor<'_>) -> crate::Buffer;
+
     /// Upload an entire texture and its mipmaps from a source buffer.
     ///
     /// Expects all mipmaps to be tightly packed in the data buffer.
@@ -51,6 +68,11 @@ pub trait DeviceExt {
     ///
     /// Implicitly adds the `COPY_DST` usage if it is not present in the descriptor,
     /// as it is required to be able to upload the data to the gpu.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `data` doesn't match the size of the tightly packed
+    /// texture described by `desc`.
     fn create_texture_with_data(
         &self,
         queue: &crate::Queue,
@@ -62,41 +84,11 @@ pub trait DeviceExt {
 
 impl DeviceExt for crate::Device {
     fn create_buffer_init(&self, descriptor: &BufferInitDescriptor<'_>) -> crate::Buffer {
-        // Skip mapping if the buffer is zero sized
-        if descriptor.contents.is_empty() {
-            let wgt_descriptor = crate::BufferDescriptor {
-                label: descriptor.label,
-                size: 0,
-                usage: descriptor.usage,
-                mapped_at_creation: false,
-            };
-
-            self.create_buffer(&wgt_descriptor)
-        } else {
-            let unpadded_size = descriptor.contents.len() as crate::BufferAddress;
-            // Valid vulkan usage is
-            // 1. buffer size must be a multiple of COPY_BUFFER_ALIGNMENT.
-            // 2. buffer size must be greater than 0.
-            // Therefore we round the value up to the nearest multiple, and ensure it's at least COPY_BUFFER_ALIGNMENT.
-            let align_mask = crate::COPY_BUFFER_ALIGNMENT - 1;
-            let padded_size =
-                ((unpadded_size + align_mask) & !align_mask).max(crate::COPY_BUFFER_ALIGNMENT);
-
-            let wgt_descriptor = crate::BufferDescriptor {
-                label: descriptor.label,
-                size: padded_size,
-                usage: descriptor.usage,
-                mapped_at_creation: true,
-            };
-
-            let buffer = self.create_buffer(&wgt_descriptor);
-
-            buffer.slice(..).get_mapped_range_mut()[..unpadded_size as usize]
-                .copy_from_slice(descriptor.contents);
-            buffer.unmap();
-
-            buffer
-        }
+        create_buffer_init_impl(self, descriptor, false)
+    }
+
+    fn create_buffer_init_mapped(&self, descriptor: &BufferInitDescriptor<'_>) -> crate::Buffer {
+        create_buffer_init_impl(self, descriptor, true)
     }
 
     fn create_texture_with_data(
@@ -109,7 +101,6 @@ impl DeviceExt for crate::Device {
         // Implicitly add the COPY_DST usage
         let mut desc = desc.to_owned();
         desc.usage |= crate::TextureUsages::COPY_DST;
-        let texture = self.create_texture(&desc);
 
         // Will return None only if it's a combined depth-stencil format
         // If so, default to 4, validation will fail later anyway since the depth or stencil
@@ -131,14 +122,9 @@ impl DeviceExt for crate::Device {
             }
         }
 
-        let mut binary_offset = 0;
-        for outer in 0..outer_iteration {
-            for inner in 0..inner_iteration {
-                let (layer, mip) = match order {
-                    TextureDataOrder::LayerMajor => (outer, inner),
-                    TextureDataOrder::MipMajor => (inner, outer),
-                };
-
+        // Layout of a single layer of each mip level in `data`.
+        let mip_layouts: Vec<_> = (0..desc.mip_level_count)
+            .map(|mip| {
                 let mut mip_size = desc.mip_level_size(mip).unwrap();
                 // copying layers separately
                 if desc.dimension != wgt::TextureDimension::D3 {
@@ -158,7 +144,41 @@ impl DeviceExt for crate::Device {
                 let bytes_per_row = width_blocks * block_size;
                 let data_size = bytes_per_row * height_blocks * mip_size.depth_or_array_layers;
 
-                let end_offset = binary_offset + data_size as usize;
+                (
+                    mip_physical,
+                    bytes_per_row,
+                    height_blocks,
+                    data_size as usize,
+                )
+            })
+            .collect();
+
+        let expected_size: usize = mip_layouts
+            .iter()
+            .map(|&(_, _, _, data_size)| data_size * layer_iterations as usize)
+            .sum();
+        assert_eq!(
+            data.len(),
+            expected_size,
+            "Texture data for {:?} must be exactly {expected_size} bytes, but {} bytes were provided",
+            desc.label,
+            data.len(),
+        );
+
+        let texture = self.create_texture(&desc);
+
+        let mut binary_offset = 0;
+        for outer in 0..outer_iteration {
+            for inner in 0..inner_iteration {
+                let (layer, mip) = match order {
+                    TextureDataOrder::LayerMajor => (outer, inner),
+                    TextureDataOrder::MipMajor => (inner, outer),
+                };
+
+                let (mip_physical, bytes_per_row, height_blocks, data_size) =
+                    mip_layouts[mip as usize];
+
+                let end_offset = binary_offset + data_size;
 
                 queue.write_texture(
                     crate::ImageCopyTexture {
@@ -187,3 +207,49 @@ impl DeviceExt for crate::Device {
         texture
     }
 }
+
+fn create_buffer_init_impl(
+    device: &crate::Device,
+    descriptor: &BufferInitDescriptor<'_>,
+    keep_mapped: bool,
+) -> crate::Buffer {
+    // Skip mapping if the buffer is zero sized. There is nothing to write or
+    // read through a mapping, so this holds for `keep_mapped` as well.
+    if descriptor.contents.is_empty() {
+        let wgt_descriptor = crate::BufferDescriptor {
+            label: descriptor.label,
+            size: 0,
+            usage: descriptor.usage,
+            mapped_at_creation: false,
+        };
+
+        return device.create_buffer(&wgt_descriptor);
+    }
+
+    let unpadded_size = descriptor.contents.len() as crate::BufferAddress;
+    // Valid vulkan usage is
+    // 1. buffer size must be a multiple of COPY_BUFFER_ALIGNMENT.
+    // 2. buffer size must be greater than 0.
+    // Therefore we round the value up to the nearest multiple, and ensure it's at least COPY_BUFFER_ALIGNMENT.
+    let align_mask = crate::COPY_BUFFER_ALIGNMENT - 1;
+    let padded_size =
+        ((unpadded_size + align_mask) & !align_mask).max(crate::COPY_BUFFER_ALIGNMENT);
+
+    let wgt_descriptor = crate::BufferDescriptor {
+        label: descriptor.label,
+        size: padded_size,
+        usage: descriptor.usage,
+        mapped_at_creation: true,
+    };
+
+    let buffer = device.create_buffer(&wgt_descriptor);
+
+    buffer.slice(..).get_mapped_range_mut()[..unpadded_size as usize]
+        .copy_from_slice(descriptor.contents);
+
+    if !keep_mapped {
+        buffer.unmap();
+    }
+
+    buffer
+}
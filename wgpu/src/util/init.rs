@@ -38,15 +38,52 @@ pub fn power_preference_from_env() -> Option<PowerPreference> {
 }
 
 /// Initialize the adapter obeying the WGPU_ADAPTER_NAME environment variable.
+///
+/// See [`initialize_adapter_from_name`] for how the adapter is chosen, with the
+/// power preference taken from the `WGPU_POWER_PREF` environment variable.
+///
+/// Returns `None` if `WGPU_ADAPTER_NAME` isn't set, or if no adapter matches it.
 #[cfg(native)]
 pub fn initialize_adapter_from_env(
     instance: &Instance,
     compatible_surface: Option<&Surface<'_>>,
 ) -> Option<Adapter> {
-    let desired_adapter_name = std::env::var("WGPU_ADAPTER_NAME")
-        .as_deref()
-        .map(str::to_lowercase)
-        .ok()?;
+    let desired_adapter_name = std::env::var("WGPU_ADAPTER_NAME").ok()?;
+
+    initialize_adapter_from_name(
+        instance,
+        compatible_surface,
+        &desired_adapter_name,
+        power_preference_from_env(),
+    )
+}
+
+/// Initialize the adapter whose name matches `adapter_name`.
+///
+/// `adapter_name` is matched case insensitively as a substring of
+/// [`AdapterInfo::name`](crate::AdapterInfo::name). Adapters that can't present to
+/// `compatible_surface` are skipped.
+///
+/// If several adapters match, the one whose device type fits `power_preference`
+/// is chosen: a discrete GPU for [`PowerPreference::HighPerformance`] and an
+/// integrated GPU for [`PowerPreference::LowPower`]. Remaining ties go to the
+/// first matching adapter in [`Instance::enumerate_adapters`] order, so the
+/// choice is deterministic for a given system.
+///
+/// Returns `None` if no adapter matches.
+#[cfg(native)]
+pub fn initialize_adapter_from_name(
+    instance: &Instance,
+    compatible_surface: Option<&Surface<'_>>,
+    adapter_name: &str,
+    power_preference: Option<PowerPreference>,
+) -> Option<Adapter> {
+    let desired_adapter_name = adapter_name.to_lowercase();
+    let preferred_device_type = match power_preference {
+        Some(PowerPreference::HighPerformance) => Some(wgt::DeviceType::DiscreteGpu),
+        Some(PowerPreference::LowPower) => Some(wgt::DeviceType::IntegratedGpu),
+        Some(PowerPreference::None) | None => None,
+    };
 
     let adapters = instance.enumerate_adapters(Backends::all());
 
@@ -60,13 +97,28 @@ pub fn initialize_adapter_from_env(
             }
         }
 
-        if info.name.to_lowercase().contains(&desired_adapter_name) {
+        if !info.name.to_lowercase().contains(&desired_adapter_name) {
+            continue;
+        }
+
+        if Some(info.device_type) == preferred_device_type {
             chosen_adapter = Some(adapter);
             break;
         }
+
+        if chosen_adapter.is_none() {
+            chosen_adapter = Some(adapter);
+            if preferred_device_type.is_none() {
+                break;
+            }
+        }
+    }
+
+    if chosen_adapter.is_none() {
+        log::warn!("No adapter matching {adapter_name:?} was found");
     }
 
-    Some(chosen_adapter.expect("WGPU_ADAPTER_NAME set but no matching adapter found!"))
+    chosen_adapter
 }
 
 /// Initialize the adapter obeying the WGPU_ADAPTER_NAME environment variable.
@@ -78,7 +130,20 @@ pub fn initialize_adapter_from_env(
     None
 }
 
+/// Initialize the adapter whose name matches `adapter_name`.
+#[cfg(not(native))]
+pub fn initialize_adapter_from_name(
+    _instance: &Instance,
+    _compatible_surface: Option<&Surface<'_>>,
+    _adapter_name: &str,
+    _power_preference: Option<PowerPreference>,
+) -> Option<Adapter> {
+    None
+}
+
 /// Initialize the adapter obeying the WGPU_ADAPTER_NAME environment variable and if it doesn't exist fall back on a default adapter.
+///
+/// The fallback adapter is requested with the power preference from `WGPU_POWER_PREF`.
 pub async fn initialize_adapter_from_env_or_default(
     instance: &Instance,
     compatible_surface: Option<&Surface<'_>>,
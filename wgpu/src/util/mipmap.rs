@@ -0,0 +1,253 @@
+use std::{error, fmt, ops::Range};
+
+use crate::{
+    util::TextureBlitterBuilder, BindGroupDescriptor, BindGroupEntry, BindGroupLayoutDescriptor,
+    BindGroupLayoutEntry, BindingResource, BindingType, CommandEncoder, ComputePassDescriptor,
+    ComputePipelineDescriptor, Device, Features, FilterMode, PipelineLayoutDescriptor,
+    ShaderModuleDescriptor, ShaderSource, ShaderStages, StorageTextureAccess, Texture,
+    TextureDimension, TextureFormat, TextureSampleType, TextureUsages, TextureViewDescriptor,
+    TextureViewDimension,
+};
+
+/// Error returned by [`generate_mipmaps`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GenerateMipmapsError {
+    /// Mipmaps can only be generated for 2D textures and 2D array textures.
+    UnsupportedDimension(TextureDimension),
+    /// The format can't be rendered to with filtering, nor written as a storage texture.
+    /// This is the case for compressed, depth and stencil, and integer formats.
+    UnsupportedFormat(TextureFormat),
+    /// The texture is missing usages needed to generate its mipmaps.
+    MissingUsage(TextureUsages),
+    /// The range is empty, starts at level 0, or goes past the texture's last level.
+    InvalidMipRange {
+        /// The levels that were requested.
+        range: Range<u32>,
+        /// The number of levels in the texture.
+        mip_level_count: u32,
+    },
+}
+
+impl fmt::Display for GenerateMipmapsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedDimension(dimension) => {
+                write!(f, "Can't generate mipmaps for {dimension:?} textures")
+            }
+            Self::UnsupportedFormat(format) => {
+                write!(
+                    f,
+                    "Can't generate mipmaps for textures of format {format:?}"
+                )
+            }
+            Self::MissingUsage(usage) => {
+                write!(f, "Generating mipmaps requires the texture usage {usage:?}")
+            }
+            Self::InvalidMipRange {
+                range,
+                mip_level_count,
+            } => write!(
+                f,
+                "Can't generate mip levels {range:?} of a texture with {mip_level_count} levels"
+            ),
+        }
+    }
+}
+
+impl error::Error for GenerateMipmapsError {}
+
+/// Record commands in `encoder` that fill each level in `mip_levels` of `texture`
+/// by downsampling the level before it, for every array layer.
+///
+/// To fill the whole mip chain from level 0, pass `1..texture.mip_level_count()`.
+///
+/// The texture must be 2D and have [`TextureUsages::TEXTURE_BINDING`]. Levels are
+/// generated in one of two ways:
+///
+/// - If the texture has [`TextureUsages::RENDER_ATTACHMENT`] and its format is
+///   filterable, each level is rendered with a [`TextureBlitter`] using linear filtering.
+///   sRGB formats are averaged in linear space.
+/// - Otherwise, if the texture has [`TextureUsages::STORAGE_BINDING`] and its format can
+///   be written as a float storage texture, each level is computed by a compute shader
+///   that averages 2x2 blocks. This requires [`DownlevelFlags::COMPUTE_SHADERS`].
+///
+/// Either way, the pipeline is created on every call, so this is meant for textures
+/// loaded once rather than for per-frame use.
+///
+/// [`TextureBlitter`]: crate::util::TextureBlitter
+/// [`DownlevelFlags::COMPUTE_SHADERS`]: crate::DownlevelFlags::COMPUTE_SHADERS
+pub fn generate_mipmaps(
+    device: &Device,
+    encoder: &mut CommandEncoder,
+    texture: &Texture,
+    mip_levels: Range<u32>,
+) -> Result<(), GenerateMipmapsError> {
+    let mip_level_count = texture.mip_level_count();
+    if mip_levels.is_empty() || mip_levels.start == 0 || mip_levels.end > mip_level_count {
+        return Err(GenerateMipmapsError::InvalidMipRange {
+            range: mip_levels,
+            mip_level_count,
+        });
+    }
+
+    let dimension = texture.dimension();
+    if dimension != TextureDimension::D2 {
+        return Err(GenerateMipmapsError::UnsupportedDimension(dimension));
+    }
+
+    let format = texture.format();
+    if format.is_compressed() {
+        return Err(GenerateMipmapsError::UnsupportedFormat(format));
+    }
+    let filterable = match format.sample_type(None, Some(device.features())) {
+        Some(TextureSampleType::Float { filterable }) => filterable,
+        _ => return Err(GenerateMipmapsError::UnsupportedFormat(format)),
+    };
+
+    let usage = texture.usage();
+    if !usage.contains(TextureUsages::TEXTURE_BINDING) {
+        return Err(GenerateMipmapsError::MissingUsage(
+            TextureUsages::TEXTURE_BINDING,
+        ));
+    }
+
+    let view = |mip_level: u32, layer: u32| {
+        texture.create_view(&TextureViewDescriptor {
+            label: Some("wgpu::util::generate_mipmaps::view"),
+            dimension: Some(TextureViewDimension::D2),
+            base_mip_level: mip_level,
+            mip_level_count: Some(1),
+            base_array_layer: layer,
+            array_layer_count: Some(1),
+            ..Default::default()
+        })
+    };
+    let layers = 0..texture.depth_or_array_layers();
+
+    if filterable && usage.contains(TextureUsages::RENDER_ATTACHMENT) {
+        let blitter = TextureBlitterBuilder::new(device, format)
+            .sample_filter(FilterMode::Linear)
+            .build();
+        for layer in layers {
+            for mip_level in mip_levels.clone() {
+                blitter.copy(
+                    device,
+                    encoder,
+                    &view(mip_level - 1, layer),
+                    &view(mip_level, layer),
+                );
+            }
+        }
+        return Ok(());
+    }
+
+    let Some(storage_format) = storage_format_name(format, device.features()) else {
+        return Err(GenerateMipmapsError::UnsupportedFormat(format));
+    };
+    if !usage.contains(TextureUsages::STORAGE_BINDING) {
+        return Err(GenerateMipmapsError::MissingUsage(
+            TextureUsages::RENDER_ATTACHMENT | TextureUsages::STORAGE_BINDING,
+        ));
+    }
+
+    let bind_group_layout = device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+        label: Some("wgpu::util::generate_mipmaps::bind_group_layout"),
+        entries: &[
+            BindGroupLayoutEntry {
+                binding: 0,
+                visibility: ShaderStages::COMPUTE,
+                ty: BindingType::Texture {
+                    sample_type: TextureSampleType::Float { filterable: false },
+                    view_dimension: TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            },
+            BindGroupLayoutEntry {
+                binding: 1,
+                visibility: ShaderStages::COMPUTE,
+                ty: BindingType::StorageTexture {
+                    access: StorageTextureAccess::WriteOnly,
+                    format,
+                    view_dimension: TextureViewDimension::D2,
+                },
+                count: None,
+            },
+        ],
+    });
+    let pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+        label: Some("wgpu::util::generate_mipmaps::pipeline_layout"),
+        bind_group_layouts: &[&bind_group_layout],
+        push_constant_ranges: &[],
+    });
+    let shader = device.create_shader_module(ShaderModuleDescriptor {
+        label: Some("wgpu::util::generate_mipmaps::shader"),
+        source: ShaderSource::Wgsl(
+            include_str!("mipmap.wgsl")
+                .replace("STORAGE_FORMAT", storage_format)
+                .into(),
+        ),
+    });
+    let pipeline = device.create_compute_pipeline(&ComputePipelineDescriptor {
+        label: Some("wgpu::util::generate_mipmaps::pipeline"),
+        layout: Some(&pipeline_layout),
+        module: &shader,
+        entry_point: "main",
+    });
+
+    // The pass borrows the bind groups, so they're all created up front.
+    let mut dispatches = Vec::new();
+    for layer in layers {
+        for mip_level in mip_levels.clone() {
+            let size = texture
+                .size()
+                .mip_level_size(mip_level, TextureDimension::D2);
+            let source = view(mip_level - 1, layer);
+            let destination = view(mip_level, layer);
+            let bind_group = device.create_bind_group(&BindGroupDescriptor {
+                label: Some("wgpu::util::generate_mipmaps::bind_group"),
+                layout: &bind_group_layout,
+                entries: &[
+                    BindGroupEntry {
+                        binding: 0,
+                        resource: BindingResource::TextureView(&source),
+                    },
+                    BindGroupEntry {
+                        binding: 1,
+                        resource: BindingResource::TextureView(&destination),
+                    },
+                ],
+            });
+            dispatches.push((bind_group, size));
+        }
+    }
+
+    {
+        let mut pass = encoder.begin_compute_pass(&ComputePassDescriptor {
+            label: Some("wgpu::util::generate_mipmaps::pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&pipeline);
+        for (bind_group, size) in &dispatches {
+            pass.set_bind_group(0, bind_group, &[]);
+            pass.dispatch_workgroups((size.width + 7) / 8, (size.height + 7) / 8, 1);
+        }
+    }
+    Ok(())
+}
+
+/// The WGSL name of `format`, if it can be written as a float storage texture with `features`.
+fn storage_format_name(format: TextureFormat, features: Features) -> Option<&'static str> {
+    Some(match format {
+        TextureFormat::Rgba8Unorm => "rgba8unorm",
+        TextureFormat::Rgba8Snorm => "rgba8snorm",
+        TextureFormat::Bgra8Unorm if features.contains(Features::BGRA8UNORM_STORAGE) => {
+            "bgra8unorm"
+        }
+        TextureFormat::Rgba16Float => "rgba16float",
+        TextureFormat::R32Float => "r32float",
+        TextureFormat::Rg32Float => "rg32float",
+        TextureFormat::Rgba32Float => "rgba32float",
+        _ => return None,
+    })
+}
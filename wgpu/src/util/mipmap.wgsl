@@ -0,0 +1,24 @@
+// `STORAGE_FORMAT` is replaced with the texture's format before this is compiled.
+
+@group(0) @binding(0)
+var source: texture_2d<f32>;
+@group(0) @binding(1)
+var destination: texture_storage_2d<STORAGE_FORMAT, write>;
+
+// Each invocation averages a 2x2 block of the source level. For odd sizes, the
+// last row or column of the source is reused rather than read out of bounds.
+@compute @workgroup_size(8, 8)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let size = textureDimensions(destination);
+    if id.x >= size.x || id.y >= size.y {
+        return;
+    }
+
+    let last = vec2<i32>(textureDimensions(source)) - 1;
+    let base = vec2<i32>(id.xy) * 2;
+    let sum = textureLoad(source, base, 0)
+        + textureLoad(source, min(base + vec2<i32>(1, 0), last), 0)
+        + textureLoad(source, min(base + vec2<i32>(0, 1), last), 0)
+        + textureLoad(source, min(base + vec2<i32>(1, 1), last), 0);
+    textureStore(destination, id.xy, sum * 0.25);
+}
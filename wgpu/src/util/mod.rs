@@ -4,9 +4,15 @@
 //! they are unique to the `wgpu` library.
 
 mod belt;
+#[cfg(feature = "wgsl")]
+mod blit;
 mod device;
 mod encoder;
 mod init;
+#[cfg(feature = "wgsl")]
+mod mipmap;
+#[cfg(native)]
+mod readback;
 
 use std::sync::Arc;
 use std::{
@@ -16,9 +22,15 @@ use std::{
 };
 
 pub use belt::StagingBelt;
+#[cfg(feature = "wgsl")]
+pub use blit::{TextureBlitter, TextureBlitterBuilder};
 pub use device::{BufferInitDescriptor, DeviceExt, TextureDataOrder};
 pub use encoder::RenderEncoder;
 pub use init::*;
+#[cfg(feature = "wgsl")]
+pub use mipmap::{generate_mipmaps, GenerateMipmapsError};
+#[cfg(native)]
+pub use readback::{read_texture_to_rgba8, ReadTextureError};
 pub use wgt::{math::*, DispatchIndirectArgs, DrawIndexedIndirectArgs, DrawIndirectArgs};
 
 /// Treat the given byte slice as a SPIR-V module.
@@ -85,23 +97,40 @@ pub fn make_spirv_raw(data: &[u8]) -> Cow<'_, [u32]> {
 pub struct DownloadBuffer(
     Arc<super::Buffer>,
     Box<dyn crate::context::BufferMappedRange>,
+    std::ops::Range<usize>,
 );
 
 impl DownloadBuffer {
     /// Asynchronously read the contents of a buffer.
+    ///
+    /// The buffer must have the [`BufferUsages::COPY_SRC`](super::BufferUsages::COPY_SRC)
+    /// usage. The slice doesn't need to be aligned to
+    /// [`COPY_BUFFER_ALIGNMENT`](super::COPY_BUFFER_ALIGNMENT): the download is widened to
+    /// aligned bounds and only the requested bytes are exposed. If the widened range doesn't
+    /// fit in the buffer, which can only happen for a buffer whose size isn't aligned, the
+    /// callback receives [`BufferAsyncError::Other`](super::BufferAsyncError::Other).
     pub fn read_buffer(
         device: &super::Device,
         queue: &super::Queue,
         buffer: &super::BufferSlice<'_>,
         callback: impl FnOnce(Result<Self, super::BufferAsyncError>) + Send + 'static,
     ) {
-        let size = match buffer.size {
-            Some(size) => size.into(),
-            None => buffer.buffer.map_context.lock().total_size - buffer.offset,
+        let total_size = buffer.buffer.map_context.lock().total_size;
+        let end = match buffer.size {
+            Some(size) => buffer.offset + size.get(),
+            None => total_size,
         };
 
+        let copy_start = buffer.offset - buffer.offset % super::COPY_BUFFER_ALIGNMENT;
+        let copy_end = align_to(end, super::COPY_BUFFER_ALIGNMENT);
+        if copy_end > total_size {
+            callback(Err(super::BufferAsyncError::Other));
+            return;
+        }
+        let copy_size = copy_end - copy_start;
+
         let download = Arc::new(device.create_buffer(&super::BufferDescriptor {
-            size,
+            size: copy_size,
             usage: super::BufferUsages::COPY_DST | super::BufferUsages::MAP_READ,
             mapped_at_creation: false,
             label: None,
@@ -109,10 +138,11 @@ impl DownloadBuffer {
 
         let mut encoder =
             device.create_command_encoder(&super::CommandEncoderDescriptor { label: None });
-        encoder.copy_buffer_to_buffer(buffer.buffer, buffer.offset, &download, 0, size);
+        encoder.copy_buffer_to_buffer(buffer.buffer, copy_start, &download, 0, copy_size);
         let command_buffer: super::CommandBuffer = encoder.finish();
         queue.submit(Some(command_buffer));
 
+        let range = (buffer.offset - copy_start) as usize..(end - copy_start) as usize;
         download
             .clone()
             .slice(..)
@@ -126,9 +156,9 @@ impl DownloadBuffer {
                     &*download.context,
                     &download.id,
                     download.data.as_ref(),
-                    0..size,
+                    0..copy_size,
                 );
-                callback(Ok(Self(download, mapped_range)));
+                callback(Ok(Self(download, mapped_range, range)));
             });
     }
 }
@@ -136,6 +166,42 @@ impl DownloadBuffer {
 impl std::ops::Deref for DownloadBuffer {
     type Target = [u8];
     fn deref(&self) -> &[u8] {
-        self.1.slice()
+        &self.1.slice()[self.2.clone()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DispatchIndirectArgs, DrawIndexedIndirectArgs, DrawIndirectArgs};
+
+    fn le_bytes(words: &[u32]) -> Vec<u8> {
+        words.iter().flat_map(|word| word.to_le_bytes()).collect()
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn indirect_args_layout() {
+        let draw = DrawIndirectArgs {
+            vertex_count: 1,
+            instance_count: 2,
+            first_vertex: 3,
+            first_instance: 4,
+        };
+        assert_eq!(draw.as_bytes(), le_bytes(&[1, 2, 3, 4]));
+
+        let draw_indexed = DrawIndexedIndirectArgs {
+            index_count: 1,
+            instance_count: 2,
+            first_index: 3,
+            base_vertex: -4,
+            first_instance: 5,
+        };
+        assert_eq!(
+            draw_indexed.as_bytes(),
+            le_bytes(&[1, 2, 3, (-4i32) as u32, 5])
+        );
+
+        let dispatch = DispatchIndirectArgs { x: 1, y: 2, z: 3 };
+        assert_eq!(dispatch.as_bytes(), le_bytes(&[1, 2, 3]));
     }
 }
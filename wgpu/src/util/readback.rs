@@ -0,0 +1,132 @@
+use std::{error, fmt};
+
+use crate::{BufferAsyncError, Device, Queue, Texture, TextureFormat};
+
+/// Error returned by [`read_texture_to_rgba8`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReadTextureError {
+    /// The texture format can't be converted to RGBA8.
+    UnsupportedFormat(TextureFormat),
+    /// Mapping the staging buffer failed.
+    Map(BufferAsyncError),
+}
+
+impl fmt::Display for ReadTextureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedFormat(format) => {
+                write!(f, "Texture format {format:?} can't be converted to RGBA8")
+            }
+            Self::Map(e) => e.fmt(f),
+        }
+    }
+}
+
+impl error::Error for ReadTextureError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::UnsupportedFormat(_) => None,
+            Self::Map(e) => Some(e),
+        }
+    }
+}
+
+/// Read back a single 2D subresource of `texture` as tightly packed RGBA8 data.
+///
+/// Rows are returned top to bottom, with no padding between them. BGRA formats are
+/// swizzled into RGBA. Bytes are returned exactly as they are stored, so data from
+/// sRGB formats stays sRGB encoded, which is what most image formats expect.
+///
+/// The texture must have been created with [`TextureUsages::COPY_SRC`]. This also
+/// works on surface textures, as long as the surface was configured with that usage.
+///
+/// This submits a copy on `queue` and blocks until it has completed.
+///
+/// [`TextureUsages::COPY_SRC`]: crate::TextureUsages::COPY_SRC
+pub fn read_texture_to_rgba8(
+    device: &Device,
+    queue: &Queue,
+    texture: &Texture,
+    mip_level: u32,
+    layer: u32,
+) -> Result<Vec<u8>, ReadTextureError> {
+    let format = texture.format();
+    let swizzle_bgra = match format {
+        TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb => false,
+        TextureFormat::Bgra8Unorm | TextureFormat::Bgra8UnormSrgb => true,
+        _ => return Err(ReadTextureError::UnsupportedFormat(format)),
+    };
+
+    let size = texture
+        .size()
+        .mip_level_size(mip_level, texture.dimension());
+    let unpadded_bytes_per_row = size.width * 4;
+    let padded_bytes_per_row =
+        super::align_to(unpadded_bytes_per_row, crate::COPY_BYTES_PER_ROW_ALIGNMENT);
+
+    let staging = device.create_buffer(&crate::BufferDescriptor {
+        label: Some("read_texture_to_rgba8 staging buffer"),
+        size: padded_bytes_per_row as u64 * size.height as u64,
+        usage: crate::BufferUsages::COPY_DST | crate::BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+
+    let mut encoder = device.create_command_encoder(&crate::CommandEncoderDescriptor {
+        label: Some("read_texture_to_rgba8"),
+    });
+    encoder.copy_texture_to_buffer(
+        crate::ImageCopyTexture {
+            texture,
+            mip_level,
+            origin: crate::Origin3d {
+                x: 0,
+                y: 0,
+                z: layer,
+            },
+            aspect: crate::TextureAspect::All,
+        },
+        crate::ImageCopyBuffer {
+            buffer: &staging,
+            layout: crate::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(padded_bytes_per_row),
+                rows_per_image: None,
+            },
+        },
+        crate::Extent3d {
+            width: size.width,
+            height: size.height,
+            depth_or_array_layers: 1,
+        },
+    );
+    let index = queue.submit(Some(encoder.finish()));
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    staging
+        .slice(..)
+        .map_async(crate::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+    device.poll(crate::Maintain::WaitForSubmissionIndex(index));
+    receiver
+        .recv()
+        .expect("map_async callback was not called after waiting on the device")
+        .map_err(ReadTextureError::Map)?;
+
+    let mut pixels = Vec::with_capacity((unpadded_bytes_per_row * size.height) as usize);
+    {
+        let mapped = staging.slice(..).get_mapped_range();
+        for row in mapped.chunks_exact(padded_bytes_per_row as usize) {
+            pixels.extend_from_slice(&row[..unpadded_bytes_per_row as usize]);
+        }
+    }
+    staging.unmap();
+
+    if swizzle_bgra {
+        for texel in pixels.chunks_exact_mut(4) {
+            texel.swap(0, 2);
+        }
+    }
+
+    Ok(pixels)
+}